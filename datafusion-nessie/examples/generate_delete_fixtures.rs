@@ -0,0 +1,513 @@
+//! Generates `test_data/db/test_iceberg_deletes`, an Iceberg v2 table written
+//! in merge-on-read mode:
+//!
+//! 1. snapshot 1 appends `(1, 'Alice')` to `(6, 'Frank')`;
+//! 2. snapshot 2 deletes the rows at positions 1 and 4, Bob and Eve, with a
+//!    position delete file;
+//! 3. snapshot 3 appends `(7, 'Carol')` and deletes the rows named Carol with
+//!    an equality delete file, which only applies to the earlier data file.
+//!
+//! Like the metadata of the other tables in `test_data`, the generated files
+//! refer to each other by their path once copied to `/tmp/iceberg_warehouse`.
+//!
+//! ```text
+//! cargo run -p datafusion-nessie --example generate_delete_fixtures -- test_data /tmp/iceberg_warehouse
+//! ```
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, Int32Array, Int64Array, RecordBatch, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::parquet::arrow::{ArrowWriter, PARQUET_FIELD_ID_META_KEY};
+use serde_json::json;
+
+const TABLE: &str = "db/test_iceberg_deletes";
+const SNAPSHOT_IDS: [i64; 3] = [
+    5735508421683045541,
+    6414236521489371370,
+    8812036813270925733,
+];
+const TIMESTAMPS_MS: [i64; 3] = [1759996800000, 1759996860000, 1759996920000];
+/// Field ids of the columns of position delete files.
+const DELETE_FILE_PATH_ID: i32 = 2147483546;
+const DELETE_POS_ID: i32 = 2147483545;
+
+/// Content of manifest entries and manifests.
+const DATA: i32 = 0;
+const POSITION_DELETES: i32 = 1;
+const EQUALITY_DELETES: i32 = 2;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let (Some(out_dir), Some(warehouse)) = (args.next(), args.next()) else {
+        eprintln!("usage: generate_delete_fixtures <out dir> <warehouse>");
+        std::process::exit(1);
+    };
+    let table = Table {
+        dir: Path::new(&out_dir).join(TABLE),
+        location: format!("{}/{TABLE}", warehouse.trim_end_matches('/')),
+    };
+    fs::create_dir_all(table.dir.join("data")).unwrap();
+    fs::create_dir_all(table.dir.join("metadata")).unwrap();
+
+    let names = ["Alice", "Bob", "Carol", "Dave", "Eve", "Frank"];
+    let data_1 = table.write_rows("data/00000-0-data.parquet", &[1, 2, 3, 4, 5, 6], &names);
+    let position_deletes = table.write_position_deletes(
+        "data/00001-0-position-deletes.parquet",
+        &data_1.path,
+        &[1, 4],
+    );
+    let data_2 = table.write_rows("data/00002-0-data.parquet", &[7], &["Carol"]);
+    let equality_deletes =
+        table.write_equality_deletes("data/00002-1-equality-deletes.parquet", &["Carol"]);
+
+    let manifests = [
+        table.write_manifest("metadata/manifest-1-m0.avro", 0, DATA, &data_1),
+        table.write_manifest(
+            "metadata/manifest-2-m0.avro",
+            1,
+            POSITION_DELETES,
+            &position_deletes,
+        ),
+        table.write_manifest("metadata/manifest-3-m0.avro", 2, DATA, &data_2),
+        table.write_manifest(
+            "metadata/manifest-3-m1.avro",
+            2,
+            EQUALITY_DELETES,
+            &equality_deletes,
+        ),
+    ];
+    let manifest_lists = [
+        table.write_manifest_list(0, &manifests[..1]),
+        table.write_manifest_list(1, &manifests[..2]),
+        table.write_manifest_list(2, &manifests),
+    ];
+    table.write_metadata(&manifest_lists);
+}
+
+struct Table {
+    dir: std::path::PathBuf,
+    location: String,
+}
+
+/// A written data or delete file.
+struct DataFile {
+    path: String,
+    content: i32,
+    record_count: i64,
+    size: i64,
+    equality_ids: Option<Vec<i32>>,
+}
+
+/// A written manifest.
+struct Manifest {
+    path: String,
+    length: i64,
+    content: i32,
+    snapshot: usize,
+    rows: i64,
+}
+
+impl Table {
+    fn write_rows(&self, name: &str, ids: &[i32], names: &[&str]) -> DataFile {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                field("id", DataType::Int32, 1),
+                field("name", DataType::Utf8, 2),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(ids.to_vec())) as ArrayRef,
+                Arc::new(StringArray::from(names.to_vec())),
+            ],
+        )
+        .unwrap();
+        self.write_parquet(name, batch, DATA, None)
+    }
+
+    fn write_position_deletes(&self, name: &str, data_file: &str, positions: &[i64]) -> DataFile {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                field("file_path", DataType::Utf8, DELETE_FILE_PATH_ID).with_nullable(false),
+                field("pos", DataType::Int64, DELETE_POS_ID).with_nullable(false),
+            ])),
+            vec![
+                Arc::new(StringArray::from(vec![data_file; positions.len()])) as ArrayRef,
+                Arc::new(Int64Array::from(positions.to_vec())),
+            ],
+        )
+        .unwrap();
+        self.write_parquet(name, batch, POSITION_DELETES, None)
+    }
+
+    fn write_equality_deletes(&self, name: &str, names: &[&str]) -> DataFile {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![field("name", DataType::Utf8, 2)])),
+            vec![Arc::new(StringArray::from(names.to_vec())) as ArrayRef],
+        )
+        .unwrap();
+        self.write_parquet(name, batch, EQUALITY_DELETES, Some(vec![2]))
+    }
+
+    fn write_parquet(
+        &self,
+        name: &str,
+        batch: RecordBatch,
+        content: i32,
+        equality_ids: Option<Vec<i32>>,
+    ) -> DataFile {
+        let mut bytes = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut bytes, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        DataFile {
+            path: self.write(name, &bytes),
+            content,
+            record_count: batch.num_rows() as i64,
+            size: bytes.len() as i64,
+            equality_ids,
+        }
+    }
+
+    /// Writes a manifest adding `file` in snapshot `snapshot`.
+    fn write_manifest(
+        &self,
+        name: &str,
+        snapshot: usize,
+        content: i32,
+        file: &DataFile,
+    ) -> Manifest {
+        let sequence_number = snapshot as i64 + 1;
+        let mut entry = Avro::default();
+        entry.int(1); // ADDED
+        entry.optional(Some(SNAPSHOT_IDS[snapshot]), Avro::long);
+        entry.optional(Some(sequence_number), Avro::long);
+        entry.optional(Some(sequence_number), Avro::long);
+        entry.int(file.content);
+        entry.string(&file.path);
+        entry.string("PARQUET");
+        // The partition of unpartitioned tables is an empty record.
+        entry.long(file.record_count);
+        entry.long(file.size);
+        for _ in [
+            "column_sizes",
+            "value_counts",
+            "null_value_counts",
+            "nan_value_counts",
+        ] {
+            entry.optional(None::<i64>, Avro::long);
+        }
+        for _ in [
+            "lower_bounds",
+            "upper_bounds",
+            "key_metadata",
+            "split_offsets",
+        ] {
+            entry.optional(None::<i64>, Avro::long);
+        }
+        entry.optional(file.equality_ids.as_deref(), |avro, ids| {
+            avro.long(ids.len() as i64);
+            ids.iter().for_each(|id| avro.int(*id));
+            avro.long(0);
+        });
+        entry.optional(None::<i32>, Avro::int);
+
+        let metadata = [
+            ("schema", table_schema().to_string()),
+            ("schema-id", "0".to_string()),
+            ("partition-spec", "[]".to_string()),
+            ("partition-spec-id", "0".to_string()),
+            ("format-version", "2".to_string()),
+            (
+                "content",
+                if content == DATA { "data" } else { "deletes" }.to_string(),
+            ),
+        ];
+        let bytes = avro_file(&manifest_entry_schema(), &metadata, &[entry]);
+        Manifest {
+            path: self.write(name, &bytes),
+            length: bytes.len() as i64,
+            content: if content == DATA { 0 } else { 1 },
+            snapshot,
+            rows: file.record_count,
+        }
+    }
+
+    fn write_manifest_list(&self, snapshot: usize, manifests: &[Manifest]) -> String {
+        let records: Vec<Avro> = manifests
+            .iter()
+            .map(|manifest| {
+                let sequence_number = manifest.snapshot as i64 + 1;
+                let mut record = Avro::default();
+                record.string(&manifest.path);
+                record.long(manifest.length);
+                record.int(0);
+                record.int(manifest.content);
+                record.long(sequence_number);
+                record.long(sequence_number);
+                record.long(SNAPSHOT_IDS[manifest.snapshot]);
+                record.int(1);
+                record.int(0);
+                record.int(0);
+                record.long(manifest.rows);
+                record.long(0);
+                record.long(0);
+                record.optional(None::<i64>, Avro::long);
+                record.optional(None::<i64>, Avro::long);
+                record
+            })
+            .collect();
+        let mut metadata = vec![
+            ("snapshot-id", SNAPSHOT_IDS[snapshot].to_string()),
+            ("sequence-number", (snapshot + 1).to_string()),
+            ("format-version", "2".to_string()),
+        ];
+        if snapshot > 0 {
+            metadata.push(("parent-snapshot-id", SNAPSHOT_IDS[snapshot - 1].to_string()));
+        }
+        let bytes = avro_file(&manifest_file_schema(), &metadata, &records);
+        self.write(
+            &format!(
+                "metadata/snap-{}-1-manifest-list.avro",
+                SNAPSHOT_IDS[snapshot]
+            ),
+            &bytes,
+        )
+    }
+
+    fn write_metadata(&self, manifest_lists: &[String]) {
+        let operations = ["append", "delete", "overwrite"];
+        let snapshots: Vec<_> = (0..manifest_lists.len())
+            .map(|i| {
+                let mut snapshot = json!({
+                    "snapshot-id": SNAPSHOT_IDS[i],
+                    "sequence-number": i + 1,
+                    "timestamp-ms": TIMESTAMPS_MS[i],
+                    "manifest-list": manifest_lists[i],
+                    "summary": {"operation": operations[i]},
+                    "schema-id": 0,
+                });
+                if i > 0 {
+                    snapshot["parent-snapshot-id"] = json!(SNAPSHOT_IDS[i - 1]);
+                }
+                snapshot
+            })
+            .collect();
+        let snapshot_log: Vec<_> = (0..manifest_lists.len())
+            .map(|i| json!({"snapshot-id": SNAPSHOT_IDS[i], "timestamp-ms": TIMESTAMPS_MS[i]}))
+            .collect();
+        let current = SNAPSHOT_IDS[manifest_lists.len() - 1];
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": "0f6a2a0e-5b1c-4d38-9d63-6f4b7f1e0c21",
+            "location": self.location,
+            "last-sequence-number": manifest_lists.len(),
+            "last-updated-ms": TIMESTAMPS_MS[manifest_lists.len() - 1],
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [table_schema()],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                "write.delete.mode": "merge-on-read",
+                "write.update.mode": "merge-on-read",
+            },
+            "current-snapshot-id": current,
+            "refs": {"main": {"snapshot-id": current, "type": "branch"}},
+            "snapshots": snapshots,
+            "snapshot-log": snapshot_log,
+            "metadata-log": [],
+        });
+        self.write(
+            "metadata/v1.metadata.json",
+            serde_json::to_string_pretty(&metadata).unwrap().as_bytes(),
+        );
+    }
+
+    /// Writes `bytes` to file `name` of the table and returns its location.
+    fn write(&self, name: &str, bytes: &[u8]) -> String {
+        fs::write(self.dir.join(name), bytes).unwrap();
+        format!("{}/{name}", self.location)
+    }
+}
+
+fn field(name: &str, data_type: DataType, id: i32) -> Field {
+    Field::new(name, data_type, true)
+        .with_metadata([(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())].into())
+}
+
+fn table_schema() -> serde_json::Value {
+    json!({
+        "type": "struct",
+        "schema-id": 0,
+        "fields": [
+            {"id": 1, "name": "id", "required": false, "type": "int"},
+            {"id": 2, "name": "name", "required": false, "type": "string"},
+        ],
+    })
+}
+
+/// Avro schema of the entries of v2 manifests.
+fn manifest_entry_schema() -> serde_json::Value {
+    fn map(key_id: i32, value_id: i32, value: &str) -> serde_json::Value {
+        json!(["null", {
+            "type": "array",
+            "logicalType": "map",
+            "items": {
+                "type": "record",
+                "name": format!("k{key_id}_v{value_id}"),
+                "fields": [
+                    {"name": "key", "type": "int", "field-id": key_id},
+                    {"name": "value", "type": value, "field-id": value_id},
+                ],
+            },
+        }])
+    }
+    let optional = |id: i32, name: &str, schema: serde_json::Value| json!({"name": name, "type": schema, "default": null, "field-id": id});
+    json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            {"name": "status", "type": "int", "field-id": 0},
+            optional(1, "snapshot_id", json!(["null", "long"])),
+            optional(3, "sequence_number", json!(["null", "long"])),
+            optional(4, "file_sequence_number", json!(["null", "long"])),
+            {"name": "data_file", "field-id": 2, "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {"name": "partition", "field-id": 102,
+                     "type": {"type": "record", "name": "r102", "fields": []}},
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    optional(108, "column_sizes", map(117, 118, "long")),
+                    optional(109, "value_counts", map(119, 120, "long")),
+                    optional(110, "null_value_counts", map(121, 122, "long")),
+                    optional(137, "nan_value_counts", map(138, 139, "long")),
+                    optional(125, "lower_bounds", map(126, 127, "bytes")),
+                    optional(128, "upper_bounds", map(129, 130, "bytes")),
+                    optional(131, "key_metadata", json!(["null", "bytes"])),
+                    optional(132, "split_offsets",
+                        json!(["null", {"type": "array", "items": "long", "element-id": 133}])),
+                    optional(135, "equality_ids",
+                        json!(["null", {"type": "array", "items": "int", "element-id": 136}])),
+                    optional(140, "sort_order_id", json!(["null", "int"])),
+                ],
+            }},
+        ],
+    })
+}
+
+/// Avro schema of the manifests of v2 manifest lists.
+fn manifest_file_schema() -> serde_json::Value {
+    let required =
+        |id: i32, name: &str, schema: &str| json!({"name": name, "type": schema, "field-id": id});
+    json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            required(500, "manifest_path", "string"),
+            required(501, "manifest_length", "long"),
+            required(502, "partition_spec_id", "int"),
+            required(517, "content", "int"),
+            required(515, "sequence_number", "long"),
+            required(516, "min_sequence_number", "long"),
+            required(503, "added_snapshot_id", "long"),
+            required(504, "added_files_count", "int"),
+            required(505, "existing_files_count", "int"),
+            required(506, "deleted_files_count", "int"),
+            required(512, "added_rows_count", "long"),
+            required(513, "existing_rows_count", "long"),
+            required(514, "deleted_rows_count", "long"),
+            {"name": "partitions", "default": null, "field-id": 507, "type": ["null", {
+                "type": "array",
+                "element-id": 508,
+                "items": {
+                    "type": "record",
+                    "name": "r508",
+                    "fields": [
+                        required(509, "contains_null", "boolean"),
+                        {"name": "contains_nan", "type": ["null", "boolean"], "field-id": 518},
+                        {"name": "lower_bound", "type": ["null", "bytes"], "field-id": 510},
+                        {"name": "upper_bound", "type": ["null", "bytes"], "field-id": 511},
+                    ],
+                },
+            }]},
+            {"name": "key_metadata", "type": ["null", "bytes"], "default": null, "field-id": 519},
+        ],
+    })
+}
+
+/// Binary encoding of Avro values.
+#[derive(Default)]
+struct Avro(Vec<u8>);
+
+impl Avro {
+    fn long(&mut self, value: i64) {
+        let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        while zigzag >= 0x80 {
+            self.0.push((zigzag as u8) | 0x80);
+            zigzag >>= 7;
+        }
+        self.0.push(zigzag as u8);
+    }
+
+    fn int(&mut self, value: i32) {
+        self.long(value as i64);
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.long(value.len() as i64);
+        self.0.extend_from_slice(value);
+    }
+
+    fn string(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    /// A `["null", T]` union.
+    fn optional<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match value {
+            None => self.long(0),
+            Some(value) => {
+                self.long(1);
+                write(self, value);
+            }
+        }
+    }
+}
+
+/// An Avro object container file of `records`, uncompressed.
+fn avro_file(schema: &serde_json::Value, metadata: &[(&str, String)], records: &[Avro]) -> Vec<u8> {
+    const SYNC: [u8; 16] = *b"nessie-iceberg-0";
+    let mut file = Avro(b"Obj\x01".to_vec());
+    let schema = schema.to_string();
+    let header = [("avro.schema", schema.as_str()), ("avro.codec", "null")];
+    let entries: Vec<(&str, &str)> = header
+        .into_iter()
+        .chain(metadata.iter().map(|(key, value)| (*key, value.as_str())))
+        .collect();
+    file.long(entries.len() as i64);
+    for (key, value) in entries {
+        file.string(key);
+        file.string(value);
+    }
+    file.long(0);
+    file.0.extend_from_slice(&SYNC);
+
+    let block: Vec<u8> = records
+        .iter()
+        .flat_map(|record| record.0.iter().copied())
+        .collect();
+    file.long(records.len() as i64);
+    file.bytes(&block);
+    file.0.extend_from_slice(&SYNC);
+    file.0
+}
@@ -0,0 +1,312 @@
+//! Iceberg v2 row-level deletes of the data files read by a scan.
+//!
+//! Position deletes remove rows of a data file by their position in the
+//! file, and equality deletes remove the rows whose values of some columns
+//! equal those of a deleted row. Iceberg's planning attaches to every data
+//! file the delete files that apply to it, i.e. those of later sequence
+//! numbers, except for some equality deletes that the scan drops again (see
+//! [`retain_applying_deletes`]). A delete file usually applies to many data files, so a scan
+//! reads each one once, see [`DeleteFiles`].
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use datafusion::arrow::array::{Array, ArrayRef, BooleanArray, Int64Array, StringArray};
+use datafusion::arrow::compute::{cast, filter_record_batch};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::row::{OwnedRow, RowConverter, SortField};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
+use iceberg::io::FileIO;
+use iceberg::scan::{FileScanTask, FileScanTaskDeleteFile};
+use iceberg::spec::{DataContentType, ManifestList};
+
+use crate::nessie_table_provider::to_datafusion_error;
+
+/// The delete files of a scan, each read on first use and shared by all the
+/// data files it applies to.
+#[derive(Default)]
+pub(crate) struct DeleteFiles {
+    files: Mutex<HashMap<String, LoadingDeleteFile>>,
+}
+
+/// A delete file being read, or read, by the first data file it applies to.
+type LoadingDeleteFile = Shared<BoxFuture<'static, Result<Arc<DeleteFile>, Arc<DataFusionError>>>>;
+
+impl fmt::Debug for DeleteFiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteFiles").finish_non_exhaustive()
+    }
+}
+
+impl DeleteFiles {
+    /// The deletes of the delete files of `task`.
+    pub(crate) async fn load(
+        &self,
+        file_io: &FileIO,
+        task: &FileScanTask,
+    ) -> Result<DataFileDeletes> {
+        let mut deletes = DataFileDeletes::default();
+        for delete in &task.deletes {
+            let file = self
+                .get(file_io, delete)
+                .await
+                .map_err(DataFusionError::Shared)?;
+            match file.as_ref() {
+                DeleteFile::Positions(positions) => deletes
+                    .positions
+                    .extend(positions.get(&task.data_file_path).cloned()),
+                DeleteFile::Equality(equality) => deletes.equality.extend(equality.clone()),
+            }
+        }
+        Ok(deletes)
+    }
+
+    fn get(&self, file_io: &FileIO, delete: &FileScanTaskDeleteFile) -> LoadingDeleteFile {
+        let mut files = self.files.lock().unwrap();
+        files
+            .entry(delete.file_path.clone())
+            .or_insert_with(|| {
+                let file_io = file_io.clone();
+                let delete = delete.clone();
+                async move {
+                    DeleteFile::load(&file_io, &delete)
+                        .await
+                        .map(Arc::new)
+                        .map_err(Arc::new)
+                }
+                .boxed()
+                .shared()
+            })
+            .clone()
+    }
+}
+
+/// The rows removed by one delete file.
+#[derive(Debug)]
+enum DeleteFile {
+    /// Deleted positions by data file path.
+    Positions(HashMap<String, Arc<HashSet<i64>>>),
+    /// `None` if there are no deleted rows.
+    Equality(Option<Arc<EqualityDeletes>>),
+}
+
+impl DeleteFile {
+    async fn load(file_io: &FileIO, delete: &FileScanTaskDeleteFile) -> Result<Self> {
+        let batches = read_parquet(file_io, &delete.file_path).await?;
+        match delete.file_type {
+            DataContentType::PositionDeletes => Ok(Self::Positions(positions(&batches)?)),
+            DataContentType::EqualityDeletes => Ok(Self::Equality(
+                EqualityDeletes::try_new(delete, &batches)?.map(Arc::new),
+            )),
+            DataContentType::Data => Err(DataFusionError::Internal(format!(
+                "Data file {} listed as a delete file",
+                delete.file_path
+            ))),
+        }
+    }
+}
+
+/// Drops from `tasks` the equality deletes of the same sequence number as
+/// their data file. Iceberg attaches the equality deletes of unpartitioned
+/// specs to the data files of sequence numbers up to theirs, but they only
+/// apply to older ones.
+pub(crate) async fn retain_applying_deletes(
+    file_io: &FileIO,
+    manifest_list: &ManifestList,
+    tasks: &mut [FileScanTask],
+) -> Result<()> {
+    let has_equality_deletes = tasks.iter().any(|task| {
+        task.deletes
+            .iter()
+            .any(|delete| delete.file_type == DataContentType::EqualityDeletes)
+    });
+    if !has_equality_deletes {
+        return Ok(());
+    }
+
+    let mut sequence_numbers = HashMap::new();
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(file_io)
+            .await
+            .map_err(to_datafusion_error)?;
+        for entry in manifest.entries() {
+            if let Some(sequence_number) = entry.sequence_number() {
+                sequence_numbers.insert(entry.file_path().to_string(), sequence_number);
+            }
+        }
+    }
+    for task in tasks {
+        let data_sequence_number = sequence_numbers.get(&task.data_file_path);
+        task.deletes.retain(|delete| {
+            delete.file_type != DataContentType::EqualityDeletes
+                || sequence_numbers.get(&delete.file_path) > data_sequence_number
+        });
+    }
+    Ok(())
+}
+
+/// Rows of one data file removed by its delete files.
+#[derive(Debug, Default)]
+pub(crate) struct DataFileDeletes {
+    /// Deleted positions, counted from the first row of the file.
+    positions: Vec<Arc<HashSet<i64>>>,
+    equality: Vec<Arc<EqualityDeletes>>,
+}
+
+/// Rows deleted by the values of the columns `field_ids`.
+#[derive(Debug)]
+struct EqualityDeletes {
+    field_ids: Vec<i32>,
+    /// Types the columns are compared as, those of the delete file.
+    types: Vec<DataType>,
+    converter: RowConverter,
+    rows: HashSet<OwnedRow>,
+}
+
+impl DataFileDeletes {
+    /// Field ids of the columns that must be read to apply the deletes.
+    pub(crate) fn field_ids(&self) -> impl Iterator<Item = i32> + '_ {
+        self.equality
+            .iter()
+            .flat_map(|deletes| deletes.field_ids.iter().copied())
+    }
+
+    /// Removes the deleted rows from `batch`, whose first row is at
+    /// `position` in the data file.
+    pub(crate) fn apply(&self, batch: &RecordBatch, position: i64) -> Result<RecordBatch> {
+        let mut keep: Vec<bool> = (0..batch.num_rows() as i64)
+            .map(|row| {
+                !self
+                    .positions
+                    .iter()
+                    .any(|positions| positions.contains(&(position + row)))
+            })
+            .collect();
+        for deletes in &self.equality {
+            deletes.retain(batch, &mut keep)?;
+        }
+        Ok(filter_record_batch(batch, &BooleanArray::from(keep))?)
+    }
+}
+
+/// The positions deleted by a position delete file, by data file path.
+fn positions(batches: &[RecordBatch]) -> Result<HashMap<String, Arc<HashSet<i64>>>> {
+    let mut positions: HashMap<String, HashSet<i64>> = HashMap::new();
+    for batch in batches {
+        let paths = column::<StringArray>(batch, "file_path")?;
+        let deleted = column::<Int64Array>(batch, "pos")?;
+        for row in 0..batch.num_rows() {
+            positions
+                .entry(paths.value(row).to_string())
+                .or_default()
+                .insert(deleted.value(row));
+        }
+    }
+    Ok(positions
+        .into_iter()
+        .map(|(path, positions)| (path, Arc::new(positions)))
+        .collect())
+}
+
+impl EqualityDeletes {
+    /// The deletes of `batches`, read from `delete`; `None` if there are no
+    /// deleted rows.
+    fn try_new(delete: &FileScanTaskDeleteFile, batches: &[RecordBatch]) -> Result<Option<Self>> {
+        let Some(schema) = batches.first().map(|batch| batch.schema()) else {
+            return Ok(None);
+        };
+        let indices = delete
+            .equality_ids
+            .iter()
+            .map(|id| field_index(&schema, *id, &delete.file_path))
+            .collect::<Result<Vec<_>>>()?;
+        let types: Vec<DataType> = indices
+            .iter()
+            .map(|i| schema.field(*i).data_type().clone())
+            .collect();
+        let converter = RowConverter::new(types.iter().cloned().map(SortField::new).collect())?;
+
+        let mut rows = HashSet::new();
+        for batch in batches {
+            let columns: Vec<ArrayRef> = indices.iter().map(|i| batch.column(*i).clone()).collect();
+            rows.extend(
+                converter
+                    .convert_columns(&columns)?
+                    .iter()
+                    .map(|row| row.owned()),
+            );
+        }
+        Ok(Some(Self {
+            field_ids: delete.equality_ids.clone(),
+            types,
+            converter,
+            rows,
+        }))
+    }
+
+    /// Clears `keep` for the rows of `batch` that are deleted.
+    fn retain(&self, batch: &RecordBatch, keep: &mut [bool]) -> Result<()> {
+        let schema = batch.schema();
+        let columns = self
+            .field_ids
+            .iter()
+            .zip(&self.types)
+            .map(|(id, data_type)| {
+                let column = batch.column(field_index(&schema, *id, "the rows read")?);
+                Ok(cast(column, data_type)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let rows = self.converter.convert_columns(&columns)?;
+        for (row, keep) in rows.iter().zip(keep.iter_mut()) {
+            if self.rows.contains(&row.owned()) {
+                *keep = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn read_parquet(file_io: &FileIO, path: &str) -> Result<Vec<RecordBatch>> {
+    let bytes = file_io
+        .new_input(path)
+        .map_err(to_datafusion_error)?
+        .read()
+        .await
+        .map_err(to_datafusion_error)?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(bytes)?.build()?;
+    Ok(reader.collect::<Result<Vec<_>, _>>()?)
+}
+
+fn column<'a, T: 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T> {
+    batch
+        .column_by_name(name)
+        .and_then(|column| column.as_any().downcast_ref::<T>())
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!("Position delete file without column {name}"))
+        })
+}
+
+pub(crate) fn field_id(field: &Field) -> Option<i32> {
+    field
+        .metadata()
+        .get(PARQUET_FIELD_ID_META_KEY)?
+        .parse()
+        .ok()
+}
+
+fn field_index(schema: &Schema, id: i32, source: &str) -> Result<usize> {
+    schema
+        .fields()
+        .iter()
+        .position(|field| field_id(field) == Some(id))
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!("No column with field id {id} in {source}"))
+        })
+}
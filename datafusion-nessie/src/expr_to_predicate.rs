@@ -0,0 +1,133 @@
+//! Conversion of DataFusion filters to Iceberg predicates, which Iceberg uses
+//! to prune partitions, data files and row groups.
+
+use datafusion::logical_expr::expr::InList;
+use datafusion::logical_expr::{Between, BinaryExpr, Expr, Operator};
+use datafusion::scalar::ScalarValue;
+use iceberg::expr::{Predicate, Reference};
+use iceberg::spec::Datum;
+
+/// The conjunction of the filters that can be converted, `None` if none can.
+pub(crate) fn convert_filters(filters: &[Expr]) -> Option<Predicate> {
+    filters
+        .iter()
+        .filter_map(convert_filter)
+        .reduce(Predicate::and)
+}
+
+/// A predicate true for every row `filter` is true for, `None` if there is no
+/// such predicate short of `true`.
+///
+/// The predicate may be weaker than the filter, as unconvertible operands of
+/// an `AND` are dropped.
+pub(crate) fn convert_filter(filter: &Expr) -> Option<Predicate> {
+    convert(filter, true)
+}
+
+/// Converts `expr`, dropping unconvertible operands of `AND`s if `weaken`.
+/// Operands of a `NOT` are never weakened, as that would make the negation
+/// stronger.
+fn convert(expr: &Expr, weaken: bool) -> Option<Predicate> {
+    match expr {
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => match op {
+            Operator::And => match (convert(left, weaken), convert(right, weaken)) {
+                (Some(left), Some(right)) => Some(left.and(right)),
+                (Some(operand), None) | (None, Some(operand)) if weaken => Some(operand),
+                _ => None,
+            },
+            Operator::Or => Some(convert(left, weaken)?.or(convert(right, weaken)?)),
+            _ => comparison(left, *op, right),
+        },
+        Expr::Not(operand) => Some(convert(operand, false)?.negate()),
+        Expr::IsNull(operand) => Some(reference(operand)?.is_null()),
+        Expr::IsNotNull(operand) => Some(reference(operand)?.is_not_null()),
+        Expr::InList(InList {
+            expr,
+            list,
+            negated,
+        }) => {
+            let reference = reference(expr)?;
+            let values = list.iter().map(datum).collect::<Option<Vec<_>>>()?;
+            Some(if *negated {
+                reference.is_not_in(values)
+            } else {
+                reference.is_in(values)
+            })
+        }
+        Expr::Between(Between {
+            expr,
+            negated,
+            low,
+            high,
+        }) => {
+            let reference = reference(expr)?;
+            let between = reference
+                .clone()
+                .greater_than_or_equal_to(datum(low)?)
+                .and(reference.less_than_or_equal_to(datum(high)?));
+            Some(if *negated { between.negate() } else { between })
+        }
+        _ => None,
+    }
+}
+
+/// Converts `left op right` where one side is a column and the other a
+/// literal.
+fn comparison(left: &Expr, op: Operator, right: &Expr) -> Option<Predicate> {
+    let (reference, op, value) = match (reference(left), reference(right)) {
+        (Some(reference), None) => (reference, op, datum(right)?),
+        (None, Some(reference)) => (reference, op.swap()?, datum(left)?),
+        _ => return None,
+    };
+    match op {
+        Operator::Eq => Some(reference.equal_to(value)),
+        Operator::NotEq => Some(reference.not_equal_to(value)),
+        Operator::Lt => Some(reference.less_than(value)),
+        Operator::LtEq => Some(reference.less_than_or_equal_to(value)),
+        Operator::Gt => Some(reference.greater_than(value)),
+        Operator::GtEq => Some(reference.greater_than_or_equal_to(value)),
+        _ => None,
+    }
+}
+
+fn reference(expr: &Expr) -> Option<Reference> {
+    match expr {
+        Expr::Column(column) => Some(Reference::new(column.name())),
+        _ => None,
+    }
+}
+
+/// The Iceberg value of a non-null literal.
+fn datum(expr: &Expr) -> Option<Datum> {
+    match expr {
+        Expr::Literal(value, _) => scalar_datum(value),
+        _ => None,
+    }
+}
+
+/// The Iceberg value of a non-null scalar of a type Iceberg has.
+pub(crate) fn scalar_datum(value: &ScalarValue) -> Option<Datum> {
+    match value {
+        ScalarValue::Boolean(Some(value)) => Some(Datum::bool(*value)),
+        ScalarValue::Int8(Some(value)) => Some(Datum::int(*value)),
+        ScalarValue::Int16(Some(value)) => Some(Datum::int(*value)),
+        ScalarValue::Int32(Some(value)) => Some(Datum::int(*value)),
+        ScalarValue::Int64(Some(value)) => Some(Datum::long(*value)),
+        ScalarValue::Float32(Some(value)) => Some(Datum::float(*value)),
+        ScalarValue::Float64(Some(value)) => Some(Datum::double(*value)),
+        ScalarValue::Utf8(Some(value))
+        | ScalarValue::LargeUtf8(Some(value))
+        | ScalarValue::Utf8View(Some(value)) => Some(Datum::string(value)),
+        ScalarValue::Binary(Some(value)) | ScalarValue::LargeBinary(Some(value)) => {
+            Some(Datum::binary(value.clone()))
+        }
+        ScalarValue::Date32(Some(days)) => Some(Datum::date(*days)),
+        ScalarValue::TimestampMicrosecond(Some(micros), None) => {
+            Some(Datum::timestamp_micros(*micros))
+        }
+        ScalarValue::TimestampMicrosecond(Some(micros), Some(_)) => {
+            Some(Datum::timestamptz_micros(*micros))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,43 @@
+//! DataFusion catalog, schemas and tables over the Iceberg tables kept on a
+//! Nessie reference.
+//!
+//! ```no_run
+//! # async fn example(catalog: nessie_iceberg_catalog::NessieCatalog) -> datafusion::error::Result<()> {
+//! use std::sync::Arc;
+//!
+//! use datafusion::prelude::SessionContext;
+//! use datafusion_nessie::NessieCatalogProvider;
+//!
+//! let ctx = SessionContext::new();
+//! let provider = NessieCatalogProvider::new(catalog)
+//!     .map_err(|e| datafusion::error::DataFusionError::External(Box::new(e)))?;
+//! ctx.register_catalog("nessie", Arc::new(provider));
+//! ctx.sql("SELECT * FROM nessie.db.test_iceberg").await?.show().await?;
+//!
+//! // CREATE TABLE is only run by `datafusion_nessie::sql`: `ctx.sql` refuses it.
+//! datafusion_nessie::sql(
+//!     &ctx,
+//!     "CREATE TABLE nessie.db.events (id BIGINT, ts TIMESTAMP) PARTITIONED BY (day(ts))",
+//! )
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod deletes;
+mod expr_to_predicate;
+pub mod nessie_catalog_provider;
+pub mod nessie_data_sink;
+pub mod nessie_scan_exec;
+pub mod nessie_schema_provider;
+pub mod nessie_sql;
+pub mod nessie_table_provider;
+mod overwrite;
+mod partitioning;
+
+pub use nessie_catalog_provider::NessieCatalogProvider;
+pub use nessie_data_sink::{NessieDataSink, OverwriteMode};
+pub use nessie_scan_exec::NessieScanExec;
+pub use nessie_schema_provider::NessieSchemaProvider;
+pub use nessie_sql::sql;
+pub use nessie_table_provider::NessieTableProvider;
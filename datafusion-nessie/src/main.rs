@@ -1,5 +1,32 @@
-pub mod nessie_catalog_provider;
+use std::error::Error;
+use std::sync::Arc;
 
-fn main() {
-    println!("Hello, world!");
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::NessieCatalogProvider;
+use iceberg::io::FileIOBuilder;
+use nessie_client::client::NessieClient;
+use nessie_iceberg_catalog::NessieCatalog;
+
+/// Runs the SQL statement given as argument against the default branch of the
+/// Nessie server at `NESSIE_URL`, with the tables registered as catalog
+/// `nessie`, e.g. `SELECT * FROM nessie.db.test_iceberg`.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let url = std::env::var("NESSIE_URL")
+        .unwrap_or_else(|_| "http://localhost:19120/api/v2/".to_string());
+    let warehouse =
+        std::env::var("WAREHOUSE").unwrap_or_else(|_| "file:///tmp/iceberg_warehouse".to_string());
+    let sql = std::env::args()
+        .nth(1)
+        .ok_or("usage: datafusion-nessie <sql>")?;
+
+    let client = NessieClient::new(&url)?;
+    let file_io = FileIOBuilder::new_fs_io().build()?;
+    let catalog = NessieCatalog::on_default_branch(client, warehouse, file_io).await?;
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("nessie", Arc::new(NessieCatalogProvider::new(catalog)?));
+    datafusion_nessie::sql(&ctx, &sql).await?.show().await?;
+
+    Ok(())
 }
@@ -1,39 +1,176 @@
-use datafusion::catalog::CatalogProvider;
+use std::any::Any;
+use std::sync::Arc;
 
+use datafusion::catalog::{CatalogProvider, SchemaProvider};
+use datafusion::common::{not_impl_err, plan_err};
+use datafusion::error::{DataFusionError, Result};
+use iceberg::{Catalog, NamespaceIdent};
+use nessie_client::blocking::BlockingNessieClient;
+use nessie_client::error::NessieError;
+use nessie_client::models::{ContentKey, EntriesParams, Entry, ReferenceType};
+use nessie_client::ref_spec::RefSpec;
+use nessie_iceberg_catalog::NessieCatalog;
+use nessie_iceberg_catalog::catalog::NAMESPACE;
+
+use crate::nessie_schema_provider::NessieSchemaProvider;
+
+/// DataFusion catalog over the namespaces of the branch of a [`NessieCatalog`].
+///
+/// Every Nessie namespace is a schema, named by the path form of its key (see
+/// [`ContentKey::to_path_string`]): its elements joined with `.`, with a `.`
+/// inside an element written as `\u001D`. So `nessie.db.events` is table
+/// `events` of namespace `db`. DataFusion looks schemas up synchronously, so
+/// they are listed through a [`BlockingNessieClient`] sharing the connection
+/// pool of the catalog, always at the current head of the branch.
+///
+/// The listing methods of DataFusion cannot fail: if the branch cannot be
+/// listed, the error is logged and the catalog reads as having no schemas,
+/// and a schema as having no tables.
+///
+/// `CREATE SCHEMA nessie.db` creates namespace `db` on the branch, and
+/// `DROP SCHEMA nessie.db` drops it if it is empty. DataFusion
+/// plans `CREATE TABLE` as an in-memory table, so tables are created with
+/// [`sql`](crate::sql) instead of [`SessionContext::sql`].
+///
+/// [`SessionContext::sql`]: datafusion::prelude::SessionContext::sql
 #[derive(Debug)]
 pub struct NessieCatalogProvider {
+    catalog: Arc<NessieCatalog>,
+    client: Arc<BlockingNessieClient>,
+}
+
+impl NessieCatalogProvider {
+    /// Catalog over the branch of `catalog`, which must exist. Tags and
+    /// commits are rejected: the catalog commits the tables and namespaces it
+    /// creates, which only a branch can take.
+    pub fn new(catalog: NessieCatalog) -> Result<Self, NessieError> {
+        let client = BlockingNessieClient::from_client(catalog.client().clone())?;
+        let reference = client.get_reference(&RefSpec::named(catalog.branch())?)?;
+        if !matches!(reference.reference.ref_type, ReferenceType::Branch) {
+            return Err(NessieError::InvalidConfiguration(format!(
+                "{} is not a branch",
+                catalog.branch()
+            )));
+        }
+        Ok(Self {
+            catalog: Arc::new(catalog),
+            client: Arc::new(client),
+        })
+    }
+
+    pub fn catalog(&self) -> &Arc<NessieCatalog> {
+        &self.catalog
+    }
+}
+
+/// Lists the entries of the branch of `catalog`. The DataFusion listing
+/// methods cannot fail, so errors are logged and read as no entries.
+pub(crate) fn list_entries(
+    client: &BlockingNessieClient,
+    catalog: &NessieCatalog,
+    params: &EntriesParams,
+) -> Vec<Entry> {
+    let entries = RefSpec::named(catalog.branch())
+        .and_then(|reference| client.list_all_entries(&reference, params));
+    entries.unwrap_or_else(|e| {
+        log::warn!(
+            "Could not list the entries of branch {}: {e}",
+            catalog.branch()
+        );
+        Vec::new()
+    })
+}
+
+/// Schema name of a namespace.
+pub(crate) fn schema_name(key: &ContentKey) -> String {
+    key.to_path_string()
+}
 
+/// Namespace named by the schema name `name`, the inverse of [`schema_name`].
+pub(crate) fn schema_namespace(name: &str) -> Option<NamespaceIdent> {
+    let key = ContentKey::from_path_string(name).ok()?;
+    NamespaceIdent::from_vec(key.elements).ok()
 }
 
 impl CatalogProvider for NessieCatalogProvider {
-    fn as_any(&self) -> &dyn std::any::Any {
-        todo!()
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 
     fn schema_names(&self) -> Vec<String> {
-        todo!()
+        let params = EntriesParams::default().of_type(NAMESPACE);
+        let mut names: Vec<String> = list_entries(&self.client, &self.catalog, &params)
+            .iter()
+            .map(|entry| schema_name(&entry.name))
+            .collect();
+        names.sort();
+        names
     }
 
-    fn schema(&self, name: &str) -> Option<std::sync::Arc<dyn datafusion::catalog::SchemaProvider>> {
-        todo!()
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        let namespace = schema_namespace(name)?;
+        let params = EntriesParams {
+            keys: vec![ContentKey::new(namespace.iter())],
+            ..Default::default()
+        }
+        .of_type(NAMESPACE);
+        if list_entries(&self.client, &self.catalog, &params).is_empty() {
+            return None;
+        }
+        Some(Arc::new(NessieSchemaProvider::new(
+            self.catalog.clone(),
+            self.client.clone(),
+            namespace,
+        )))
     }
-    
+
+    /// Creates the namespace of schema `name`. DataFusion only registers
+    /// empty schemas, for `CREATE SCHEMA`, so `schema` itself is not kept.
     fn register_schema(
         &self,
         name: &str,
-        schema: std::sync::Arc<dyn datafusion::catalog::SchemaProvider>,
-    ) -> datafusion::error::Result<Option<std::sync::Arc<dyn datafusion::catalog::SchemaProvider>>> {
-        // use variables to avoid unused variable warnings
-        let _ = name;
-        let _ = schema;
-        datafusion::common::not_impl_err!("Registering new schemas is not supported")
+        schema: Arc<dyn SchemaProvider>,
+    ) -> Result<Option<Arc<dyn SchemaProvider>>> {
+        let Some(namespace) = schema_namespace(name) else {
+            return plan_err!("Invalid schema name {name}");
+        };
+        if !schema.table_names().is_empty() {
+            return not_impl_err!("Registering a schema with tables is not supported");
+        }
+
+        let catalog = self.catalog.clone();
+        self.client
+            .run(async move {
+                catalog
+                    .create_namespace(&namespace, Default::default())
+                    .await
+            })
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(None)
     }
-    
+
+    /// Drops the namespace of schema `name`. Nessie has no cascading
+    /// delete, so the namespace must be empty.
     fn deregister_schema(
         &self,
-        _name: &str,
-        _cascade: bool,
-    ) -> datafusion::error::Result<Option<std::sync::Arc<dyn datafusion::catalog::SchemaProvider>>> {
-        datafusion::common::not_impl_err!("Deregistering new schemas is not supported")
+        name: &str,
+        cascade: bool,
+    ) -> Result<Option<Arc<dyn SchemaProvider>>> {
+        let Some(schema) = self.schema(name) else {
+            return Ok(None);
+        };
+        if cascade {
+            return not_impl_err!("Dropping a schema with CASCADE is not supported");
+        }
+        if !schema.table_names().is_empty() {
+            return plan_err!("Cannot drop schema {name}: it has tables");
+        }
+
+        let catalog = self.catalog.clone();
+        let namespace = schema_namespace(name).expect("the schema exists");
+        self.client
+            .run(async move { catalog.drop_namespace(&namespace).await })
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(Some(schema))
     }
-}
\ No newline at end of file
+}
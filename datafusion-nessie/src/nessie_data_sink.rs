@@ -0,0 +1,223 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::sink::DataSink;
+use datafusion::error::Result;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::parquet::file::properties::WriterProperties;
+use datafusion::physical_plan::{DisplayAs, DisplayFormatType};
+use futures::TryStreamExt;
+use iceberg::spec::{DataFile, DataFileFormat, Struct};
+use iceberg::table::Table;
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::file_writer::ParquetWriterBuilder;
+use iceberg::writer::file_writer::location_generator::{
+    DefaultFileNameGenerator, DefaultLocationGenerator,
+};
+use iceberg::writer::{IcebergWriter, IcebergWriterBuilder};
+use iceberg::{Catalog, TableIdent};
+use nessie_iceberg_catalog::NessieCatalog;
+use uuid::Uuid;
+
+use crate::nessie_table_provider::{table_name, to_datafusion_error};
+use crate::overwrite::overwrite;
+use crate::partitioning::Partitioner;
+
+/// Table property choosing the [`OverwriteMode`] of `INSERT OVERWRITE`,
+/// `static` or `dynamic`.
+pub const OVERWRITE_MODE_PROPERTY: &str = "write.overwrite.mode";
+
+/// What `INSERT OVERWRITE` replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverwriteMode {
+    /// The whole table.
+    #[default]
+    Static,
+    /// Only the partitions the inserted rows are written to.
+    Dynamic,
+}
+
+impl OverwriteMode {
+    /// The mode set by the [`OVERWRITE_MODE_PROPERTY`] of `table`, static
+    /// unless it is `dynamic`.
+    pub fn of_table(table: &Table) -> Self {
+        match table.metadata().properties().get(OVERWRITE_MODE_PROPERTY) {
+            Some(mode) if mode.eq_ignore_ascii_case("dynamic") => Self::Dynamic,
+            _ => Self::Static,
+        }
+    }
+}
+
+impl fmt::Display for OverwriteMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Static => write!(f, "static"),
+            Self::Dynamic => write!(f, "dynamic"),
+        }
+    }
+}
+
+/// Writes the rows inserted into an Iceberg table as Parquet data files, at
+/// least one per partition of the table's spec, and commits them on the
+/// catalog's branch: an `append` snapshot adds them to the table, or an
+/// `overwrite` snapshot replaces data with them, see [`OverwriteMode`].
+#[derive(Debug)]
+pub struct NessieDataSink {
+    catalog: Arc<NessieCatalog>,
+    table: TableIdent,
+    schema: SchemaRef,
+    /// Appends if `None`.
+    overwrite: Option<OverwriteMode>,
+}
+
+impl NessieDataSink {
+    pub(crate) fn new(catalog: Arc<NessieCatalog>, table: TableIdent, schema: SchemaRef) -> Self {
+        Self {
+            catalog,
+            table,
+            schema,
+            overwrite: None,
+        }
+    }
+
+    /// Replaces data of the table instead of appending to it.
+    pub(crate) fn with_overwrite(mut self, mode: OverwriteMode) -> Self {
+        self.overwrite = Some(mode);
+        self
+    }
+}
+
+impl DisplayAs for NessieDataSink {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "NessieDataSink: table={}, branch={}",
+            table_name(&self.table),
+            self.catalog.branch()
+        )?;
+        if let Some(mode) = self.overwrite {
+            write!(f, ", overwrite={mode}")?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DataSink for NessieDataSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        // The latest metadata, so that the snapshot builds on the current
+        // snapshot of the branch.
+        let table = self
+            .catalog
+            .load_table(&self.table)
+            .await
+            .map_err(to_datafusion_error)?;
+        let data_files = write_data_files(&table, data).await?;
+        let rows = data_files.iter().map(DataFile::record_count).sum();
+        if let Some(mode) = self.overwrite {
+            overwrite(&self.catalog, &table, data_files, mode)
+                .await
+                .map_err(to_datafusion_error)?;
+            return Ok(rows);
+        }
+        if data_files.is_empty() {
+            return Ok(rows);
+        }
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .map_err(to_datafusion_error)?;
+        tx.commit(self.catalog.as_ref())
+            .await
+            .map_err(to_datafusion_error)?;
+        Ok(rows)
+    }
+}
+
+/// Writes `data` as Parquet data files of `table`, split by the partitions
+/// of its default spec.
+pub(crate) async fn write_data_files(
+    table: &Table,
+    mut data: SendableRecordBatchStream,
+) -> Result<Vec<DataFile>> {
+    let metadata = table.metadata();
+    let schema = Arc::new(
+        iceberg::arrow::schema_to_arrow_schema(metadata.current_schema())
+            .map_err(to_datafusion_error)?,
+    );
+    let mut partitioner = Partitioner::try_new(metadata.default_partition_spec().clone(), &schema)?;
+
+    let mut writers: HashMap<Vec<u8>, Box<dyn IcebergWriter>> = HashMap::new();
+    while let Some(batch) = data.try_next().await? {
+        // The columns of the table, with the field ids the writer maps them by.
+        let batch = RecordBatch::try_new(schema.clone(), batch.columns().to_vec())?;
+        for partition in partitioner.partition(&batch)? {
+            let writer = match writers.entry(partition.key) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let spec_id = partitioner.spec().spec_id();
+                    entry.insert(data_file_writer(table, partition.value, spec_id).await?)
+                }
+            };
+            writer
+                .write(partition.batch)
+                .await
+                .map_err(to_datafusion_error)?;
+        }
+    }
+
+    let mut data_files = Vec::new();
+    for (_, mut writer) in writers {
+        data_files.extend(writer.close().await.map_err(to_datafusion_error)?);
+    }
+    Ok(data_files)
+}
+
+/// Writer of the data files of one partition.
+async fn data_file_writer(
+    table: &Table,
+    partition: Struct,
+    spec_id: i32,
+) -> Result<Box<dyn IcebergWriter>> {
+    let metadata = table.metadata();
+    let location_generator =
+        DefaultLocationGenerator::new(metadata.clone()).map_err(to_datafusion_error)?;
+    // Each writer numbers its files from zero, so the prefix names them apart
+    // from those of the other partitions and writes.
+    let prefix = Uuid::new_v4().to_string();
+    let file_name_generator = DefaultFileNameGenerator::new(prefix, None, DataFileFormat::Parquet);
+    let parquet_writer = ParquetWriterBuilder::new(
+        WriterProperties::default(),
+        metadata.current_schema().clone(),
+        table.file_io().clone(),
+        location_generator,
+        file_name_generator,
+    );
+    let writer = DataFileWriterBuilder::new(parquet_writer, Some(partition), spec_id)
+        .build()
+        .await
+        .map_err(to_datafusion_error)?;
+    Ok(Box::new(writer))
+}
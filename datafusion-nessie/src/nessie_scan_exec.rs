@@ -0,0 +1,439 @@
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use datafusion::arrow::array::new_null_array;
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
+use datafusion::physical_expr::EquivalenceProperties;
+use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties,
+};
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt, future, stream};
+use iceberg::arrow::ArrowFileReader;
+use iceberg::expr::Predicate;
+use iceberg::io::{FileIO, FileRead};
+use iceberg::scan::FileScanTask;
+use iceberg::spec::ManifestList;
+use iceberg::table::Table;
+
+use crate::deletes::{DeleteFiles, field_id, retain_applying_deletes};
+use crate::nessie_table_provider::{table_name, to_datafusion_error};
+
+/// Table property of the size of the splits big data files are read in.
+pub const SPLIT_SIZE_PROPERTY: &str = "read.split.target-size";
+/// Split size of tables without [`SPLIT_SIZE_PROPERTY`], as in Iceberg.
+pub const DEFAULT_SPLIT_SIZE: u64 = 128 * 1024 * 1024;
+
+/// What a [`NessieScanExec`] reads.
+#[derive(Debug, Clone)]
+pub(crate) struct ScanOptions {
+    pub snapshot_id: Option<i64>,
+    /// Names of the columns read, all of them if `None`.
+    pub columns: Option<Vec<String>>,
+    pub predicate: Option<Predicate>,
+    /// Most partitions the scan is split into.
+    pub target_partitions: usize,
+    /// Size in bytes of the splits of big data files, the table's
+    /// [`SPLIT_SIZE_PROPERTY`] if `None`.
+    pub split_size: Option<u64>,
+}
+
+/// Reads a snapshot of an Iceberg table in parallel.
+///
+/// Iceberg plans the data files from the manifest list and manifests, pruned
+/// with the predicate. Files bigger than the split size are split at the row
+/// group boundaries found in their Parquet footer, and the splits are
+/// bin-packed by size into at most `target_partitions` partitions. Each
+/// partition reads only the projected columns of its Parquet files, without
+/// the rows removed by their position and equality delete files, each of
+/// which is read once per scan.
+#[derive(Debug)]
+pub struct NessieScanExec {
+    table: Table,
+    options: ScanOptions,
+    /// Splits read by each partition.
+    partitions: Vec<Vec<ScanSplit>>,
+    /// Delete files read by the partitions so far.
+    delete_files: Arc<DeleteFiles>,
+    schema: SchemaRef,
+    properties: PlanProperties,
+}
+
+impl NessieScanExec {
+    pub(crate) async fn try_new(
+        table: Table,
+        options: ScanOptions,
+        schema: SchemaRef,
+    ) -> Result<Self> {
+        // Iceberg indexes the delete files before it takes the entries of the
+        // data manifests, which it stops reading once they fill its queue:
+        // the manifests are read all at once, or a delete manifest after a
+        // full data manifest would never be.
+        let manifest_list = manifest_list(&table, options.snapshot_id).await?;
+        let manifests = manifest_list
+            .as_ref()
+            .map_or(0, |list| list.entries().len());
+        let concurrency = std::thread::available_parallelism().map_or(1, usize::from);
+        let mut scan = table
+            .scan()
+            .with_concurrency_limit(manifests.max(concurrency));
+        if let Some(snapshot_id) = options.snapshot_id {
+            scan = scan.snapshot_id(snapshot_id);
+        }
+        if let Some(columns) = &options.columns {
+            scan = scan.select(columns.clone());
+        }
+        if let Some(predicate) = &options.predicate {
+            scan = scan.with_filter(predicate.clone());
+        }
+        let mut tasks: Vec<FileScanTask> = scan
+            .build()
+            .map_err(to_datafusion_error)?
+            .plan_files()
+            .await
+            .map_err(to_datafusion_error)?
+            .try_collect()
+            .await
+            .map_err(to_datafusion_error)?;
+        if let Some(manifest_list) = &manifest_list {
+            retain_applying_deletes(table.file_io(), manifest_list, &mut tasks).await?;
+        }
+
+        let split_size = options.split_size.unwrap_or_else(|| split_size(&table));
+        let splits = future::try_join_all(
+            tasks
+                .into_iter()
+                .map(|task| split(table.file_io(), task, split_size)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        let partitions = bin_pack(splits, options.target_partitions);
+
+        let properties = PlanProperties::new(
+            EquivalenceProperties::new(schema.clone()),
+            Partitioning::UnknownPartitioning(partitions.len()),
+            EmissionType::Incremental,
+            Boundedness::Bounded,
+        );
+        Ok(Self {
+            table,
+            options,
+            partitions,
+            delete_files: Arc::default(),
+            schema,
+            properties,
+        })
+    }
+
+    pub fn predicate(&self) -> Option<&Predicate> {
+        self.options.predicate.as_ref()
+    }
+
+    /// Splits read by each partition.
+    pub fn partitions(&self) -> &[Vec<ScanSplit>] {
+        &self.partitions
+    }
+
+    fn splits(&self) -> impl Iterator<Item = &FileScanTask> {
+        self.partitions.iter().flatten().map(|split| &split.task)
+    }
+}
+
+/// A data file, or some of its row groups, read by one partition.
+#[derive(Debug, Clone)]
+pub struct ScanSplit {
+    /// The file, with `start` and `length` the byte range of the row groups.
+    pub task: FileScanTask,
+    /// Indices of the row groups read, all of them if `None`.
+    pub row_groups: Option<Range<usize>>,
+}
+
+/// Manifest list of the snapshot scanned, the current one if `None`.
+async fn manifest_list(table: &Table, snapshot_id: Option<i64>) -> Result<Option<ManifestList>> {
+    let metadata = table.metadata();
+    let snapshot = match snapshot_id {
+        Some(snapshot_id) => metadata.snapshot_by_id(snapshot_id),
+        None => metadata.current_snapshot(),
+    };
+    let Some(snapshot) = snapshot else {
+        return Ok(None);
+    };
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(to_datafusion_error)?;
+    Ok(Some(manifest_list))
+}
+
+fn split_size(table: &Table) -> u64 {
+    table
+        .metadata()
+        .properties()
+        .get(SPLIT_SIZE_PROPERTY)
+        .and_then(|size| size.parse().ok())
+        .filter(|size| *size > 0)
+        .unwrap_or(DEFAULT_SPLIT_SIZE)
+}
+
+/// Splits `task` at row group boundaries in parts of at most `split_size`
+/// bytes, or of one bigger row group.
+async fn split(file_io: &FileIO, task: FileScanTask, split_size: u64) -> Result<Vec<ScanSplit>> {
+    if task.length <= split_size {
+        return Ok(vec![ScanSplit {
+            task,
+            row_groups: None,
+        }]);
+    }
+    let reader = parquet_reader(file_io, &task.data_file_path).await?;
+    let row_groups = reader.metadata().row_groups();
+
+    let mut splits = Vec::new();
+    let mut first = 0;
+    while first < row_groups.len() {
+        let mut end = first;
+        let mut length = 0;
+        let mut record_count = 0;
+        while end < row_groups.len()
+            && (end == first || length + row_groups[end].compressed_size() as u64 <= split_size)
+        {
+            length += row_groups[end].compressed_size() as u64;
+            record_count += row_groups[end].num_rows() as u64;
+            end += 1;
+        }
+        let start = row_groups[first]
+            .columns()
+            .first()
+            .map_or(0, |column| column.byte_range().0);
+        splits.push(ScanSplit {
+            task: FileScanTask {
+                start,
+                length,
+                record_count: Some(record_count),
+                ..task.clone()
+            },
+            row_groups: Some(first..end),
+        });
+        first = end;
+    }
+    if splits.len() <= 1 {
+        return Ok(vec![ScanSplit {
+            task,
+            row_groups: None,
+        }]);
+    }
+    Ok(splits)
+}
+
+/// Distributes `splits` into at most `partitions` groups of similar sizes,
+/// adding the biggest remaining split to the smallest group.
+fn bin_pack(mut splits: Vec<ScanSplit>, partitions: usize) -> Vec<Vec<ScanSplit>> {
+    let partitions = partitions.clamp(1, splits.len().max(1));
+    let mut groups: Vec<(u64, Vec<ScanSplit>)> = vec![(0, Vec::new()); partitions];
+    splits.sort_by_key(|split| Reverse(split.task.length));
+    for split in splits {
+        let (size, group) = groups.iter_mut().min_by_key(|(size, _)| *size).unwrap();
+        *size += split.task.length;
+        group.push(split);
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+impl DisplayAs for NessieScanExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        let files: HashSet<&str> = self
+            .splits()
+            .map(|split| split.data_file_path.as_str())
+            .collect();
+        let bytes: u64 = self.splits().map(|split| split.length).sum();
+        write!(
+            f,
+            "NessieScanExec: table={}, files={}, bytes={bytes}",
+            table_name(self.table.identifier()),
+            files.len()
+        )?;
+        if let Some(snapshot_id) = self.options.snapshot_id {
+            write!(f, ", snapshot={snapshot_id}")?;
+        }
+        if let Some(columns) = &self.options.columns {
+            write!(f, ", projection=[{}]", columns.join(", "))?;
+        }
+        if let Some(predicate) = &self.options.predicate {
+            write!(f, ", predicate={predicate}")?;
+        }
+        if let DisplayFormatType::Verbose = t {
+            for (i, partition) in self.partitions.iter().enumerate() {
+                let splits: Vec<String> = partition
+                    .iter()
+                    .map(|split| &split.task)
+                    .map(|split| {
+                        format!(
+                            "{}:{}..{}",
+                            split.data_file_path,
+                            split.start,
+                            split.start + split.length
+                        )
+                    })
+                    .collect();
+                write!(f, ", partition {i}=[{}]", splits.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ExecutionPlan for NessieScanExec {
+    fn name(&self) -> &str {
+        "NessieScanExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let file_io = self.table.file_io().clone();
+        let batch_size = context.session_config().batch_size();
+        let schema = self.schema.clone();
+        let delete_files = self.delete_files.clone();
+        let batches = stream::iter(self.partitions[partition].clone())
+            .then(move |split| {
+                read_split(
+                    file_io.clone(),
+                    batch_size,
+                    schema.clone(),
+                    delete_files.clone(),
+                    split,
+                )
+            })
+            .try_flatten();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            batches,
+        )))
+    }
+}
+
+/// Parquet reader of the data file at `path`, with its footer read.
+async fn parquet_reader(
+    file_io: &FileIO,
+    path: &str,
+) -> Result<ParquetRecordBatchStreamBuilder<ArrowFileReader<impl FileRead>>> {
+    let input = file_io.new_input(path).map_err(to_datafusion_error)?;
+    let metadata = input.metadata().await.map_err(to_datafusion_error)?;
+    let reader = input.reader().await.map_err(to_datafusion_error)?;
+    Ok(ParquetRecordBatchStreamBuilder::new(ArrowFileReader::new(metadata, reader)).await?)
+}
+
+/// Reads the row groups of `split` with the Parquet reader, without the
+/// rows its delete files delete. The predicate only pruned the files of the
+/// scan: it is not applied to the rows, DataFusion filters them again.
+async fn read_split(
+    file_io: FileIO,
+    batch_size: usize,
+    schema: SchemaRef,
+    delete_files: Arc<DeleteFiles>,
+    split: ScanSplit,
+) -> Result<BoxStream<'static, Result<RecordBatch>>> {
+    let ScanSplit { task, row_groups } = split;
+    let deletes = delete_files.load(&file_io, &task).await?;
+    let reader = parquet_reader(&file_io, &task.data_file_path).await?;
+    let row_groups = row_groups.unwrap_or(0..reader.metadata().num_row_groups());
+    // Positions of deleted rows count from the first row of the file.
+    let mut position: i64 = reader.metadata().row_groups()[..row_groups.start]
+        .iter()
+        .map(|row_group| row_group.num_rows())
+        .sum();
+    let field_ids: Vec<i32> = schema
+        .fields()
+        .iter()
+        .filter_map(|field| field_id(field))
+        .chain(deletes.field_ids())
+        .collect();
+    let columns = reader
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field_id(field).is_some_and(|id| field_ids.contains(&id)))
+        .map(|(i, _)| i);
+    let projection = ProjectionMask::roots(reader.parquet_schema(), columns);
+    let batches = reader
+        .with_row_groups(row_groups.collect())
+        .with_projection(projection)
+        .with_batch_size(batch_size)
+        .build()?;
+    Ok(batches
+        .map_err(DataFusionError::from)
+        .and_then(move |batch| {
+            let first_position = position;
+            position += batch.num_rows() as i64;
+            future::ready(
+                deletes
+                    .apply(&batch, first_position)
+                    .and_then(|batch| to_table_schema(&batch, &schema)),
+            )
+        })
+        .boxed())
+}
+
+/// `batch` read from a data file with the columns of `schema`, matched by
+/// field id: cast to the type of the table, or null if the file predates the
+/// column.
+fn to_table_schema(batch: &RecordBatch, schema: &SchemaRef) -> Result<RecordBatch> {
+    let batch_schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let column = field_id(field).and_then(|id| {
+                batch_schema
+                    .fields()
+                    .iter()
+                    .position(|column| field_id(column) == Some(id))
+            });
+            Ok(match column {
+                Some(i) => cast(batch.column(i), field.data_type())?,
+                None => new_null_array(field.data_type(), batch.num_rows()),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        schema.clone(),
+        columns,
+        &options,
+    )?)
+}
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::catalog::{SchemaProvider, TableProvider};
+use datafusion::common::not_impl_err;
+use datafusion::error::Result;
+use iceberg::{Catalog, ErrorKind, NamespaceIdent, TableIdent};
+use nessie_client::blocking::BlockingNessieClient;
+use nessie_client::models::{ContentKey, EntriesParams};
+use nessie_iceberg_catalog::NessieCatalog;
+use nessie_iceberg_catalog::catalog::ICEBERG_TABLE;
+
+use crate::nessie_catalog_provider::list_entries;
+use crate::nessie_table_provider::{NessieTableProvider, to_datafusion_error};
+
+/// DataFusion schema over the Iceberg tables directly in one Nessie namespace.
+///
+/// Nested namespaces are not tables of their parent: each one is a schema of
+/// its own, named as described on [`NessieCatalogProvider`]. As DataFusion
+/// splits table references on `.`, such a schema is quoted in SQL, as in
+/// `SELECT * FROM nessie."db.staging".events`.
+///
+/// [`NessieCatalogProvider`]: crate::NessieCatalogProvider
+#[derive(Debug)]
+pub struct NessieSchemaProvider {
+    catalog: Arc<NessieCatalog>,
+    client: Arc<BlockingNessieClient>,
+    namespace: NamespaceIdent,
+}
+
+impl NessieSchemaProvider {
+    pub(crate) fn new(
+        catalog: Arc<NessieCatalog>,
+        client: Arc<BlockingNessieClient>,
+        namespace: NamespaceIdent,
+    ) -> Self {
+        Self {
+            catalog,
+            client,
+            namespace,
+        }
+    }
+
+    pub fn namespace(&self) -> &NamespaceIdent {
+        &self.namespace
+    }
+
+    fn namespace_key(&self) -> ContentKey {
+        ContentKey::new(self.namespace.iter())
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for NessieSchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        let params = EntriesParams::children_of(Some(&self.namespace_key())).of_type(ICEBERG_TABLE);
+        let mut names: Vec<String> = list_entries(&self.client, &self.catalog, &params)
+            .iter()
+            .map(|entry| entry.name.name().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    async fn table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let ident = TableIdent::new(self.namespace.clone(), name.to_string());
+        match self.catalog.load_table(&ident).await {
+            Ok(table) => {
+                let provider =
+                    NessieTableProvider::try_new(table)?.with_catalog(self.catalog.clone());
+                Ok(Some(Arc::new(provider)))
+            }
+            Err(e) if e.kind() == ErrorKind::TableNotFound => Ok(None),
+            Err(e) => Err(to_datafusion_error(e)),
+        }
+    }
+
+    fn table_exist(&self, name: &str) -> bool {
+        let mut key = self.namespace_key();
+        key.elements.push(name.to_string());
+        if key.validate().is_err() {
+            return false;
+        }
+        let params = EntriesParams {
+            keys: vec![key],
+            ..Default::default()
+        }
+        .of_type(ICEBERG_TABLE);
+        !list_entries(&self.client, &self.catalog, &params).is_empty()
+    }
+
+    /// DataFusion registers the in-memory tables of its `CREATE TABLE` here,
+    /// which cannot be kept on the branch.
+    fn register_table(
+        &self,
+        name: String,
+        _table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        not_impl_err!(
+            "Cannot register table {name} in a Nessie schema: create Iceberg tables with datafusion_nessie::sql"
+        )
+    }
+}
@@ -0,0 +1,418 @@
+//! `CREATE TABLE` for Iceberg tables.
+//!
+//! DataFusion plans `CREATE TABLE` as an in-memory table and cannot parse
+//! partition transforms, so [`sql`] parses the statements creating a table
+//! in a [`NessieCatalogProvider`] itself:
+//!
+//! ```sql
+//! CREATE TABLE [IF NOT EXISTS] nessie.db.events (id BIGINT NOT NULL, ts TIMESTAMP, ...)
+//!     [PARTITIONED BY (bucket(16, id), day(ts), ...)]
+//!     [WITH ('write.overwrite.mode' = 'dynamic', ...)]
+//!     [AS SELECT ...]
+//! ```
+//!
+//! The columns, or the query of a `CREATE TABLE ... AS SELECT`, are planned
+//! by DataFusion, and the Arrow schema they give is the Iceberg schema of the
+//! table. Partitions are columns or one of the transforms `bucket(n, column)`,
+//! `truncate(width, column)`, `year(column)`, `month(column)`, `day(column)`
+//! and `hour(column)`, and the `WITH` options are the table properties. The
+//! rows of the query are inserted before [`sql`] returns, and the table is
+//! dropped again if they cannot be.
+//!
+//! [`sql`] is the only way to create a table: [`SessionContext::sql`] plans
+//! `CREATE TABLE` as an in-memory table, which a [`NessieCatalogProvider`]
+//! refuses to register.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, Field, Fields, Schema as ArrowSchema, TimeUnit};
+use datafusion::catalog::TableProvider;
+use datafusion::common::{
+    ResolvedTableReference, TableReference, internal_err, not_impl_err, plan_datafusion_err,
+    plan_err,
+};
+use datafusion::dataframe::DataFrame;
+use datafusion::datasource::provider_as_source;
+use datafusion::error::Result;
+use datafusion::logical_expr::dml::InsertOp;
+use datafusion::logical_expr::{DdlStatement, Expr, LogicalPlan, LogicalPlanBuilder, cast};
+use datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::Statement as DFStatement;
+use datafusion::sql::planner::{IdentNormalizer, object_name_to_table_reference};
+use datafusion::sql::sqlparser::ast::helpers::stmt_create_table::CreateTableBuilder;
+use datafusion::sql::sqlparser::ast::{
+    ColumnDef, Expr as SqlExpr, FunctionArg, FunctionArgExpr, FunctionArguments, HiveFormat,
+    ObjectName, Query,
+};
+use datafusion::sql::sqlparser::dialect::{Dialect, dialect_from_str};
+use datafusion::sql::sqlparser::keywords::Keyword;
+use datafusion::sql::sqlparser::parser::{Parser, ParserError};
+use datafusion::sql::sqlparser::tokenizer::Token;
+use iceberg::arrow::arrow_schema_to_schema;
+use iceberg::spec::{Schema, Transform, UnboundPartitionSpec};
+use iceberg::{Catalog, TableCreation, TableIdent};
+use nessie_iceberg_catalog::NessieCatalog;
+
+use crate::nessie_catalog_provider::{NessieCatalogProvider, schema_namespace};
+use crate::nessie_table_provider::{NessieTableProvider, to_datafusion_error};
+
+/// Runs `sql` in `ctx`, creating the tables of `CREATE TABLE` statements on
+/// a [`NessieCatalogProvider`] in its catalog. Other statements are run by
+/// [`SessionContext::sql`].
+pub async fn sql(ctx: &SessionContext, sql: &str) -> Result<DataFrame> {
+    let state = ctx.state();
+    let options = &state.config().options().sql_parser;
+    let Some(dialect) = dialect_from_str(&options.dialect) else {
+        return ctx.sql(sql).await;
+    };
+    // Statements that do not create a table in a Nessie catalog, or that
+    // cannot be read as one, are left to DataFusion.
+    let Some(name) = create_table_name(dialect.as_ref(), sql) else {
+        return ctx.sql(sql).await;
+    };
+    let normalization = options.enable_ident_normalization;
+    let Ok(name) = object_name_to_table_reference(name, normalization) else {
+        return ctx.sql(sql).await;
+    };
+    let defaults = &state.config_options().catalog;
+    let name = name.resolve(&defaults.default_catalog, &defaults.default_schema);
+    let Some(catalog) = ctx.catalog(&name.catalog) else {
+        return ctx.sql(sql).await;
+    };
+    let Some(provider) = catalog.as_any().downcast_ref::<NessieCatalogProvider>() else {
+        return ctx.sql(sql).await;
+    };
+
+    let create = parse_create_table(dialect.as_ref(), sql)?;
+    create_table(
+        ctx,
+        provider.catalog(),
+        name,
+        create,
+        &IdentNormalizer::new(normalization),
+    )
+    .await
+}
+
+/// A parsed `CREATE TABLE` statement.
+#[derive(Debug)]
+struct CreateTable {
+    name: ObjectName,
+    or_replace: bool,
+    if_not_exists: bool,
+    columns: Vec<ColumnDef>,
+    partitioned_by: Vec<SqlExpr>,
+    properties: HashMap<String, String>,
+    query: Option<Box<Query>>,
+}
+
+/// Name of the table created by `sql`, if it starts as a `CREATE TABLE`
+/// statement.
+fn create_table_name(dialect: &dyn Dialect, sql: &str) -> Option<ObjectName> {
+    let mut parser = Parser::new(dialect).try_with_sql(sql).ok()?;
+    parse_create_table_header(&mut parser).map(|(name, _, _)| name)
+}
+
+/// Name, `OR REPLACE` and `IF NOT EXISTS` of a `CREATE TABLE` statement.
+fn parse_create_table_header(parser: &mut Parser) -> Option<(ObjectName, bool, bool)> {
+    if !parser.parse_keyword(Keyword::CREATE) {
+        return None;
+    }
+    let or_replace = parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+    if !parser.parse_keyword(Keyword::TABLE) {
+        return None;
+    }
+    let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false).ok()?;
+    Some((name, or_replace, if_not_exists))
+}
+
+/// Parses `sql`, a single `CREATE TABLE` statement.
+fn parse_create_table(dialect: &dyn Dialect, sql: &str) -> Result<CreateTable> {
+    let mut parser = Parser::new(dialect).try_with_sql(sql)?;
+    let Some((name, or_replace, if_not_exists)) = parse_create_table_header(&mut parser) else {
+        return internal_err!("Not a CREATE TABLE statement: {sql}");
+    };
+    let (columns, constraints) = parser.parse_columns()?;
+    if !constraints.is_empty() {
+        return not_impl_err!("Constraints are not supported for Iceberg tables");
+    }
+
+    let mut partitioned_by = Vec::new();
+    if parser.parse_keywords(&[Keyword::PARTITIONED, Keyword::BY]) {
+        parser.expect_token(&Token::LParen)?;
+        partitioned_by = parser.parse_comma_separated(Parser::parse_expr)?;
+        parser.expect_token(&Token::RParen)?;
+    }
+    let mut properties = HashMap::new();
+    if parser.parse_keyword(Keyword::WITH) {
+        parser.expect_token(&Token::LParen)?;
+        properties = parser
+            .parse_comma_separated(parse_property)?
+            .into_iter()
+            .collect();
+        parser.expect_token(&Token::RParen)?;
+    }
+    let query = if parser.parse_keyword(Keyword::AS) {
+        Some(parser.parse_query()?)
+    } else {
+        None
+    };
+
+    while parser.consume_token(&Token::SemiColon) {}
+    let next = parser.peek_token();
+    if next.token != Token::EOF {
+        parser.expected::<()>("end of statement", next)?;
+    }
+    Ok(CreateTable {
+        name,
+        or_replace,
+        if_not_exists,
+        columns,
+        partitioned_by,
+        properties,
+        query,
+    })
+}
+
+/// A table property, `'key' = 'value'`, the key also as dotted identifiers
+/// and the value as a number or a word.
+fn parse_property(parser: &mut Parser) -> Result<(String, String), ParserError> {
+    let next = parser.next_token();
+    let key = match next.token {
+        Token::SingleQuotedString(key) => key,
+        Token::Word(word) => {
+            let mut key = word.value;
+            while parser.consume_token(&Token::Period) {
+                key.push('.');
+                key.push_str(&parser.parse_identifier()?.value);
+            }
+            key
+        }
+        _ => return parser.expected("a table property", next),
+    };
+    parser.expect_token(&Token::Eq)?;
+    let next = parser.next_token();
+    let value = match next.token {
+        Token::SingleQuotedString(value) | Token::Number(value, _) => value,
+        Token::Word(word) => word.value,
+        _ => return parser.expected("a table property value", next),
+    };
+    Ok((key, value))
+}
+
+async fn create_table(
+    ctx: &SessionContext,
+    catalog: &Arc<NessieCatalog>,
+    name: ResolvedTableReference,
+    create: CreateTable,
+    normalizer: &IdentNormalizer,
+) -> Result<DataFrame> {
+    if create.or_replace {
+        return not_impl_err!("CREATE OR REPLACE TABLE is not supported for Iceberg tables");
+    }
+    let Some(namespace) = schema_namespace(&name.schema) else {
+        return plan_err!("Invalid schema name {}", name.schema);
+    };
+    let ident = TableIdent::new(namespace.clone(), name.table.to_string());
+    if catalog
+        .table_exists(&ident)
+        .await
+        .map_err(to_datafusion_error)?
+    {
+        if create.if_not_exists {
+            return ctx
+                .execute_logical_plan(LogicalPlanBuilder::empty(false).build()?)
+                .await;
+        }
+        return plan_err!("Table {name} already exists");
+    }
+
+    // DataFusion plans the columns, or the query, as an in-memory table.
+    let has_query = create.query.is_some();
+    // The parser gives plain statements empty Hive formats, which is all the
+    // planner accepts.
+    let statement = CreateTableBuilder::new(create.name)
+        .columns(create.columns)
+        .query(create.query)
+        .hive_formats(Some(HiveFormat::default()))
+        .build();
+    let plan = ctx
+        .state()
+        .statement_to_plan(DFStatement::Statement(Box::new(statement)))
+        .await?;
+    let LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(plan)) = plan else {
+        return internal_err!("CREATE TABLE was not planned as a table: {plan}");
+    };
+    let input = Arc::unwrap_or_clone(plan.input);
+
+    let schema = iceberg_schema(input.schema().as_arrow())?;
+    let mut spec = UnboundPartitionSpec::builder();
+    for expr in &create.partitioned_by {
+        let (column, transform) = partition_transform(expr, normalizer)?;
+        let Some(field) = schema.field_by_name(&column) else {
+            return plan_err!("Partition column {column} of table {name} does not exist");
+        };
+        spec = spec
+            .add_partition_field(field.id, partition_name(&column, transform), transform)
+            .map_err(to_datafusion_error)?;
+    }
+    let creation = TableCreation::builder()
+        .name(ident.name().to_string())
+        .schema(schema)
+        .partition_spec(spec.build())
+        .properties(create.properties)
+        .build();
+    let table = catalog
+        .create_table(&namespace, creation)
+        .await
+        .map_err(to_datafusion_error)?;
+    if !has_query {
+        return ctx
+            .execute_logical_plan(LogicalPlanBuilder::empty(false).build()?)
+            .await;
+    }
+
+    // Inserts the rows of the query, as the types of the table.
+    let provider = NessieTableProvider::try_new(table)?.with_catalog(catalog.clone());
+    let columns: Vec<Expr> = provider
+        .schema()
+        .fields()
+        .iter()
+        .zip(input.schema().columns())
+        .map(|(field, column)| {
+            cast(Expr::Column(column), field.data_type().clone()).alias(field.name())
+        })
+        .collect();
+    let input = LogicalPlanBuilder::from(input).project(columns)?.build()?;
+    let insert = LogicalPlanBuilder::insert_into(
+        input,
+        TableReference::full(
+            name.catalog.clone(),
+            name.schema.clone(),
+            name.table.clone(),
+        ),
+        provider_as_source(Arc::new(provider)),
+        InsertOp::Append,
+    )?
+    .build()?;
+    // The table is only created if its rows are inserted.
+    let inserted = match ctx.execute_logical_plan(insert).await {
+        Ok(insert) => insert.collect().await,
+        Err(e) => Err(e),
+    };
+    match inserted {
+        Ok(batches) => ctx.read_batches(batches),
+        Err(e) => {
+            if let Err(drop) = catalog.drop_table(&ident).await {
+                log::warn!("Could not drop table {name} after its insert failed: {drop}");
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Iceberg schema of the columns of `schema`, with fresh field ids. Types
+/// Iceberg does not have are stored as the closest one: views as their
+/// plain arrays, and times and timestamps in microseconds.
+fn iceberg_schema(schema: &ArrowSchema) -> Result<Schema> {
+    let mut next_id = 1;
+    let fields: Fields = schema
+        .fields()
+        .iter()
+        .map(|field| with_field_id(field, &mut next_id))
+        .collect();
+    arrow_schema_to_schema(&ArrowSchema::new(fields)).map_err(to_datafusion_error)
+}
+
+fn with_field_id(field: &Field, next_id: &mut i32) -> Field {
+    let id = *next_id;
+    *next_id += 1;
+    let data_type = match field.data_type() {
+        DataType::Utf8View => DataType::Utf8,
+        DataType::BinaryView => DataType::Binary,
+        DataType::Time64(_) => DataType::Time64(TimeUnit::Microsecond),
+        DataType::Timestamp(_, tz) => DataType::Timestamp(TimeUnit::Microsecond, tz.clone()),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| with_field_id(field, next_id))
+                .collect(),
+        ),
+        DataType::List(item) => DataType::List(Arc::new(with_field_id(item, next_id))),
+        DataType::LargeList(item) => DataType::LargeList(Arc::new(with_field_id(item, next_id))),
+        DataType::Map(entries, sorted) => {
+            DataType::Map(Arc::new(with_field_id(entries, next_id)), *sorted)
+        }
+        data_type => data_type.clone(),
+    };
+    field
+        .clone()
+        .with_data_type(data_type)
+        .with_metadata(HashMap::from([(
+            PARQUET_FIELD_ID_META_KEY.to_string(),
+            id.to_string(),
+        )]))
+}
+
+/// Source column and transform of a partition of `PARTITIONED BY`.
+fn partition_transform(
+    expr: &SqlExpr,
+    normalizer: &IdentNormalizer,
+) -> Result<(String, Transform)> {
+    let SqlExpr::Function(function) = expr else {
+        return Ok((column_name(expr, normalizer)?, Transform::Identity));
+    };
+    let FunctionArguments::List(list) = &function.args else {
+        return plan_err!("Unsupported partition transform {expr}");
+    };
+    let args = list
+        .args
+        .iter()
+        .map(|arg| match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) => Ok(arg),
+            _ => plan_err!("Unsupported argument {arg} of partition transform {expr}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let transform = function.name.to_string().to_lowercase();
+    let (transform, column) = match (transform.as_str(), args.as_slice()) {
+        ("identity", [column]) => (Transform::Identity, column),
+        ("bucket", [n, column]) => (Transform::Bucket(number(n)?), column),
+        ("truncate", [width, column]) => (Transform::Truncate(number(width)?), column),
+        ("year" | "years", [column]) => (Transform::Year, column),
+        ("month" | "months", [column]) => (Transform::Month, column),
+        ("day" | "days", [column]) => (Transform::Day, column),
+        ("hour" | "hours", [column]) => (Transform::Hour, column),
+        _ => return plan_err!("Unsupported partition transform {expr}"),
+    };
+    Ok((column_name(column, normalizer)?, transform))
+}
+
+fn column_name(expr: &SqlExpr, normalizer: &IdentNormalizer) -> Result<String> {
+    match expr {
+        SqlExpr::Identifier(ident) => Ok(normalizer.normalize(ident.clone())),
+        _ => plan_err!("Expected a column, found {expr}"),
+    }
+}
+
+fn number(expr: &SqlExpr) -> Result<u32> {
+    expr.to_string()
+        .parse()
+        .map_err(|_| plan_datafusion_err!("Expected a positive number, found {expr}"))
+}
+
+/// Name of the partition field, as Iceberg names them.
+fn partition_name(column: &str, transform: Transform) -> String {
+    match transform {
+        Transform::Bucket(_) => format!("{column}_bucket"),
+        Transform::Truncate(_) => format!("{column}_trunc"),
+        Transform::Year => format!("{column}_year"),
+        Transform::Month => format!("{column}_month"),
+        Transform::Day => format!("{column}_day"),
+        Transform::Hour => format!("{column}_hour"),
+        _ => column.to_string(),
+    }
+}
@@ -0,0 +1,203 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::common::{not_impl_err, plan_err};
+use datafusion::datasource::TableType;
+use datafusion::datasource::sink::DataSinkExec;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::dml::InsertOp;
+use datafusion::logical_expr::{Expr, TableProviderFilterPushDown};
+use datafusion::physical_plan::ExecutionPlan;
+use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::table::Table;
+use iceberg::{Error, ErrorKind, TableIdent};
+use nessie_iceberg_catalog::NessieCatalog;
+
+use crate::expr_to_predicate::{convert_filter, convert_filters};
+use crate::nessie_data_sink::{NessieDataSink, OverwriteMode};
+use crate::nessie_scan_exec::{NessieScanExec, ScanOptions};
+
+/// DataFusion table over a snapshot of an Iceberg table, the current one
+/// unless another is chosen with [`NessieTableProvider::with_snapshot`].
+///
+/// Projections are pushed down to the Parquet reader, and the filters that
+/// convert to Iceberg predicates prune partitions, data files and row groups.
+/// Scans read the data files in up to `target_partitions` partitions, see
+/// [`NessieScanExec`]. Tables with a catalog, see
+/// [`NessieTableProvider::with_catalog`], take inserts, appending or
+/// overwriting, see [`NessieDataSink`].
+#[derive(Debug)]
+pub struct NessieTableProvider {
+    table: Table,
+    /// Catalog committing the writes to the table, read-only if `None`.
+    catalog: Option<Arc<NessieCatalog>>,
+    snapshot_id: Option<i64>,
+    split_size: Option<u64>,
+    overwrite_mode: Option<OverwriteMode>,
+    schema: SchemaRef,
+}
+
+impl NessieTableProvider {
+    pub fn try_new(table: Table) -> Result<Self> {
+        let schema = schema_to_arrow_schema(table.metadata().current_schema())
+            .map_err(to_datafusion_error)?;
+        Ok(Self {
+            table,
+            catalog: None,
+            snapshot_id: None,
+            split_size: None,
+            overwrite_mode: None,
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Reads snapshot `snapshot_id` of the table, with the schema it was
+    /// written with.
+    pub fn with_snapshot(mut self, snapshot_id: i64) -> Result<Self> {
+        let metadata = self.table.metadata();
+        let snapshot = metadata.snapshot_by_id(snapshot_id).ok_or_else(|| {
+            to_datafusion_error(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Snapshot {snapshot_id} of table {} does not exist",
+                    table_name(self.table.identifier())
+                ),
+            ))
+        })?;
+        let schema = snapshot.schema(metadata).map_err(to_datafusion_error)?;
+        self.schema = Arc::new(schema_to_arrow_schema(&schema).map_err(to_datafusion_error)?);
+        self.snapshot_id = Some(snapshot_id);
+        Ok(self)
+    }
+
+    /// Commits the rows inserted into the table through `catalog`, on its
+    /// branch.
+    pub fn with_catalog(mut self, catalog: Arc<NessieCatalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Reads data files in splits of `split_size` bytes instead of the size
+    /// set by the table's `read.split.target-size`.
+    pub fn with_split_size(mut self, split_size: u64) -> Self {
+        self.split_size = Some(split_size);
+        self
+    }
+
+    /// Overwrites in `mode` instead of the mode set by the table's
+    /// `write.overwrite.mode`.
+    pub fn with_overwrite_mode(mut self, mode: OverwriteMode) -> Self {
+        self.overwrite_mode = Some(mode);
+        self
+    }
+
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+}
+
+pub(crate) fn to_datafusion_error(error: iceberg::Error) -> DataFusionError {
+    DataFusionError::External(Box::new(error))
+}
+
+/// Name of a table in messages and plans, `namespace.table`.
+pub(crate) fn table_name(table: &TableIdent) -> String {
+    format!("{}.{}", table.namespace().join("."), table.name())
+}
+
+#[async_trait]
+impl TableProvider for NessieTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let (columns, schema) = match projection {
+            Some(projection) => (
+                Some(
+                    projection
+                        .iter()
+                        .map(|i| self.schema.field(*i).name().clone())
+                        .collect(),
+                ),
+                Arc::new(self.schema.project(projection)?),
+            ),
+            None => (None, self.schema.clone()),
+        };
+
+        let options = ScanOptions {
+            snapshot_id: self.snapshot_id,
+            columns,
+            predicate: convert_filters(filters),
+            target_partitions: state.config().target_partitions(),
+            split_size: self.split_size,
+        };
+        Ok(Arc::new(
+            NessieScanExec::try_new(self.table.clone(), options, schema).await?,
+        ))
+    }
+
+    /// Iceberg only prunes with the predicates, down to row groups, so the
+    /// filters it takes are still applied to the rows read.
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        Ok(filters
+            .iter()
+            .map(|filter| match convert_filter(filter) {
+                Some(_) => TableProviderFilterPushDown::Inexact,
+                None => TableProviderFilterPushDown::Unsupported,
+            })
+            .collect())
+    }
+
+    async fn insert_into(
+        &self,
+        _state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        insert_op: InsertOp,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let name = table_name(self.table.identifier());
+        let Some(catalog) = &self.catalog else {
+            return plan_err!("Table {name} is read-only, it has no catalog to commit to");
+        };
+        if let Some(snapshot_id) = self.snapshot_id {
+            return plan_err!("Cannot insert into snapshot {snapshot_id} of table {name}");
+        }
+
+        let sink = NessieDataSink::new(
+            catalog.clone(),
+            self.table.identifier().clone(),
+            self.schema.clone(),
+        );
+        let sink = match insert_op {
+            InsertOp::Append => sink,
+            InsertOp::Overwrite => sink.with_overwrite(
+                self.overwrite_mode
+                    .unwrap_or_else(|| OverwriteMode::of_table(&self.table)),
+            ),
+            InsertOp::Replace => {
+                return not_impl_err!("{insert_op} is not supported for Iceberg tables");
+            }
+        };
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
+    }
+}
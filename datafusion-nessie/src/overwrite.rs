@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iceberg::spec::{
+    DataContentType, DataFile, FormatVersion, MAIN_BRANCH, ManifestContentType, ManifestFile,
+    ManifestListWriter, ManifestWriter, ManifestWriterBuilder, Operation, Snapshot,
+    SnapshotReference, SnapshotRetention, Struct, Summary, TableMetadata, TableMetadataBuilder,
+};
+use iceberg::table::Table;
+use iceberg::{Error, ErrorKind, Result};
+use nessie_iceberg_catalog::NessieCatalog;
+use uuid::Uuid;
+
+use crate::nessie_data_sink::OverwriteMode;
+
+/// Replaces data of `table` with `data_files` in an `overwrite` snapshot
+/// committed through `catalog`.
+///
+/// [`OverwriteMode::Static`] deletes every live data and delete file of the
+/// current snapshot, [`OverwriteMode::Dynamic`] only those in the partitions
+/// of the default spec that `data_files` write to. Manifests without deleted
+/// files are kept as they are, the others are rewritten with the files they
+/// keep marked as existing. Nothing is committed if nothing changes.
+pub(crate) async fn overwrite(
+    catalog: &NessieCatalog,
+    table: &Table,
+    data_files: Vec<DataFile>,
+    mode: OverwriteMode,
+) -> Result<()> {
+    let metadata = table.metadata();
+    let spec_id = metadata.default_partition_spec_id();
+    let partitions: Vec<&Struct> = data_files.iter().map(DataFile::partition).collect();
+    let replaced = |manifest: &ManifestFile, partition: &Struct| match mode {
+        OverwriteMode::Static => true,
+        OverwriteMode::Dynamic => {
+            manifest.partition_spec_id == spec_id && partitions.contains(&partition)
+        }
+    };
+
+    let snapshot_id = snapshot_id(metadata);
+    let sequence_number = metadata.next_sequence_number();
+    let commit_id = Uuid::new_v4();
+    let mut manifests = Vec::new();
+    let mut summary = SummaryCounts::default();
+
+    if let Some(parent) = metadata.current_snapshot() {
+        let manifest_list = parent.load_manifest_list(table.file_io(), metadata).await?;
+        for manifest_file in manifest_list.entries() {
+            let manifest = manifest_file.load_manifest(table.file_io()).await?;
+            let deleted = manifest.entries().iter().any(|entry| {
+                entry.is_alive() && replaced(manifest_file, entry.data_file().partition())
+            });
+            if !deleted {
+                manifests.push(manifest_file.clone());
+                continue;
+            }
+
+            let path = manifest_path(metadata, commit_id, manifests.len());
+            let mut writer = manifest_writer(table, &path, snapshot_id, manifest_file)?;
+            for entry in manifest.entries() {
+                if !entry.is_alive() {
+                    continue;
+                }
+                // Files keep the sequence numbers they were added with.
+                let data_file = entry.data_file().clone();
+                let sequence_number = entry
+                    .sequence_number()
+                    .unwrap_or(manifest_file.sequence_number);
+                let file_sequence_number = entry.file_sequence_number.or(Some(sequence_number));
+                if replaced(manifest_file, data_file.partition()) {
+                    summary.delete(&data_file);
+                    writer.add_delete_file(data_file, sequence_number, file_sequence_number)?;
+                } else {
+                    let added_by = entry
+                        .snapshot_id()
+                        .unwrap_or(manifest_file.added_snapshot_id);
+                    writer.add_existing_file(
+                        data_file,
+                        added_by,
+                        sequence_number,
+                        file_sequence_number,
+                    )?;
+                }
+            }
+            manifests.push(writer.write_manifest_file().await?);
+        }
+    }
+
+    if !data_files.is_empty() {
+        let path = manifest_path(metadata, commit_id, manifests.len());
+        let builder = ManifestWriterBuilder::new(
+            table.file_io().new_output(path)?,
+            Some(snapshot_id),
+            None,
+            metadata.current_schema().clone(),
+            metadata.default_partition_spec().as_ref().clone(),
+        );
+        let mut writer = match metadata.format_version() {
+            FormatVersion::V1 => builder.build_v1(),
+            _ => builder.build_v2_data(),
+        };
+        for data_file in data_files {
+            summary.add(&data_file);
+            writer.add_file(data_file, sequence_number)?;
+        }
+        manifests.push(writer.write_manifest_file().await?);
+    }
+
+    if summary.is_empty() {
+        return Ok(());
+    }
+
+    let parent_snapshot_id = metadata.current_snapshot_id();
+    let manifest_list_path = format!(
+        "{}/metadata/snap-{snapshot_id}-1-{commit_id}.avro",
+        metadata.location().trim_end_matches('/')
+    );
+    let output = table.file_io().new_output(&manifest_list_path)?;
+    let mut writer = match metadata.format_version() {
+        FormatVersion::V1 => ManifestListWriter::v1(output, snapshot_id, parent_snapshot_id),
+        _ => ManifestListWriter::v2(output, snapshot_id, parent_snapshot_id, sequence_number),
+    };
+    writer.add_manifests(manifests.into_iter())?;
+    writer.close().await?;
+
+    let snapshot = Snapshot::builder()
+        .with_snapshot_id(snapshot_id)
+        .with_parent_snapshot_id(parent_snapshot_id)
+        .with_sequence_number(sequence_number)
+        .with_timestamp_ms(now_ms())
+        .with_manifest_list(manifest_list_path)
+        .with_schema_id(metadata.current_schema_id())
+        .with_summary(Summary {
+            operation: Operation::Overwrite,
+            additional_properties: summary.into_properties(mode),
+        })
+        .build();
+    let metadata = TableMetadataBuilder::new_from_metadata(
+        metadata.clone(),
+        table.metadata_location().map(str::to_string),
+    )
+    .add_snapshot(snapshot)?
+    .set_ref(
+        MAIN_BRANCH,
+        SnapshotReference::new(snapshot_id, SnapshotRetention::branch(None, None, None)),
+    )?
+    .build()?
+    .metadata;
+
+    catalog.commit_table_metadata(table, metadata).await?;
+    Ok(())
+}
+
+/// Writer rewriting `manifest_file` at `path`, for the same spec and content.
+fn manifest_writer(
+    table: &Table,
+    path: &str,
+    snapshot_id: i64,
+    manifest_file: &ManifestFile,
+) -> Result<ManifestWriter> {
+    let metadata = table.metadata();
+    let spec = metadata
+        .partition_spec_by_id(manifest_file.partition_spec_id)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Partition spec {} of manifest {} does not exist",
+                    manifest_file.partition_spec_id, manifest_file.manifest_path
+                ),
+            )
+        })?;
+    let builder = ManifestWriterBuilder::new(
+        table.file_io().new_output(path)?,
+        Some(snapshot_id),
+        None,
+        metadata.current_schema().clone(),
+        spec.as_ref().clone(),
+    );
+    Ok(match (metadata.format_version(), manifest_file.content) {
+        (FormatVersion::V1, _) => builder.build_v1(),
+        (_, ManifestContentType::Data) => builder.build_v2_data(),
+        (_, ManifestContentType::Deletes) => builder.build_v2_deletes(),
+    })
+}
+
+fn manifest_path(metadata: &TableMetadata, commit_id: Uuid, index: usize) -> String {
+    format!(
+        "{}/metadata/{commit_id}-m{index}.avro",
+        metadata.location().trim_end_matches('/')
+    )
+}
+
+/// A positive snapshot id not used by the table yet.
+fn snapshot_id(metadata: &TableMetadata) -> i64 {
+    loop {
+        let (high, low) = Uuid::new_v4().as_u64_pair();
+        let id = ((high ^ low) & i64::MAX as u64) as i64;
+        if metadata.snapshot_by_id(id).is_none() {
+            return id;
+        }
+    }
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64)
+}
+
+/// Files and records added and deleted by an overwrite, for its summary.
+#[derive(Default)]
+struct SummaryCounts {
+    added_data_files: u64,
+    added_records: u64,
+    deleted_data_files: u64,
+    deleted_records: u64,
+    removed_delete_files: u64,
+}
+
+impl SummaryCounts {
+    fn add(&mut self, data_file: &DataFile) {
+        self.added_data_files += 1;
+        self.added_records += data_file.record_count();
+    }
+
+    fn delete(&mut self, data_file: &DataFile) {
+        match data_file.content_type() {
+            DataContentType::Data => {
+                self.deleted_data_files += 1;
+                self.deleted_records += data_file.record_count();
+            }
+            _ => self.removed_delete_files += 1,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added_data_files == 0 && self.deleted_data_files == 0 && self.removed_delete_files == 0
+    }
+
+    fn into_properties(self, mode: OverwriteMode) -> HashMap<String, String> {
+        let mut properties = HashMap::from([
+            (
+                "added-data-files".to_string(),
+                self.added_data_files.to_string(),
+            ),
+            ("added-records".to_string(), self.added_records.to_string()),
+            (
+                "deleted-data-files".to_string(),
+                self.deleted_data_files.to_string(),
+            ),
+            (
+                "deleted-records".to_string(),
+                self.deleted_records.to_string(),
+            ),
+            (
+                "removed-delete-files".to_string(),
+                self.removed_delete_files.to_string(),
+            ),
+        ]);
+        if mode == OverwriteMode::Dynamic {
+            properties.insert("replace-partitions".to_string(), "true".to_string());
+        }
+        properties
+    }
+}
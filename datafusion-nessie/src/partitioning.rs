@@ -0,0 +1,142 @@
+//! Splitting of the rows written to an Iceberg table by partition.
+
+use std::collections::HashMap;
+
+use datafusion::arrow::array::{ArrayRef, RecordBatch, UInt32Array};
+use datafusion::arrow::compute::take_record_batch;
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::row::{RowConverter, SortField};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use datafusion::scalar::ScalarValue;
+use iceberg::spec::{Literal, PartitionSpecRef, Struct};
+use iceberg::transform::{BoxedTransformFunction, create_transform_function};
+
+use crate::expr_to_predicate::scalar_datum;
+use crate::nessie_table_provider::to_datafusion_error;
+
+/// The rows of a partition.
+pub(crate) struct Partition {
+    /// Identifies the partition among those of a [`Partitioner`].
+    pub key: Vec<u8>,
+    pub value: Struct,
+    pub batch: RecordBatch,
+}
+
+/// Splits batches by the partition their rows belong to under a spec.
+pub(crate) struct Partitioner {
+    spec: PartitionSpecRef,
+    /// Per partition field, the column of its source and its transform.
+    fields: Vec<(usize, BoxedTransformFunction)>,
+    /// Encodes the partition values of rows, once their types are known.
+    converter: Option<RowConverter>,
+}
+
+impl Partitioner {
+    /// A partitioner of the batches of `schema`, whose fields carry their
+    /// Iceberg field ids.
+    pub(crate) fn try_new(spec: PartitionSpecRef, schema: &Schema) -> Result<Self> {
+        let fields = spec
+            .fields()
+            .iter()
+            .map(|field| {
+                let column = schema
+                    .fields()
+                    .iter()
+                    .position(|column| {
+                        column.metadata().get(PARQUET_FIELD_ID_META_KEY)
+                            == Some(&field.source_id.to_string())
+                    })
+                    .ok_or_else(|| {
+                        DataFusionError::NotImplemented(format!(
+                            "Partition field {} of a nested column",
+                            field.name
+                        ))
+                    })?;
+                let transform =
+                    create_transform_function(&field.transform).map_err(to_datafusion_error)?;
+                Ok((column, transform))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            spec,
+            fields,
+            converter: None,
+        })
+    }
+
+    pub(crate) fn spec(&self) -> &PartitionSpecRef {
+        &self.spec
+    }
+
+    /// Splits `batch` by partition.
+    pub(crate) fn partition(&mut self, batch: &RecordBatch) -> Result<Vec<Partition>> {
+        if self.fields.is_empty() {
+            return Ok(vec![Partition {
+                key: Vec::new(),
+                value: Struct::empty(),
+                batch: batch.clone(),
+            }]);
+        }
+        let values = self
+            .fields
+            .iter()
+            .map(|(column, transform)| {
+                transform
+                    .transform(batch.column(*column).clone())
+                    .map_err(to_datafusion_error)
+            })
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        if self.converter.is_none() {
+            let fields = values
+                .iter()
+                .map(|value| SortField::new(value.data_type().clone()))
+                .collect();
+            self.converter = Some(RowConverter::new(fields)?);
+        }
+        let keys = self
+            .converter
+            .as_ref()
+            .expect("created above")
+            .convert_columns(&values)?;
+
+        // Rows of each partition, in the order partitions first appear.
+        let mut partitions: Vec<(Vec<u8>, Vec<u32>)> = Vec::new();
+        let mut indices: HashMap<Vec<u8>, usize> = HashMap::new();
+        for (row, key) in keys.iter().enumerate() {
+            let key = key.as_ref();
+            let i = match indices.get(key) {
+                Some(i) => *i,
+                None => {
+                    indices.insert(key.to_vec(), partitions.len());
+                    partitions.push((key.to_vec(), Vec::new()));
+                    partitions.len() - 1
+                }
+            };
+            partitions[i].1.push(row as u32);
+        }
+
+        partitions
+            .into_iter()
+            .map(|(key, rows)| {
+                let value = values
+                    .iter()
+                    .map(|value| literal(&ScalarValue::try_from_array(value, rows[0] as usize)?))
+                    .collect::<Result<Struct>>()?;
+                let batch = take_record_batch(batch, &UInt32Array::from(rows))?;
+                Ok(Partition { key, value, batch })
+            })
+            .collect()
+    }
+}
+
+/// The partition value of a transformed scalar, `None` for nulls.
+fn literal(value: &ScalarValue) -> Result<Option<Literal>> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    let datum = scalar_datum(value).ok_or_else(|| {
+        DataFusionError::NotImplemented(format!("Partition values of type {}", value.data_type()))
+    })?;
+    Ok(Some(Literal::Primitive(datum.literal().clone())))
+}
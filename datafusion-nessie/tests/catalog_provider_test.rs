@@ -0,0 +1,70 @@
+use datafusion_nessie::{NessieCatalogProvider, NessieSchemaProvider};
+use nessie_client::models::ReferenceType;
+use nessie_client::ref_spec::RefSpec;
+
+mod common;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_namespaces_are_schemas() {
+    let (_server, catalog) = common::catalog(&[&["db"], &["db", "staging"], &["logs"]]).await;
+    let ctx = common::context(catalog);
+    let catalog = ctx.catalog("nessie").unwrap();
+
+    assert_eq!(catalog.schema_names(), vec!["db", "db.staging", "logs"]);
+    assert!(catalog.schema("db").is_some());
+    assert!(catalog.schema("db.staging").is_some());
+    assert!(catalog.schema("missing").is_none());
+    assert!(catalog.schema("").is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_schemas_of_empty_namespaces_have_no_tables() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+    let schema = ctx.catalog("nessie").unwrap().schema("db").unwrap();
+
+    assert!(schema.table_names().is_empty());
+    assert!(!schema.table_exist("events"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dotted_namespace_elements_keep_their_schema() {
+    let (_server, catalog) = common::catalog(&[&["a"], &["a", "b"], &["a.b"]]).await;
+    let ctx = common::context(catalog);
+    let catalog = ctx.catalog("nessie").unwrap();
+
+    assert_eq!(catalog.schema_names(), vec!["a", "a\u{1D}b", "a.b"]);
+    let namespace = |name: &str| {
+        let schema = catalog.schema(name).unwrap();
+        let provider = schema
+            .as_any()
+            .downcast_ref::<NessieSchemaProvider>()
+            .unwrap();
+        provider.namespace().clone().inner()
+    };
+    assert_eq!(namespace("a.b"), vec!["a", "b"]);
+    assert_eq!(namespace("a\u{1D}b"), vec!["a.b"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_only_branches_are_catalogs() {
+    let server = common::TestServer::start().await;
+    let client = server.client();
+    let main = client
+        .get_reference(&RefSpec::named("main").unwrap())
+        .await
+        .unwrap();
+    client
+        .create_reference(
+            "release",
+            ReferenceType::Tag,
+            &RefSpec::from(&main.reference),
+        )
+        .await
+        .unwrap();
+
+    assert!(NessieCatalogProvider::new(server.catalog("main")).is_ok());
+    let error = NessieCatalogProvider::new(server.catalog("release")).unwrap_err();
+    assert!(error.to_string().contains("not a branch"), "{error}");
+    assert!(NessieCatalogProvider::new(server.catalog("missing")).is_err());
+}
@@ -0,0 +1,325 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use datafusion::arrow::array::{RecordBatch, StringArray};
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::NessieCatalogProvider;
+use iceberg::io::{FileIO, FileIOBuilder};
+use iceberg::spec::{
+    DataContentType, DataFile, DataFileBuilder, FormatVersion, Manifest, ManifestContentType,
+    ManifestFile, ManifestList, ManifestListWriter, ManifestStatus, ManifestWriterBuilder,
+    TableMetadata,
+};
+use iceberg::{Catalog, NamespaceIdent, TableIdent};
+use nessie_client::client::NessieClient;
+use nessie_client::test_server::NessieTestServer;
+use nessie_iceberg_catalog::NessieCatalog;
+use tempfile::TempDir;
+
+/// The warehouse the files of `test_data` refer to each other in.
+const FIXTURES: &str = "/tmp/iceberg_warehouse";
+
+/// A Nessie server and the warehouse directory of its tables, both removed
+/// when dropped.
+pub struct TestServer {
+    pub server: NessieTestServer,
+    pub warehouse: TempDir,
+}
+
+impl TestServer {
+    pub async fn start() -> Self {
+        Self {
+            server: NessieTestServer::start().await.unwrap(),
+            warehouse: TempDir::new().unwrap(),
+        }
+    }
+
+    pub fn client(&self) -> NessieClient {
+        NessieClient::new(&self.server.base_url()).unwrap()
+    }
+
+    /// Catalog over `branch`, with the tables in the warehouse of the test.
+    pub fn catalog(&self, branch: &str) -> NessieCatalog {
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        let warehouse = self.warehouse.path().to_str().unwrap();
+        NessieCatalog::new(self.client(), branch, warehouse, file_io)
+    }
+}
+
+/// Starts a Nessie server and returns a catalog over its default branch with
+/// `namespaces` created, in order.
+pub async fn catalog(namespaces: &[&[&str]]) -> (TestServer, NessieCatalog) {
+    let server = TestServer::start().await;
+    let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+    let warehouse = server.warehouse.path().to_str().unwrap();
+    let catalog = NessieCatalog::on_default_branch(server.client(), warehouse, file_io)
+        .await
+        .unwrap();
+    for namespace in namespaces {
+        catalog
+            .create_namespace(
+                &NamespaceIdent::from_strs(*namespace).unwrap(),
+                Default::default(),
+            )
+            .await
+            .unwrap();
+    }
+    (server, catalog)
+}
+
+/// A session with `catalog` registered as `nessie`.
+pub fn context(catalog: NessieCatalog) -> SessionContext {
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "nessie",
+        Arc::new(NessieCatalogProvider::new(catalog).unwrap()),
+    );
+    ctx
+}
+
+/// Registers `test_data/db/test_iceberg`, two rows `(1, 'Alice')` and
+/// `(2, 'Bob')` in two data files, as table `name` of `namespace`.
+pub async fn register_test_iceberg(catalog: &NessieCatalog, namespace: &[&str], name: &str) {
+    register(
+        catalog,
+        namespace,
+        name,
+        "test_iceberg/metadata/v2.metadata.json",
+    )
+    .await;
+}
+
+/// Registers `test_data/db/test_iceberg_deletes`, see the
+/// `generate_delete_fixtures` example, as table `name` of `namespace`.
+pub async fn register_test_iceberg_deletes(
+    catalog: &NessieCatalog,
+    namespace: &[&str],
+    name: &str,
+) {
+    register(
+        catalog,
+        namespace,
+        name,
+        "test_iceberg_deletes/metadata/v1.metadata.json",
+    )
+    .await;
+}
+
+/// Registers the table of `test_data` whose metadata is `metadata` as table
+/// `name` of `namespace`, copied into the warehouse of `catalog`. The files
+/// of `test_data` refer to each other by their path under [`FIXTURES`], so
+/// the copy rewrites every path of its metadata, manifest lists, manifests
+/// and position delete files.
+async fn register(catalog: &NessieCatalog, namespace: &[&str], name: &str, metadata: &str) {
+    let (fixture, metadata) = metadata.split_once('/').unwrap();
+    let location = format!("{}/{}/{name}", catalog.warehouse(), namespace.join("/"));
+    let metadata_location = relocate(fixture, metadata, &location).await;
+
+    let table = TableIdent::new(
+        NamespaceIdent::from_strs(namespace).unwrap(),
+        name.to_string(),
+    );
+    catalog
+        .register_table(&table, metadata_location)
+        .await
+        .unwrap();
+}
+
+/// Copies table `fixture` of `test_data` to `location`, and returns the
+/// location of its copy of the metadata file at `metadata`, relative to
+/// the table.
+async fn relocate(fixture: &str, metadata: &str, location: &str) -> String {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../test_data/db")
+        .join(fixture);
+    let old_location = format!("{FIXTURES}/db/{fixture}");
+    let relocated = |path: &str| path.replacen(&old_location, location, 1);
+    // A file of the fixture, by its path under `FIXTURES`.
+    let fixture_file = |path: &str| {
+        let name = Path::new(path).file_name().unwrap();
+        let kind = Path::new(path).parent().unwrap().file_name().unwrap();
+        dir.join(kind).join(name)
+    };
+    let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+
+    std::fs::create_dir_all(Path::new(location).join("metadata")).unwrap();
+    std::fs::create_dir_all(Path::new(location).join("data")).unwrap();
+    let json = std::fs::read_to_string(dir.join(metadata))
+        .unwrap()
+        .replace(&old_location, location);
+    let table_metadata: TableMetadata = serde_json::from_str(&json).unwrap();
+    let format_version = table_metadata.format_version();
+
+    let mut manifests: HashMap<String, ManifestFile> = HashMap::new();
+    for snapshot in table_metadata.snapshots() {
+        let list_bytes = std::fs::read(fixture_file(snapshot.manifest_list())).unwrap();
+        let manifest_list = ManifestList::parse_with_version(&list_bytes, format_version).unwrap();
+
+        let mut relocated_files = Vec::new();
+        for manifest_file in manifest_list.entries() {
+            if !manifests.contains_key(&manifest_file.manifest_path) {
+                let copy = relocate_manifest(
+                    &file_io,
+                    manifest_file,
+                    &fixture_file(&manifest_file.manifest_path),
+                    &relocated,
+                    &fixture_file,
+                )
+                .await;
+                manifests.insert(manifest_file.manifest_path.clone(), copy);
+            }
+            relocated_files.push(manifests[&manifest_file.manifest_path].clone());
+        }
+
+        let output = file_io.new_output(snapshot.manifest_list()).unwrap();
+        let mut writer = match format_version {
+            FormatVersion::V1 => ManifestListWriter::v1(
+                output,
+                snapshot.snapshot_id(),
+                snapshot.parent_snapshot_id(),
+            ),
+            _ => ManifestListWriter::v2(
+                output,
+                snapshot.snapshot_id(),
+                snapshot.parent_snapshot_id(),
+                snapshot.sequence_number(),
+            ),
+        };
+        writer.add_manifests(relocated_files.into_iter()).unwrap();
+        writer.close().await.unwrap();
+    }
+
+    let metadata_location = format!("{location}/{metadata}");
+    std::fs::write(&metadata_location, json).unwrap();
+    metadata_location
+}
+
+/// Copies the manifest `manifest_file`, read from `path`, and the files it
+/// lists to their relocated paths.
+async fn relocate_manifest(
+    file_io: &FileIO,
+    manifest_file: &ManifestFile,
+    path: &Path,
+    relocated: &impl Fn(&str) -> String,
+    fixture_file: &impl Fn(&str) -> PathBuf,
+) -> ManifestFile {
+    let manifest = Manifest::parse_avro(&std::fs::read(path).unwrap()).unwrap();
+    let (entries, metadata) = manifest.into_parts();
+    let manifest_path = relocated(&manifest_file.manifest_path);
+    let builder = ManifestWriterBuilder::new(
+        file_io.new_output(&manifest_path).unwrap(),
+        Some(manifest_file.added_snapshot_id),
+        None,
+        metadata.schema.clone(),
+        metadata.partition_spec.clone(),
+    );
+    let mut writer = match (metadata.format_version, metadata.content) {
+        (FormatVersion::V1, _) => builder.build_v1(),
+        (_, ManifestContentType::Data) => builder.build_v2_data(),
+        (_, ManifestContentType::Deletes) => builder.build_v2_deletes(),
+    };
+
+    for entry in entries {
+        let file = entry.data_file();
+        let file_path = relocated(file.file_path());
+        let source = fixture_file(file.file_path());
+        if file.content_type() == DataContentType::PositionDeletes {
+            relocate_position_deletes(&source, &file_path, relocated);
+        } else {
+            std::fs::copy(&source, &file_path).unwrap();
+        }
+        let data_file = with_path(file, file_path, manifest_file.partition_spec_id);
+
+        let sequence_number = entry.sequence_number().unwrap_or(-1);
+        match entry.status() {
+            ManifestStatus::Added => writer.add_file(data_file, sequence_number),
+            ManifestStatus::Existing => writer.add_existing_file(
+                data_file,
+                entry.snapshot_id().unwrap(),
+                sequence_number,
+                entry.file_sequence_number,
+            ),
+            ManifestStatus::Deleted => {
+                writer.add_delete_file(data_file, sequence_number, entry.file_sequence_number)
+            }
+        }
+        .unwrap();
+    }
+
+    let written = writer.write_manifest_file().await.unwrap();
+    ManifestFile {
+        manifest_path,
+        manifest_length: written.manifest_length,
+        ..manifest_file.clone()
+    }
+}
+
+/// Copies the position delete file at `source` to `target`, with the paths
+/// of the data files it deletes rows of relocated.
+fn relocate_position_deletes(source: &Path, target: &str, relocated: &impl Fn(&str) -> String) {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(source).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut writer: Option<ArrowWriter<std::fs::File>> = None;
+    for batch in reader {
+        let batch = batch.unwrap();
+        let index = batch.schema().index_of("file_path").unwrap();
+        let paths: StringArray = batch
+            .column(index)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .iter()
+            .map(|path| path.map(relocated))
+            .collect();
+        let mut columns = batch.columns().to_vec();
+        columns[index] = Arc::new(paths);
+        let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+        writer
+            .get_or_insert_with(|| {
+                let file = std::fs::File::create(target).unwrap();
+                ArrowWriter::try_new(file, batch.schema(), None).unwrap()
+            })
+            .write(&batch)
+            .unwrap();
+    }
+    writer.unwrap().close().unwrap();
+}
+
+/// `file` of partition spec `spec_id` at `path`, with the size of the file
+/// there.
+fn with_path(file: &DataFile, path: String, spec_id: i32) -> DataFile {
+    let size = std::fs::metadata(&path).unwrap().len();
+    let mut builder = DataFileBuilder::default();
+    builder
+        .content(file.content_type())
+        .file_path(path)
+        .file_format(file.file_format())
+        .partition(file.partition().clone())
+        .record_count(file.record_count())
+        .file_size_in_bytes(size)
+        .column_sizes(file.column_sizes().clone())
+        .value_counts(file.value_counts().clone())
+        .null_value_counts(file.null_value_counts().clone())
+        .nan_value_counts(file.nan_value_counts().clone())
+        .lower_bounds(file.lower_bounds().clone())
+        .upper_bounds(file.upper_bounds().clone())
+        .key_metadata(file.key_metadata().map(<[u8]>::to_vec))
+        .split_offsets(file.split_offsets().to_vec())
+        .equality_ids(file.equality_ids().to_vec())
+        .first_row_id(file.first_row_id())
+        .partition_spec_id(spec_id)
+        .referenced_data_file(file.referenced_data_file())
+        .content_offset(file.content_offset())
+        .content_size_in_bytes(file.content_size_in_bytes());
+    if let Some(sort_order_id) = file.sort_order_id() {
+        builder.sort_order_id(sort_order_id);
+    }
+    builder.build().unwrap()
+}
@@ -0,0 +1,259 @@
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::NessieCatalogProvider;
+use iceberg::spec::{PrimitiveType, Transform, Type};
+use iceberg::table::Table;
+use iceberg::{Catalog, NamespaceIdent, TableIdent};
+
+mod common;
+
+async fn query(ctx: &SessionContext, sql: &str) -> String {
+    let batches = datafusion_nessie::sql(ctx, sql)
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    pretty_format_batches(&batches).unwrap().to_string()
+}
+
+/// Table `db.<name>` of the catalog registered as `nessie`.
+async fn load_table(ctx: &SessionContext, name: &str) -> Table {
+    let catalog = ctx.catalog("nessie").unwrap();
+    let provider = catalog
+        .as_any()
+        .downcast_ref::<NessieCatalogProvider>()
+        .unwrap();
+    provider
+        .catalog()
+        .load_table(&TableIdent::new(
+            NamespaceIdent::new("db".to_string()),
+            name.to_string(),
+        ))
+        .await
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_table_with_partitions_and_properties() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+
+    query(
+        &ctx,
+        "CREATE TABLE nessie.db.events (
+            id BIGINT NOT NULL,
+            name VARCHAR,
+            category VARCHAR,
+            ts TIMESTAMP
+        )
+        PARTITIONED BY (bucket(4, id), truncate(2, name), category, day(ts))
+        WITH ('write.overwrite.mode' = 'dynamic', 'read.split.target-size' = 1024)",
+    )
+    .await;
+
+    let table = load_table(&ctx, "events").await;
+    let metadata = table.metadata();
+    let schema = metadata.current_schema();
+    let id = schema.field_by_name("id").unwrap();
+    assert!(id.required);
+    assert_eq!(*id.field_type, Type::Primitive(PrimitiveType::Long));
+    assert_eq!(
+        *schema.field_by_name("name").unwrap().field_type,
+        Type::Primitive(PrimitiveType::String)
+    );
+    assert_eq!(
+        *schema.field_by_name("ts").unwrap().field_type,
+        Type::Primitive(PrimitiveType::Timestamp)
+    );
+
+    let partitions: Vec<(String, Transform)> = metadata
+        .default_partition_spec()
+        .fields()
+        .iter()
+        .map(|field| (field.name.clone(), field.transform))
+        .collect();
+    assert_eq!(
+        partitions,
+        vec![
+            ("id_bucket".to_string(), Transform::Bucket(4)),
+            ("name_trunc".to_string(), Transform::Truncate(2)),
+            ("category".to_string(), Transform::Identity),
+            ("ts_day".to_string(), Transform::Day),
+        ]
+    );
+    assert_eq!(metadata.properties()["write.overwrite.mode"], "dynamic");
+    assert_eq!(metadata.properties()["read.split.target-size"], "1024");
+
+    query(
+        &ctx,
+        "INSERT INTO nessie.db.events VALUES (1, 'abc', 'a', TIMESTAMP '2024-01-01 10:00:00')",
+    )
+    .await;
+    assert_eq!(
+        query(&ctx, "SELECT id, name, category FROM nessie.db.events").await,
+        [
+            "+----+------+----------+",
+            "| id | name | category |",
+            "+----+------+----------+",
+            "| 1  | abc  | a        |",
+            "+----+------+----------+",
+        ]
+        .join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_table_as_select() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    let ctx = common::context(catalog);
+
+    assert_eq!(
+        query(
+            &ctx,
+            "CREATE TABLE nessie.db.people PARTITIONED BY (name) AS
+             SELECT id, name, id * 10 AS score FROM nessie.db.test_iceberg",
+        )
+        .await,
+        [
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 2     |",
+            "+-------+"
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        query(&ctx, "SELECT * FROM nessie.db.people ORDER BY id").await,
+        [
+            "+----+-------+-------+",
+            "| id | name  | score |",
+            "+----+-------+-------+",
+            "| 1  | Alice | 10    |",
+            "| 2  | Bob   | 20    |",
+            "+----+-------+-------+",
+        ]
+        .join("\n")
+    );
+    let table = load_table(&ctx, "people").await;
+    assert_eq!(table.metadata().default_partition_spec().fields().len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_existing_table() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    let ctx = common::context(catalog);
+
+    query(
+        &ctx,
+        "CREATE TABLE IF NOT EXISTS nessie.db.test_iceberg (id INT)",
+    )
+    .await;
+    let error = datafusion_nessie::sql(&ctx, "CREATE TABLE nessie.db.test_iceberg (id INT)")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("already exists"), "{error}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_table_rejects_unknown_transforms() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+
+    let error = datafusion_nessie::sql(
+        &ctx,
+        "CREATE TABLE nessie.db.events (id BIGINT) PARTITIONED BY (minute(id))",
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Unsupported partition transform"),
+        "{error}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_table_outside_nessie_is_run_by_datafusion() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+
+    query(&ctx, "CREATE TABLE numbers AS VALUES (1), (2)").await;
+
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM numbers").await,
+        ["+---+", "| n |", "+---+", "| 2 |", "+---+"].join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_failed_create_table_as_select_leaves_no_table() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+
+    datafusion_nessie::sql(
+        &ctx,
+        "CREATE TABLE nessie.db.broken AS SELECT CAST('x' AS INT) AS id",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(!ctx.table_exist("nessie.db.broken").unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_table_in_nessie_needs_nessie_sql() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let ctx = common::context(catalog);
+
+    let error = ctx
+        .sql("CREATE TABLE nessie.db.events (id INT)")
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("datafusion_nessie::sql"),
+        "{error}"
+    );
+    assert!(!ctx.table_exist("nessie.db.events").unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_schema_creates_namespace() {
+    let (_server, catalog) = common::catalog(&[]).await;
+    let ctx = common::context(catalog);
+
+    query(&ctx, "CREATE SCHEMA nessie.db").await;
+    query(&ctx, "CREATE TABLE nessie.db.events (id BIGINT)").await;
+
+    assert_eq!(
+        ctx.catalog("nessie").unwrap().schema_names(),
+        vec!["db".to_string()]
+    );
+    assert_eq!(
+        load_table(&ctx, "events").await.identifier().name(),
+        "events"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_drop_schema_drops_empty_namespace() {
+    let (_server, catalog) = common::catalog(&[&["db"], &["logs"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    let ctx = common::context(catalog);
+
+    let error = datafusion_nessie::sql(&ctx, "DROP SCHEMA nessie.db")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("has tables"), "{error}");
+
+    query(&ctx, "DROP SCHEMA nessie.logs").await;
+    assert_eq!(
+        ctx.catalog("nessie").unwrap().schema_names(),
+        vec!["db".to_string()]
+    );
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::NessieTableProvider;
+
+mod common;
+
+/// Snapshot of `test_iceberg_deletes` before any delete.
+const APPEND_SNAPSHOT_ID: i64 = 5735508421683045541;
+
+async fn context() -> (common::TestServer, SessionContext) {
+    let (server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg_deletes(&catalog, &["db"], "events").await;
+    (server, common::context(catalog))
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> String {
+    let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+    pretty_format_batches(&batches).unwrap().to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_position_and_equality_deletes() {
+    let (_server, ctx) = context().await;
+
+    // Bob and Eve are deleted by position, the first Carol by equality; the
+    // second Carol is in a data file as recent as the equality delete.
+    assert_eq!(
+        query(&ctx, "SELECT id, name FROM nessie.db.events ORDER BY id").await,
+        [
+            "+----+-------+",
+            "| id | name  |",
+            "+----+-------+",
+            "| 1  | Alice |",
+            "| 4  | Dave  |",
+            "| 6  | Frank |",
+            "| 7  | Carol |",
+            "+----+-------+",
+        ]
+        .join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_deletes_with_projection_and_filters() {
+    let (_server, ctx) = context().await;
+
+    // `name` is read to apply the equality delete, though not selected.
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id FROM nessie.db.events WHERE id > 2 ORDER BY id"
+        )
+        .await,
+        [
+            "+----+", "| id |", "+----+", "| 4  |", "| 6  |", "| 7  |", "+----+"
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM nessie.db.events").await,
+        ["+---+", "| n |", "+---+", "| 4 |", "+---+"].join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot_before_deletes() {
+    let (_server, ctx) = context().await;
+    let table = ctx
+        .catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table("events")
+        .await
+        .unwrap()
+        .unwrap();
+    let iceberg = table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .clone();
+    let provider = NessieTableProvider::try_new(iceberg)
+        .unwrap()
+        .with_snapshot(APPEND_SNAPSHOT_ID)
+        .unwrap();
+    ctx.register_table("before_deletes", Arc::new(provider))
+        .unwrap();
+
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM before_deletes").await,
+        ["+---+", "| n |", "+---+", "| 6 |", "+---+"].join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_deletes_with_small_splits() {
+    let (_server, ctx) = context().await;
+    let table = ctx
+        .catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table("events")
+        .await
+        .unwrap()
+        .unwrap();
+    let iceberg = table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .clone();
+    let provider = NessieTableProvider::try_new(iceberg)
+        .unwrap()
+        .with_split_size(1);
+    ctx.register_table("small_splits", Arc::new(provider))
+        .unwrap();
+
+    // The equality delete applies with the filter pushed into the scan.
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id, name FROM small_splits WHERE name = 'Carol' OR id < 3 ORDER BY id"
+        )
+        .await,
+        [
+            "+----+-------+",
+            "| id | name  |",
+            "+----+-------+",
+            "| 1  | Alice |",
+            "| 7  | Carol |",
+            "+----+-------+",
+        ]
+        .join("\n")
+    );
+}
@@ -0,0 +1,333 @@
+use std::sync::Arc;
+
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::nessie_data_sink::OVERWRITE_MODE_PROPERTY;
+use datafusion_nessie::{NessieCatalogProvider, NessieTableProvider};
+use iceberg::spec::{
+    NestedField, Operation, PrimitiveType, Schema, SnapshotRef, Transform, Type,
+    UnboundPartitionSpec,
+};
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::{Catalog, NamespaceIdent, TableCreation, TableIdent};
+use nessie_client::models::ReferenceType;
+use nessie_client::ref_spec::RefSpec;
+use nessie_iceberg_catalog::NessieCatalog;
+
+mod common;
+
+/// Creates table `db.<name>` with columns `id` and `category`, partitioned
+/// by `category` if `partitioned`.
+async fn create_table(catalog: &NessieCatalog, name: &str, partitioned: bool) {
+    let schema = Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            NestedField::optional(2, "category", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()
+        .unwrap();
+    let creation = TableCreation::builder()
+        .name(name.to_string())
+        .schema(schema);
+    let creation = if partitioned {
+        let spec = UnboundPartitionSpec::builder()
+            .add_partition_field(2, "category", Transform::Identity)
+            .unwrap()
+            .build();
+        creation.partition_spec(spec).build()
+    } else {
+        creation.build()
+    };
+    catalog
+        .create_table(&NamespaceIdent::new("db".to_string()), creation)
+        .await
+        .unwrap();
+}
+
+/// Sets property `key` of table `db.<name>` to `value`.
+async fn set_property(catalog: &NessieCatalog, name: &str, key: &str, value: &str) {
+    let ident = TableIdent::new(NamespaceIdent::new("db".to_string()), name.to_string());
+    let table = catalog.load_table(&ident).await.unwrap();
+    let tx = Transaction::new(&table);
+    let tx = tx
+        .update_table_properties()
+        .set(key.to_string(), value.to_string())
+        .apply(tx)
+        .unwrap();
+    tx.commit(catalog).await.unwrap();
+}
+
+/// The current snapshot of table `name` of schema `db` of `nessie`.
+async fn current_snapshot(ctx: &SessionContext, name: &str) -> SnapshotRef {
+    let table = ctx
+        .catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table(name)
+        .await
+        .unwrap()
+        .unwrap();
+    table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .metadata()
+        .current_snapshot()
+        .unwrap()
+        .clone()
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> String {
+    let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+    pretty_format_batches(&batches).unwrap().to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_appends_snapshots() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    create_table(&catalog, "events", false).await;
+    let ctx = common::context(catalog);
+
+    assert_eq!(
+        query(
+            &ctx,
+            "INSERT INTO nessie.db.events VALUES (1, 'a'), (2, 'b')"
+        )
+        .await,
+        [
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 2     |",
+            "+-------+"
+        ]
+        .join("\n")
+    );
+    query(
+        &ctx,
+        "INSERT INTO nessie.db.events SELECT id + 2, category FROM nessie.db.events",
+    )
+    .await;
+
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id, category FROM nessie.db.events ORDER BY id"
+        )
+        .await,
+        [
+            "+----+----------+",
+            "| id | category |",
+            "+----+----------+",
+            "| 1  | a        |",
+            "| 2  | b        |",
+            "| 3  | a        |",
+            "| 4  | b        |",
+            "+----+----------+",
+        ]
+        .join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_writes_a_file_per_partition() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    create_table(&catalog, "events", true).await;
+    let ctx = common::context(catalog);
+
+    query(
+        &ctx,
+        "INSERT INTO nessie.db.events VALUES (1, 'a'), (2, 'b'), (3, 'a'), (4, NULL)",
+    )
+    .await;
+
+    let plan = query(&ctx, "EXPLAIN SELECT * FROM nessie.db.events").await;
+    assert!(plan.contains("files=3,"), "{plan}");
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id FROM nessie.db.events WHERE category = 'a' ORDER BY id"
+        )
+        .await,
+        ["+----+", "| id |", "+----+", "| 1  |", "| 3  |", "+----+"].join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_commits_on_the_target_branch() {
+    let server = common::TestServer::start().await;
+    let client = server.client();
+    let main = server.catalog("main");
+    main.create_namespace(&NamespaceIdent::new("db".to_string()), Default::default())
+        .await
+        .unwrap();
+    create_table(&main, "events", false).await;
+    let head = client
+        .get_reference(&RefSpec::named("main").unwrap())
+        .await
+        .unwrap()
+        .reference;
+    client
+        .create_reference("dev", ReferenceType::Branch, &RefSpec::from(&head))
+        .await
+        .unwrap();
+    let dev = server.catalog("dev");
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("main", Arc::new(NessieCatalogProvider::new(main).unwrap()));
+    ctx.register_catalog("dev", Arc::new(NessieCatalogProvider::new(dev).unwrap()));
+    query(&ctx, "INSERT INTO dev.db.events VALUES (1, 'a')").await;
+
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM dev.db.events").await,
+        ["+---+", "| n |", "+---+", "| 1 |", "+---+"].join("\n")
+    );
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM main.db.events").await,
+        ["+---+", "| n |", "+---+", "| 0 |", "+---+"].join("\n")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_into_read_only_table_is_rejected() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    let ctx = common::context(catalog);
+    let table = ctx
+        .catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table("test_iceberg")
+        .await
+        .unwrap()
+        .unwrap();
+    let iceberg = table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .clone();
+    let read_only = NessieTableProvider::try_new(iceberg).unwrap();
+    ctx.register_table("read_only", Arc::new(read_only))
+        .unwrap();
+
+    let error = ctx
+        .sql("INSERT INTO read_only VALUES (3, 'Carol')")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("read-only"), "{error}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_overwrite_replaces_the_table() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    create_table(&catalog, "events", true).await;
+    let ctx = common::context(catalog);
+    query(
+        &ctx,
+        "INSERT INTO nessie.db.events VALUES (1, 'a'), (2, 'b')",
+    )
+    .await;
+
+    query(&ctx, "INSERT OVERWRITE nessie.db.events VALUES (3, 'a')").await;
+
+    assert_eq!(
+        query(&ctx, "SELECT * FROM nessie.db.events").await,
+        [
+            "+----+----------+",
+            "| id | category |",
+            "+----+----------+",
+            "| 3  | a        |",
+            "+----+----------+",
+        ]
+        .join("\n")
+    );
+    let snapshot = current_snapshot(&ctx, "events").await;
+    assert_eq!(snapshot.summary().operation, Operation::Overwrite);
+    let properties = &snapshot.summary().additional_properties;
+    assert_eq!(properties["added-data-files"], "1");
+    assert_eq!(properties["deleted-data-files"], "2");
+    assert_eq!(properties["deleted-records"], "2");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_overwrite_dynamic_replaces_written_partitions() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    create_table(&catalog, "events", true).await;
+    set_property(&catalog, "events", OVERWRITE_MODE_PROPERTY, "dynamic").await;
+    let ctx = common::context(catalog);
+    query(
+        &ctx,
+        "INSERT INTO nessie.db.events VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'a')",
+    )
+    .await;
+
+    query(
+        &ctx,
+        "INSERT OVERWRITE nessie.db.events VALUES (5, 'a'), (6, NULL)",
+    )
+    .await;
+
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id, category FROM nessie.db.events ORDER BY id"
+        )
+        .await,
+        [
+            "+----+----------+",
+            "| id | category |",
+            "+----+----------+",
+            "| 2  | b        |",
+            "| 3  | c        |",
+            "| 5  | a        |",
+            "| 6  |          |",
+            "+----+----------+",
+        ]
+        .join("\n")
+    );
+    let snapshot = current_snapshot(&ctx, "events").await;
+    assert_eq!(snapshot.summary().operation, Operation::Overwrite);
+    let properties = &snapshot.summary().additional_properties;
+    assert_eq!(properties["replace-partitions"], "true");
+    assert_eq!(properties["deleted-records"], "2");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_overwrite_removes_delete_files() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg_deletes(&catalog, &["db"], "people").await;
+    let ctx = common::context(catalog);
+
+    query(
+        &ctx,
+        "INSERT OVERWRITE nessie.db.people VALUES (8, 'Grace'), (9, 'Heidi')",
+    )
+    .await;
+
+    assert_eq!(
+        query(&ctx, "SELECT * FROM nessie.db.people ORDER BY id").await,
+        [
+            "+----+-------+",
+            "| id | name  |",
+            "+----+-------+",
+            "| 8  | Grace |",
+            "| 9  | Heidi |",
+            "+----+-------+",
+        ]
+        .join("\n")
+    );
+    let properties = current_snapshot(&ctx, "people")
+        .await
+        .summary()
+        .additional_properties
+        .clone();
+    assert_eq!(properties["deleted-data-files"], "2");
+    assert_eq!(properties["removed-delete-files"], "2");
+}
@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::Int64Array;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::catalog::TableProvider;
+use datafusion::parquet::file::properties::WriterProperties;
+use datafusion::physical_plan::{ExecutionPlan, ExecutionPlanProperties, collect, displayable};
+use datafusion::prelude::SessionContext;
+use datafusion_nessie::{NessieScanExec, NessieTableProvider};
+use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::spec::{DataFileFormat, NestedField, PrimitiveType, Schema, Type};
+use iceberg::table::Table;
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::file_writer::ParquetWriterBuilder;
+use iceberg::writer::file_writer::location_generator::{
+    DefaultFileNameGenerator, DefaultLocationGenerator,
+};
+use iceberg::writer::{IcebergWriter, IcebergWriterBuilder};
+use iceberg::{Catalog, NamespaceIdent, TableCreation};
+use nessie_iceberg_catalog::NessieCatalog;
+
+mod common;
+
+async fn context(target_partitions: usize) -> (common::TestServer, SessionContext) {
+    let (server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    let ctx = common::context(catalog);
+    ctx.sql(&format!(
+        "SET datafusion.execution.target_partitions = {target_partitions}"
+    ))
+    .await
+    .unwrap();
+    (server, ctx)
+}
+
+async fn table(ctx: &SessionContext) -> NessieTableProvider {
+    let table = ctx
+        .catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table("test_iceberg")
+        .await
+        .unwrap()
+        .unwrap();
+    let iceberg = table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .clone();
+    NessieTableProvider::try_new(iceberg).unwrap()
+}
+
+async fn scan(ctx: &SessionContext, table: &NessieTableProvider) -> Arc<dyn ExecutionPlan> {
+    table.scan(&ctx.state(), None, &[], None).await.unwrap()
+}
+
+async fn rows(ctx: &SessionContext, plan: Arc<dyn ExecutionPlan>) -> usize {
+    let batches = collect(plan, ctx.task_ctx()).await.unwrap();
+    batches.iter().map(|batch| batch.num_rows()).sum()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_one_partition_per_file() {
+    let (_server, ctx) = context(4).await;
+    let plan = scan(&ctx, &table(&ctx).await).await;
+
+    let exec = plan.as_any().downcast_ref::<NessieScanExec>().unwrap();
+    assert_eq!(exec.partitions().len(), 2);
+    assert!(exec.partitions().iter().all(|splits| splits.len() == 1));
+    assert_eq!(plan.output_partitioning().partition_count(), 2);
+
+    let display = displayable(plan.as_ref()).one_line().to_string();
+    assert!(display.contains("files=2, bytes="), "{display}");
+
+    assert_eq!(rows(&ctx, plan).await, 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_files_are_packed_into_target_partitions() {
+    let (_server, ctx) = context(1).await;
+    let plan = scan(&ctx, &table(&ctx).await).await;
+
+    let exec = plan.as_any().downcast_ref::<NessieScanExec>().unwrap();
+    assert_eq!(exec.partitions().len(), 1);
+    assert_eq!(exec.partitions()[0].len(), 2);
+
+    assert_eq!(rows(&ctx, plan).await, 2);
+}
+
+/// Creates table `db.numbers` with one data file of the ids `0..10` in row
+/// groups of two rows.
+async fn create_numbers(catalog: &NessieCatalog) -> Table {
+    let schema = Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+        ])
+        .build()
+        .unwrap();
+    let creation = TableCreation::builder()
+        .name("numbers".to_string())
+        .schema(schema)
+        .build();
+    let table = catalog
+        .create_table(&NamespaceIdent::new("db".to_string()), creation)
+        .await
+        .unwrap();
+
+    let metadata = table.metadata();
+    let parquet_writer = ParquetWriterBuilder::new(
+        WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build(),
+        metadata.current_schema().clone(),
+        table.file_io().clone(),
+        DefaultLocationGenerator::new(metadata.clone()).unwrap(),
+        DefaultFileNameGenerator::new("numbers".to_string(), None, DataFileFormat::Parquet),
+    );
+    let mut writer = DataFileWriterBuilder::new(parquet_writer, None, 0)
+        .build()
+        .await
+        .unwrap();
+    let arrow_schema = Arc::new(schema_to_arrow_schema(metadata.current_schema()).unwrap());
+    let ids = Int64Array::from_iter_values(0..10);
+    writer
+        .write(RecordBatch::try_new(arrow_schema, vec![Arc::new(ids)]).unwrap())
+        .await
+        .unwrap();
+    let data_files = writer.close().await.unwrap();
+
+    let tx = Transaction::new(&table);
+    let tx = tx
+        .fast_append()
+        .add_data_files(data_files)
+        .apply(tx)
+        .unwrap();
+    tx.commit(catalog).await.unwrap()
+}
+
+async fn ids(ctx: &SessionContext, plan: Arc<dyn ExecutionPlan>) -> Vec<i64> {
+    let batches = collect(plan, ctx.task_ctx()).await.unwrap();
+    let mut ids: Vec<i64> = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        })
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_big_files_are_split_by_row_groups() {
+    let (_server, catalog) = common::catalog(&[&["db"]]).await;
+    let table = create_numbers(&catalog).await;
+    let ctx = common::context(catalog);
+
+    // Splits of one row group each.
+    let provider = NessieTableProvider::try_new(table.clone())
+        .unwrap()
+        .with_split_size(1);
+    let plan = scan(&ctx, &provider).await;
+    let exec = plan.as_any().downcast_ref::<NessieScanExec>().unwrap();
+    let mut row_groups: Vec<_> = exec
+        .partitions()
+        .iter()
+        .flatten()
+        .map(|split| split.row_groups.clone().unwrap())
+        .collect();
+    row_groups.sort_by_key(|row_groups| row_groups.start);
+    assert_eq!(row_groups, vec![0..1, 1..2, 2..3, 3..4, 4..5]);
+    assert!(
+        exec.partitions()
+            .iter()
+            .flatten()
+            .all(|split| split.task.record_count == Some(2))
+    );
+    let row_group_bytes: u64 = exec
+        .partitions()
+        .iter()
+        .flatten()
+        .map(|split| split.task.length)
+        .sum();
+    let display = displayable(plan.as_ref()).one_line().to_string();
+    assert!(display.contains("files=1, bytes="), "{display}");
+    assert_eq!(ids(&ctx, plan).await, (0..10).collect::<Vec<_>>());
+
+    // Splits of the row groups that fit in the split size.
+    let provider = NessieTableProvider::try_new(table)
+        .unwrap()
+        .with_split_size(row_group_bytes / 2);
+    let plan = scan(&ctx, &provider).await;
+    let exec = plan.as_any().downcast_ref::<NessieScanExec>().unwrap();
+    let splits: Vec<_> = exec.partitions().iter().flatten().collect();
+    assert!(splits.len() > 1 && splits.len() < 5, "{splits:?}");
+    assert!(
+        splits
+            .iter()
+            .all(|split| split.task.length <= row_group_bytes / 2)
+    );
+    assert_eq!(ids(&ctx, plan).await, (0..10).collect::<Vec<_>>());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_shows_files() {
+    let (_server, ctx) = context(4).await;
+
+    let batches = ctx
+        .sql("EXPLAIN SELECT * FROM nessie.db.test_iceberg")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let plan = datafusion::arrow::util::pretty::pretty_format_batches(&batches)
+        .unwrap()
+        .to_string();
+    assert!(plan.contains("NessieScanExec: table="), "{plan}");
+    assert!(plan.contains("files=2, bytes="), "{plan}");
+}
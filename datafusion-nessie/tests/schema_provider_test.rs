@@ -0,0 +1,66 @@
+use datafusion::arrow::util::pretty::pretty_format_batches;
+
+mod common;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tables_of_a_namespace() {
+    let (_server, catalog) = common::catalog(&[&["db"], &["db", "staging"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    common::register_test_iceberg(&catalog, &["db", "staging"], "events").await;
+    let ctx = common::context(catalog);
+    let nessie = ctx.catalog("nessie").unwrap();
+
+    let db = nessie.schema("db").unwrap();
+    assert_eq!(db.table_names(), vec!["test_iceberg"]);
+    assert!(db.table_exist("test_iceberg"));
+    assert!(!db.table_exist("events"));
+    assert!(!db.table_exist("staging"));
+    assert!(db.table("test_iceberg").await.unwrap().is_some());
+    assert!(db.table("missing").await.unwrap().is_none());
+
+    let staging = nessie.schema("db.staging").unwrap();
+    assert_eq!(staging.table_names(), vec!["events"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_select_from_nessie_table() {
+    let (_server, catalog) = common::catalog(&[&["db"], &["db", "staging"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    common::register_test_iceberg(&catalog, &["db", "staging"], "events").await;
+    let ctx = common::context(catalog);
+
+    let batches = ctx
+        .sql("SELECT id, name FROM nessie.db.test_iceberg ORDER BY id")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let expected = [
+        "+----+-------+",
+        "| id | name  |",
+        "+----+-------+",
+        "| 1  | Alice |",
+        "| 2  | Bob   |",
+        "+----+-------+",
+    ];
+    assert_eq!(
+        pretty_format_batches(&batches).unwrap().to_string(),
+        expected.join("\n")
+    );
+
+    let count = ctx
+        .sql("SELECT count(*) AS n FROM nessie.\"db.staging\".events")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        pretty_format_batches(&count).unwrap().to_string(),
+        ["+---+", "| n |", "+---+", "| 2 |", "+---+"].join("\n")
+    );
+
+    let missing = ctx.sql("SELECT * FROM nessie.db.missing").await;
+    assert!(missing.is_err());
+}
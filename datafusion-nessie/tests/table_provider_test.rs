@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::catalog::TableProvider;
+use datafusion::logical_expr::TableProviderFilterPushDown::{Inexact, Unsupported};
+use datafusion::physical_plan::{collect, displayable};
+use datafusion::prelude::{SessionContext, col, lit};
+use datafusion_nessie::NessieTableProvider;
+
+mod common;
+
+const SNAPSHOT_ID: i64 = 4292585322385150940;
+
+async fn context() -> (common::TestServer, SessionContext) {
+    let (server, catalog) = common::catalog(&[&["db"]]).await;
+    common::register_test_iceberg(&catalog, &["db"], "test_iceberg").await;
+    (server, common::context(catalog))
+}
+
+async fn table(ctx: &SessionContext) -> Arc<dyn TableProvider> {
+    ctx.catalog("nessie")
+        .unwrap()
+        .schema("db")
+        .unwrap()
+        .table("test_iceberg")
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> String {
+    let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+    pretty_format_batches(&batches).unwrap().to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_filters_pushdown() {
+    let (_server, ctx) = context().await;
+    let table = table(&ctx).await;
+
+    let filters = [
+        col("id").eq(lit(1)),
+        lit("Bob").eq(col("name")),
+        col("id").in_list(vec![lit(1), lit(2)], false),
+        col("name").is_null().or(col("id").between(lit(1), lit(5))),
+        // Only `id = 1` converts, which is enough to prune.
+        col("id").eq(lit(1)).and((col("id") + lit(1)).eq(lit(2))),
+        (col("id") + lit(1)).eq(lit(2)),
+        col("id").eq(col("id")),
+        // Dropping `id + 1 = 2` would make the negation stronger.
+        !(col("id").eq(lit(1)).and((col("id") + lit(1)).eq(lit(2)))),
+    ];
+    let pushdown = table
+        .supports_filters_pushdown(&filters.iter().collect::<Vec<_>>())
+        .unwrap();
+
+    assert_eq!(
+        pushdown,
+        vec![
+            Inexact,
+            Inexact,
+            Inexact,
+            Inexact,
+            Inexact,
+            Unsupported,
+            Unsupported,
+            Unsupported
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_scan_prunes_data_files() {
+    let (_server, ctx) = context().await;
+    let table = table(&ctx).await;
+
+    let scan = table
+        .scan(&ctx.state(), None, &[col("id").eq(lit(2))], None)
+        .await
+        .unwrap();
+    let plan = displayable(scan.as_ref()).one_line().to_string();
+    assert!(plan.contains("predicate=id = 2"), "{plan}");
+
+    let batches = collect(scan, ctx.task_ctx()).await.unwrap();
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_select_with_filters_and_projection() {
+    let (_server, ctx) = context().await;
+
+    assert_eq!(
+        query(&ctx, "SELECT name FROM nessie.db.test_iceberg WHERE id = 2").await,
+        ["+------+", "| name |", "+------+", "| Bob  |", "+------+"].join("\n")
+    );
+    assert_eq!(
+        query(
+            &ctx,
+            "SELECT id FROM nessie.db.test_iceberg WHERE name IN ('Alice', 'Carol') OR id > 5"
+        )
+        .await,
+        ["+----+", "| id |", "+----+", "| 1  |", "+----+"].join("\n")
+    );
+    assert_eq!(
+        query(&ctx, "SELECT * FROM nessie.db.test_iceberg WHERE id > 5").await,
+        ["++", "++"].join("\n")
+    );
+
+    let plan = query(
+        &ctx,
+        "EXPLAIN SELECT name FROM nessie.db.test_iceberg WHERE id < 2",
+    )
+    .await;
+    assert!(plan.contains("NessieScanExec"), "{plan}");
+    assert!(
+        plan.contains("projection=[name, id]") || plan.contains("projection=[id, name]"),
+        "{plan}"
+    );
+    assert!(plan.contains("predicate=id < 2"), "{plan}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot_selection() {
+    let (_server, ctx) = context().await;
+    let table = table(&ctx).await;
+    let iceberg = table
+        .as_any()
+        .downcast_ref::<NessieTableProvider>()
+        .unwrap()
+        .table()
+        .clone();
+
+    assert!(
+        NessieTableProvider::try_new(iceberg.clone())
+            .unwrap()
+            .with_snapshot(1)
+            .is_err()
+    );
+
+    let snapshot = NessieTableProvider::try_new(iceberg)
+        .unwrap()
+        .with_snapshot(SNAPSHOT_ID)
+        .unwrap();
+    ctx.register_table("at_snapshot", Arc::new(snapshot))
+        .unwrap();
+    assert_eq!(
+        query(&ctx, "SELECT count(*) AS n FROM at_snapshot").await,
+        ["+---+", "| n |", "+---+", "| 2 |", "+---+"].join("\n")
+    );
+}
@@ -0,0 +1,235 @@
+use std::fmt::{self, Debug};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::error::NessieError;
+
+/// Default margin before expiry at which an OAuth2 access token is refreshed.
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Printed in place of secrets by the `Debug` implementations, which end up
+/// in logs along with the client.
+const REDACTED: &str = "<redacted>";
+
+/// Adds credentials to every request sent by a [`crate::client::NessieClient`].
+#[async_trait]
+pub trait AuthenticationProvider: Debug + Send + Sync {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder, NessieError>;
+}
+
+/// Sends a fixed bearer token in the `Authorization` header.
+#[derive(Clone)]
+pub struct BearerAuthentication {
+    token: String,
+}
+
+impl BearerAuthentication {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl Debug for BearerAuthentication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BearerAuthentication")
+            .field("token", &REDACTED)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl AuthenticationProvider for BearerAuthentication {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder, NessieError> {
+        Ok(request.bearer_auth(&self.token))
+    }
+}
+
+/// Sends HTTP basic credentials in the `Authorization` header.
+#[derive(Clone)]
+pub struct BasicAuthentication {
+    username: String,
+    password: String,
+}
+
+impl BasicAuthentication {
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl Debug for BasicAuthentication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuthentication")
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl AuthenticationProvider for BasicAuthentication {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder, NessieError> {
+        Ok(request.basic_auth(&self.username, Some(&self.password)))
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Option<Instant>,
+}
+
+/// OAuth2 client-credentials flow against an OIDC token endpoint.
+///
+/// The access token is cached and fetched again once it is within the refresh
+/// margin of its expiry. Tokens without `expires_in` are kept until the client
+/// is dropped.
+///
+/// Tokens are fetched with the HTTP client set by
+/// [`with_http_client`](Self::with_http_client), or else with the client of the
+/// request being authenticated, so the token endpoint is reached with the
+/// certificates, proxy and timeouts the [`crate::builder::NessieClientBuilder`]
+/// configured.
+pub struct OAuth2Authentication {
+    token_endpoint: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    refresh_margin: Duration,
+    http: Option<Client>,
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl OAuth2Authentication {
+    pub fn new(
+        token_endpoint: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            token_endpoint: token_endpoint.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scope: None,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            http: None,
+            token: Mutex::new(None),
+        }
+    }
+
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// HTTP client to fetch tokens with.
+    pub fn with_http_client(mut self, http: Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Returns a valid access token, fetching a new one if needed.
+    pub async fn access_token(&self) -> Result<String, NessieError> {
+        match &self.http {
+            Some(http) => self.access_token_with(http).await,
+            None => self.access_token_with(&Client::new()).await,
+        }
+    }
+
+    /// Returns a valid access token, fetching a new one with `http` if needed.
+    async fn access_token_with(&self, http: &Client) -> Result<String, NessieError> {
+        let mut cached = self.token.lock().await;
+
+        if let Some(token) = cached.as_ref() {
+            let fresh = match token.expires_at {
+                Some(expires_at) => Instant::now() + self.refresh_margin < expires_at,
+                None => true,
+            };
+            if fresh {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let token = self.fetch_token(http).await?;
+        let access_token = token.access_token.clone();
+        *cached = Some(token);
+        Ok(access_token)
+    }
+
+    async fn fetch_token(&self, http: &Client) -> Result<CachedToken, NessieError> {
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope.as_str()));
+        }
+
+        let requested_at = Instant::now();
+        let response = http.post(&self.token_endpoint).form(&form).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<failed to read body>".to_string());
+            return Err(NessieError::Authentication(format!(
+                "token endpoint returned HTTP {}: {}",
+                status, body
+            )));
+        }
+
+        let token = response.json::<TokenResponse>().await?;
+        Ok(CachedToken {
+            access_token: token.access_token,
+            expires_at: token
+                .expires_in
+                .map(|secs| requested_at + Duration::from_secs(secs)),
+        })
+    }
+}
+
+impl Debug for OAuth2Authentication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Authentication")
+            .field("token_endpoint", &self.token_endpoint)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED)
+            .field("scope", &self.scope)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AuthenticationProvider for OAuth2Authentication {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder, NessieError> {
+        let (client, request) = request.build_split();
+        let token = self
+            .access_token_with(self.http.as_ref().unwrap_or(&client))
+            .await?;
+        Ok(RequestBuilder::from_parts(client, request?).bearer_auth(token))
+    }
+}
@@ -0,0 +1,259 @@
+//! Synchronous facade over [`NessieClient`], for callers that are not async.
+//!
+//! Requests run on a small runtime owned by the [`BlockingNessieClient`], and
+//! the calling thread waits for their result. This works both outside of any
+//! Tokio runtime and from code that already runs inside one, such as the
+//! synchronous trait methods DataFusion calls from its async planner: the
+//! caller's runtime never has to drive the request.
+
+use std::future::Future;
+use std::sync::{mpsc, Arc};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::builder::NessieClientBuilder;
+use crate::client::{ApiVersion, NessieClient};
+use crate::error::NessieError;
+use crate::generated::Operation;
+use crate::models::{
+    CommitResponse, EntriesParams, EntriesResponse, Entry, FetchOption, ListReferencesParams,
+    NessieConfiguration, Operations, Reference, ReferenceHistoryResponse, ReferenceResponse,
+    ReferenceType, ReferencesResponse, RepositoryConfig, RepositoryConfigType,
+};
+use crate::ref_spec::RefSpec;
+
+/// Blocking counterpart of [`NessieClient`], with the same operations.
+#[derive(Debug)]
+pub struct BlockingNessieClient {
+    client: Arc<NessieClient>,
+    runtime: RequestRuntime,
+}
+
+impl BlockingNessieClient {
+    pub fn new(base_url: &str) -> Result<Self, NessieError> {
+        Self::from_client(NessieClient::new(base_url)?)
+    }
+
+    /// Wraps an async client.
+    pub fn from_client(client: NessieClient) -> Result<Self, NessieError> {
+        Ok(Self {
+            client: Arc::new(client),
+            runtime: RequestRuntime::new()?,
+        })
+    }
+
+    /// The underlying async client.
+    pub fn async_client(&self) -> &NessieClient {
+        &self.client
+    }
+
+    pub fn api_version(&self) -> ApiVersion {
+        self.client.api_version()
+    }
+
+    /// Runs `future` on the runtime of the client and waits for its output,
+    /// for async code built on [`Self::async_client`].
+    pub fn run<T: Send + 'static>(&self, future: impl Future<Output = T> + Send + 'static) -> T {
+        self.runtime.run(future)
+    }
+
+    fn block_on<T, F>(&self, request: impl FnOnce(Arc<NessieClient>) -> F) -> Result<T, NessieError>
+    where
+        T: Send + 'static,
+        F: Future<Output = Result<T, NessieError>> + Send + 'static,
+    {
+        self.runtime.run(request(self.client.clone()))
+    }
+
+    /// See [`NessieClient::execute`].
+    pub fn execute<O>(&self, operation: &O) -> Result<O::Response, NessieError>
+    where
+        O: Operation + Clone + Send + Sync + 'static,
+        O::Response: Send,
+    {
+        let operation = operation.clone();
+        self.block_on(|client| async move { client.execute(&operation).await })
+    }
+
+    pub fn get_config(&self) -> Result<NessieConfiguration, NessieError> {
+        self.block_on(|client| async move { client.get_config().await })
+    }
+
+    /// See [`NessieClient::default_branch`].
+    pub fn default_branch(&self) -> Result<String, NessieError> {
+        self.block_on(|client| async move { client.default_branch().await })
+    }
+
+    pub fn get_repository_config(
+        &self,
+        types: &[RepositoryConfigType],
+    ) -> Result<Vec<RepositoryConfig>, NessieError> {
+        let types = types.to_vec();
+        self.block_on(|client| async move { client.get_repository_config(&types).await })
+    }
+
+    pub fn update_repository_config(
+        &self,
+        config: RepositoryConfig,
+    ) -> Result<Option<RepositoryConfig>, NessieError> {
+        self.block_on(|client| async move { client.update_repository_config(config).await })
+    }
+
+    pub fn list_references(&self) -> Result<Vec<Reference>, NessieError> {
+        self.block_on(|client| async move { client.list_references().await })
+    }
+
+    pub fn list_references_with(
+        &self,
+        params: &ListReferencesParams,
+    ) -> Result<ReferencesResponse, NessieError> {
+        let params = params.clone();
+        self.block_on(|client| async move { client.list_references_with(&params).await })
+    }
+
+    pub fn get_reference(&self, reference: &RefSpec) -> Result<ReferenceResponse, NessieError> {
+        let reference = reference.clone();
+        self.block_on(|client| async move { client.get_reference(&reference).await })
+    }
+
+    pub fn get_reference_with(
+        &self,
+        reference: &RefSpec,
+        fetch: FetchOption,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let reference = reference.clone();
+        self.block_on(|client| async move { client.get_reference_with(&reference, fetch).await })
+    }
+
+    pub fn create_reference(
+        &self,
+        name: &str,
+        ref_type: ReferenceType,
+        source: &RefSpec,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let name = name.to_string();
+        let source = source.clone();
+        self.block_on(
+            |client| async move { client.create_reference(&name, ref_type, &source).await },
+        )
+    }
+
+    pub fn delete_reference(&self, reference: &RefSpec) -> Result<ReferenceResponse, NessieError> {
+        let reference = reference.clone();
+        self.block_on(|client| async move { client.delete_reference(&reference).await })
+    }
+
+    pub fn assign_reference(
+        &self,
+        reference: &RefSpec,
+        target: &RefSpec,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let reference = reference.clone();
+        let target = target.clone();
+        self.block_on(|client| async move { client.assign_reference(&reference, &target).await })
+    }
+
+    pub fn get_reference_history(
+        &self,
+        reference: &RefSpec,
+        scan_commits: Option<i32>,
+    ) -> Result<ReferenceHistoryResponse, NessieError> {
+        let reference = reference.clone();
+        self.block_on(|client| async move {
+            client.get_reference_history(&reference, scan_commits).await
+        })
+    }
+
+    pub fn list_entries(&self, reference: &RefSpec) -> Result<Vec<String>, NessieError> {
+        let reference = reference.clone();
+        self.block_on(|client| async move { client.list_entries(&reference).await })
+    }
+
+    pub fn list_entries_with(
+        &self,
+        reference: &RefSpec,
+        params: &EntriesParams,
+    ) -> Result<EntriesResponse, NessieError> {
+        let reference = reference.clone();
+        let params = params.clone();
+        self.block_on(|client| async move { client.list_entries_with(&reference, &params).await })
+    }
+
+    pub fn list_all_entries(
+        &self,
+        reference: &RefSpec,
+        params: &EntriesParams,
+    ) -> Result<Vec<Entry>, NessieError> {
+        let reference = reference.clone();
+        let params = params.clone();
+        self.block_on(|client| async move { client.list_all_entries(&reference, &params).await })
+    }
+
+    pub fn commit_entry(
+        &self,
+        branch: &RefSpec,
+        operations: Operations,
+    ) -> Result<CommitResponse, NessieError> {
+        let branch = branch.clone();
+        self.block_on(|client| async move { client.commit_entry(&branch, operations).await })
+    }
+}
+
+impl NessieClientBuilder {
+    /// Builds a [`BlockingNessieClient`].
+    pub fn build_blocking(self) -> Result<BlockingNessieClient, NessieError> {
+        BlockingNessieClient::from_client(self.build()?)
+    }
+
+    /// Builds a [`BlockingNessieClient`] for the newest API version the server
+    /// exposes, see [`NessieClientBuilder::build_negotiated`].
+    pub fn build_negotiated_blocking(self) -> Result<BlockingNessieClient, NessieError> {
+        let runtime = RequestRuntime::new()?;
+        let client = runtime.run(self.build_negotiated())?;
+        Ok(BlockingNessieClient {
+            client: Arc::new(client),
+            runtime,
+        })
+    }
+}
+
+/// Runtime with a single worker thread that runs the requests of a blocking
+/// client.
+#[derive(Debug)]
+struct RequestRuntime(Option<Runtime>);
+
+impl RequestRuntime {
+    fn new() -> Result<Self, NessieError> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("nessie-blocking")
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                NessieError::InvalidConfiguration(format!("cannot start runtime: {}", e))
+            })?;
+        Ok(Self(Some(runtime)))
+    }
+
+    /// Runs `future` on the runtime and waits for its output.
+    fn run<T: Send + 'static>(&self, future: impl Future<Output = T> + Send + 'static) -> T {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.0
+            .as_ref()
+            .expect("the runtime is only taken on drop")
+            .spawn(async move {
+                let _ = sender.send(future.await);
+            });
+        receiver.recv().expect("the Nessie request task panicked")
+    }
+}
+
+impl Drop for RequestRuntime {
+    fn drop(&mut self) {
+        // Dropping a runtime waits for its tasks, which panics inside another
+        // runtime.
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Client, Identity, Method, Proxy};
+
+use crate::auth::AuthenticationProvider;
+use crate::client::{api_version_of, versioned_base_url, ApiVersion, NessieClient};
+use crate::error::NessieError;
+
+const DEFAULT_USER_AGENT: &str = concat!("nessie-client-rust/", env!("CARGO_PKG_VERSION"));
+
+/// Retry policy for idempotent requests that fail with a connection error,
+/// a timeout or a 5xx response.
+///
+/// The delay before retry `n` (starting at 0) is
+/// `initial_backoff * multiplier^n`, capped at `max_backoff`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+}
+
+impl RetryPolicy {
+    /// Never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.powi(attempt as i32);
+        self.initial_backoff.mul_f64(factor).min(self.max_backoff)
+    }
+
+    /// `GET`, `HEAD`, `PUT`, `DELETE` and `OPTIONS` are safe to resend.
+    pub fn is_idempotent(method: &Method) -> bool {
+        matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// Configures the HTTP transport of a [`NessieClient`].
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use nessie_client::client::NessieClient;
+/// let client = NessieClient::builder("http://localhost:19120/api/v2/")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .proxy("http://proxy.internal:3128")
+///     .default_header("X-Team", "data-platform")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct NessieClientBuilder {
+    base_url: String,
+    api_version: Option<ApiVersion>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    root_certificates: Vec<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+    proxy: Option<String>,
+    user_agent: String,
+    default_headers: Vec<(String, String)>,
+    auth: Option<Arc<dyn AuthenticationProvider>>,
+}
+
+impl NessieClientBuilder {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            api_version: None,
+            connect_timeout: None,
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            root_certificates: Vec::new(),
+            identity: None,
+            proxy: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: Vec::new(),
+            auth: None,
+        }
+    }
+
+    /// Talks to the given API version.
+    ///
+    /// The trailing `v1`/`v2` segment of the base URL is replaced accordingly.
+    /// A base URL without a version segment is kept as given, as it may point
+    /// at a proxy or mount that already selects the API version. Without this
+    /// setting the version is taken from the base URL, defaulting to v2.
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Maximum time to establish a TCP/TLS connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum time for a whole request, from sending it until the response
+    /// body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Trusts an additional PEM encoded CA certificate.
+    pub fn add_root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Presents a client certificate for mutual TLS. Both arguments are PEM
+    /// encoded; the key must be PKCS#8.
+    pub fn client_identity_pem(
+        mut self,
+        certificate: impl Into<Vec<u8>>,
+        key: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.identity = Some((certificate.into(), key.into()));
+        self
+    }
+
+    /// Routes all requests through the given HTTP proxy.
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        self.default_headers
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn authentication(mut self, auth: impl AuthenticationProvider + 'static) -> Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
+    pub fn build(self) -> Result<NessieClient, NessieError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.default_headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+                NessieError::InvalidConfiguration(format!("header name {:?}: {}", name, e))
+            })?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                NessieError::InvalidConfiguration(format!("header value {:?}: {}", value, e))
+            })?;
+            headers.append(name, value);
+        }
+
+        let mut builder = Client::builder()
+            .user_agent(self.user_agent)
+            .default_headers(headers);
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        for pem in &self.root_certificates {
+            builder = builder.add_root_certificate(Certificate::from_pem(pem)?);
+        }
+        if let Some((certificate, key)) = &self.identity {
+            builder = builder.identity(Identity::from_pkcs8_pem(certificate, key)?);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy.as_str())?);
+        }
+
+        let base_url = self.base_url.parse()?;
+        let api_version = self
+            .api_version
+            .or_else(|| api_version_of(&base_url))
+            .unwrap_or(ApiVersion::V2);
+
+        Ok(NessieClient::from_parts(
+            versioned_base_url(&base_url, api_version)?,
+            api_version,
+            builder.build()?,
+            self.retry_policy,
+            self.auth,
+        ))
+    }
+
+    /// Builds a client for the newest API version the server exposes.
+    ///
+    /// The v2 configuration endpoint is queried first; if the server does not
+    /// have it, the client falls back to v1. Falling back replaces the version
+    /// segment of the base URL, so it fails if the URL does not end with one.
+    pub async fn build_negotiated(self) -> Result<NessieClient, NessieError> {
+        let mut client = self.api_version(ApiVersion::V2).build()?;
+        match client.get_config().await {
+            Ok(_) => Ok(client),
+            Err(NessieError::NotFound(_)) => {
+                log::debug!("Nessie API v2 not available, falling back to v1");
+                client.switch_api_version(ApiVersion::V1)?;
+                client.get_config().await?;
+                Ok(client)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,143 +1,589 @@
+use std::sync::Arc;
+
+use crate::auth::AuthenticationProvider;
+use crate::builder::{NessieClientBuilder, RetryPolicy};
 use crate::error::NessieError;
+use crate::generated::operations::{
+    AssignReferenceV2, CreateReferenceV2, DeleteReferenceV2, GetAllReferencesV2, GetConfig,
+    GetConfigV2, GetEntriesV2, GetReferenceByNameV2, GetReferenceHistory, GetRepositoryConfig,
+    UpdateRepositoryConfig,
+};
+use crate::generated::{encode_path_segment, Operation};
 use crate::models::{
-    CommitResponse, EntriesResponse, Reference, ReferenceResponse, ReferencesResponse,
+    CommitResponse, EntriesParams, EntriesResponse, Entry, FetchOption, ListReferencesParams,
+    NessieConfiguration, Reference, ReferenceHistoryResponse, ReferenceResponse, ReferenceType,
+    ReferencesResponse, RepositoryConfig, RepositoryConfigResponse, RepositoryConfigType,
+    UpdateRepositoryConfigRequest, UpdateRepositoryConfigResponse,
 };
-use reqwest::Client;
+use crate::ref_spec::{validate_name, RefSpec};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use tokio::sync::OnceCell;
 use url::Url;
 
-#[derive(Debug)]
+/// Version of the Nessie REST API a [`NessieClient`] talks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    /// The legacy `/api/v1` endpoints.
+    V1,
+    /// The `/api/v2` endpoints.
+    V2,
+}
+
+impl ApiVersion {
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2 => "v2",
+        }
+    }
+}
+
+/// Client of the Nessie REST API. Clones share the connection pool and the
+/// authentication provider.
+#[derive(Debug, Clone)]
 pub struct NessieClient {
-    base_url: Url,
-    client: Client,
+    pub(crate) base_url: Url,
+    pub(crate) client: Client,
+    api_version: ApiVersion,
+    retry_policy: RetryPolicy,
+    auth: Option<Arc<dyn AuthenticationProvider>>,
+    default_branch: OnceCell<String>,
 }
 
 impl NessieClient {
     pub fn new(base_url: &str) -> Result<Self, NessieError> {
-        Ok(Self {
-            base_url: base_url.parse()?,
-            client: Client::new(),
-        })
+        NessieClientBuilder::new(base_url).build()
     }
 
-    pub async fn list_references(&self) -> Result<Vec<Reference>, NessieError> {
-        let url = self.base_url.join("trees")?;
-        // let response = self.client.get(url).send().await?.json::<ReferencesResponse>()?;
-        let response = self
+    pub fn builder(base_url: &str) -> NessieClientBuilder {
+        NessieClientBuilder::new(base_url)
+    }
+
+    pub(crate) fn from_parts(
+        base_url: Url,
+        api_version: ApiVersion,
+        client: Client,
+        retry_policy: RetryPolicy,
+        auth: Option<Arc<dyn AuthenticationProvider>>,
+    ) -> Self {
+        Self {
+            base_url,
+            client,
+            api_version,
+            retry_policy,
+            auth,
+            default_branch: OnceCell::new(),
+        }
+    }
+
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Points the client at the endpoints of another API version on the same
+    /// server, which requires the base URL to end with a version segment.
+    pub(crate) fn switch_api_version(
+        &mut self,
+        api_version: ApiVersion,
+    ) -> Result<(), NessieError> {
+        if api_version_of(&self.base_url).is_none() {
+            return Err(NessieError::InvalidConfiguration(format!(
+                "cannot switch {} to API {}: it does not end with a v1 or v2 segment",
+                self.base_url,
+                api_version.path_segment()
+            )));
+        }
+        self.base_url = versioned_base_url(&self.base_url, api_version)?;
+        self.api_version = api_version;
+        Ok(())
+    }
+
+    fn ensure_v2(&self, operation: &str) -> Result<(), NessieError> {
+        match self.api_version {
+            ApiVersion::V2 => Ok(()),
+            ApiVersion::V1 => Err(NessieError::Unsupported(format!(
+                "{} requires Nessie API v2",
+                operation
+            ))),
+        }
+    }
+
+    /// Authenticates every request with the given provider.
+    pub fn with_authentication(mut self, auth: impl AuthenticationProvider + 'static) -> Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
+    /// Sends a request, retrying idempotent ones according to the retry policy.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response, NessieError> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        let max_retries = if RetryPolicy::is_idempotent(request.method()) {
+            self.retry_policy.max_retries
+        } else {
+            0
+        };
+
+        let mut attempt = 0;
+        loop {
+            // Requests with a streaming body cannot be cloned, and so cannot be retried.
+            let Some(current) = request.try_clone() else {
+                return self
+                    .send_once(RequestBuilder::from_parts(client, request))
+                    .await;
+            };
+
+            let result = self
+                .send_once(RequestBuilder::from_parts(client.clone(), current))
+                .await;
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(NessieError::Http(e)) => e.is_connect() || e.is_timeout(),
+                Err(_) => false,
+            };
+            if !retryable || attempt >= max_retries {
+                return result;
+            }
+
+            log::debug!(
+                "retrying {} {} (attempt {})",
+                request.method(),
+                request.url(),
+                attempt + 1
+            );
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    async fn send_once(&self, request: RequestBuilder) -> Result<Response, NessieError> {
+        let request = match &self.auth {
+            Some(auth) => auth.authenticate(request).await?,
+            None => request,
+        };
+        Ok(request.send().await?)
+    }
+
+    /// Turns a non-2xx response into an error, keeping the body for context.
+    pub(crate) async fn check_status(response: Response) -> Result<Response, NessieError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "<failed to read body>".to_string());
+        if status == StatusCode::CONFLICT {
+            Err(NessieError::Conflict(body))
+        } else if status == StatusCode::NOT_FOUND {
+            Err(NessieError::NotFound(body))
+        } else {
+            Err(NessieError::InvalidResponse(format!(
+                "HTTP {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Sends any operation of the Nessie REST API, including those the other
+    /// methods of the client do not cover. See [`crate::generated`].
+    ///
+    /// The operation must belong to the API version the client talks.
+    pub async fn execute<O: Operation>(&self, operation: &O) -> Result<O::Response, NessieError> {
+        let response = self.send(self.request_for(operation)?).await?;
+        let body = Self::check_status(response).await?.text().await?;
+        // Operations without a response payload answer with an empty body.
+        let body = if body.is_empty() {
+            "null"
+        } else {
+            body.as_str()
+        };
+        serde_json::from_str(body)
+            .map_err(|e| NessieError::InvalidResponse(format!("{} {}: {}", O::METHOD, O::PATH, e)))
+    }
+
+    /// Prepares the request of `operation`: method, URL, query and body.
+    pub(crate) fn request_for<O: Operation>(
+        &self,
+        operation: &O,
+    ) -> Result<RequestBuilder, NessieError> {
+        if O::API_VERSION != self.api_version {
+            return Err(NessieError::Unsupported(format!(
+                "{} {} requires Nessie API {}",
+                O::METHOD,
+                O::PATH,
+                O::API_VERSION.path_segment()
+            )));
+        }
+        let url = self.base_url.join(&operation.path())?;
+        let mut request = self
             .client
-            .get(url)
-            .send()
+            .request(O::METHOD, url)
+            .query(&operation.query());
+        if let Some(body) = operation.body() {
+            request = request.json(body);
+        }
+        Ok(request)
+    }
+
+    pub async fn get_config(&self) -> Result<NessieConfiguration, NessieError> {
+        let request = match self.api_version {
+            ApiVersion::V1 => self.request_for(&GetConfig)?,
+            ApiVersion::V2 => self.request_for(&GetConfigV2)?,
+        };
+        let response = self.send(request).await?;
+        Ok(Self::check_status(response)
             .await?
-            .json::<ReferencesResponse>()
+            .json::<NessieConfiguration>()
+            .await?)
+    }
+
+    /// Name of the server's default branch, fetched once from the server
+    /// configuration and cached.
+    pub async fn default_branch(&self) -> Result<String, NessieError> {
+        let branch = self
+            .default_branch
+            .get_or_try_init(|| async {
+                self.get_config().await?.default_branch.ok_or_else(|| {
+                    NessieError::InvalidResponse(
+                        "server configuration has no default branch".to_string(),
+                    )
+                })
+            })
+            .await?;
+        Ok(branch.clone())
+    }
+
+    /// Returns the repository configuration objects of the given types that
+    /// exist on the server.
+    pub async fn get_repository_config(
+        &self,
+        types: &[RepositoryConfigType],
+    ) -> Result<Vec<RepositoryConfig>, NessieError> {
+        self.ensure_v2("get_repository_config")?;
+        let operation = GetRepositoryConfig {
+            r#type: types.iter().map(ToString::to_string).collect(),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<RepositoryConfigResponse>()
+            .await?
+            .configs)
+    }
+
+    /// Creates or replaces a repository configuration object, returning the
+    /// previous one of the same type if any.
+    pub async fn update_repository_config(
+        &self,
+        config: RepositoryConfig,
+    ) -> Result<Option<RepositoryConfig>, NessieError> {
+        self.ensure_v2("update_repository_config")?;
+        // The specification omits the payload of this operation.
+        let request = self
+            .request_for(&UpdateRepositoryConfig)?
+            .json(&UpdateRepositoryConfigRequest { config });
+        let response = self.send(request).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<UpdateRepositoryConfigResponse>()
+            .await?
+            .previous)
+    }
+
+    pub async fn list_references(&self) -> Result<Vec<Reference>, NessieError> {
+        let response = self
+            .list_references_with(&ListReferencesParams::default())
             .await?;
         Ok(response.references)
     }
 
+    /// Lists one page of references, optionally filtered and with metadata.
+    pub async fn list_references_with(
+        &self,
+        params: &ListReferencesParams,
+    ) -> Result<ReferencesResponse, NessieError> {
+        if self.api_version == ApiVersion::V1 {
+            return self.list_references_v1(params).await;
+        }
+        let operation = GetAllReferencesV2 {
+            fetch: params.fetch,
+            filter: params.filter.clone(),
+            max_records: params.max_records,
+            page_token: params.page_token.clone(),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<ReferencesResponse>()
+            .await?)
+    }
+
     pub async fn get_reference(
         &self,
-        reference_name: String,
+        reference: &RefSpec,
     ) -> Result<ReferenceResponse, NessieError> {
-        let url = self.base_url.join(&format!("trees/{}", reference_name))?;
-        let response = self
-            .client
-            .get(url)
-            .send()
+        if self.api_version == ApiVersion::V1 {
+            return self.get_reference_v1(reference, None).await;
+        }
+        let operation = GetReferenceByNameV2 {
+            r#ref: reference.to_string(),
+            fetch: None,
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
             .await?
             .json::<ReferenceResponse>()
-            .await?;
-        Ok(response)
+            .await?)
+    }
+
+    /// Fetches a reference, including its [`ReferenceMetadata`] when `fetch`
+    /// is [`FetchOption::All`].
+    ///
+    /// [`ReferenceMetadata`]: crate::models::ReferenceMetadata
+    pub async fn get_reference_with(
+        &self,
+        reference: &RefSpec,
+        fetch: FetchOption,
+    ) -> Result<ReferenceResponse, NessieError> {
+        if self.api_version == ApiVersion::V1 {
+            return self.get_reference_v1(reference, Some(fetch)).await;
+        }
+        let operation = GetReferenceByNameV2 {
+            r#ref: reference.to_string(),
+            fetch: Some(fetch),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<ReferenceResponse>()
+            .await?)
     }
 
+    /// Creates the branch or tag `name` pointing at the commit of `source`,
+    /// which must carry a hash, e.g. `main@2e1cfa82` or `@2e1cfa82`.
     pub async fn create_reference(
         &self,
-        name: String,
-        ref_type: String,
-        reference: Reference,
+        name: &str,
+        ref_type: ReferenceType,
+        source: &RefSpec,
     ) -> Result<ReferenceResponse, NessieError> {
-        let url = self.base_url.join("trees")?;
-        let response = self
-            .client
-            .post(url)
-            .query(&[("name", name), ("type", ref_type)])
-            .json(&reference)
-            .send()
+        validate_name(name)?;
+        let source = source.to_reference("create_reference")?;
+        if self.api_version == ApiVersion::V1 {
+            return self.create_reference_v1(name, ref_type, &source).await;
+        }
+        let operation = CreateReferenceV2 {
+            name: name.to_string(),
+            r#type: ref_type.to_string(),
+            body: (&source).into(),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
             .await?
             .json::<ReferenceResponse>()
-            .await?;
-        Ok(response)
+            .await?)
     }
 
+    /// Deletes a branch or tag. `reference` must carry the expected head hash.
     pub async fn delete_reference(
         &self,
-        reference: Reference,
+        reference: &RefSpec,
     ) -> Result<ReferenceResponse, NessieError> {
-        let url = self.base_url.join(&format!(
-            "trees/{}@{}",
-            reference.name,
-            reference.hash.unwrap()
-        ))?;
-        let response = self
-            .client
-            .delete(url)
-            .send()
+        let name = reference.require_name("delete_reference")?;
+        let hash = reference.require_hash("delete_reference")?;
+        if self.api_version == ApiVersion::V1 {
+            return self.delete_reference_v1(name, hash).await;
+        }
+        let operation = DeleteReferenceV2 {
+            r#ref: format!("{}@{}", name, hash),
+            r#type: None,
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
             .await?
             .json::<ReferenceResponse>()
-            .await?;
-        Ok(response)
+            .await?)
     }
 
-    pub async fn list_entries(&self, reference: Reference) -> Result<Vec<String>, NessieError> {
-        let url = self.base_url.join(&format!(
-            "trees/{}@{}/entries",
-            reference.name,
-            reference.hash.unwrap()
-        ))?;
-        let response = self.client.get(url).send().await?;
+    /// Points `reference` at the commit `target` resolves to.
+    ///
+    /// The hash of `reference` is the head the caller expects the branch or tag
+    /// to currently have; if it has moved in the meantime the server refuses
+    /// the assignment and [`NessieError::Conflict`] is returned. `target` may
+    /// be a branch or tag at a hash, or a detached commit.
+    pub async fn assign_reference(
+        &self,
+        reference: &RefSpec,
+        target: &RefSpec,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let name = reference.require_name("assign_reference")?;
+        let expected_hash = reference.require_hash("assign_reference")?;
+        let target = target.to_reference("assign_reference")?;
+        if self.api_version == ApiVersion::V1 {
+            return self.assign_reference_v1(name, expected_hash, &target).await;
+        }
+        let operation = AssignReferenceV2 {
+            r#ref: format!("{}@{}", name, expected_hash),
+            r#type: None,
+            body: (&target).into(),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<ReferenceResponse>()
+            .await?)
+    }
 
-        // if !response.status().is_success() {
-        //     let status = response.status();
-        //     let body = response.text().unwrap_or_default();
-        //     return Err(NessieError::InvalidResponse(format!(
-        //         "Status: {}, Body: {}",
-        //         status, body
-        //     )));
-        // }
+    /// Fetches the recent changes of the HEAD of a branch or tag, so that
+    /// re-assignments can be audited and past heads recovered.
+    ///
+    /// With `scan_commits`, that many commits of the log are also checked for
+    /// consistency. The depth of the history is limited by the server
+    /// configuration.
+    pub async fn get_reference_history(
+        &self,
+        reference: &RefSpec,
+        scan_commits: Option<i32>,
+    ) -> Result<ReferenceHistoryResponse, NessieError> {
+        self.ensure_v2("get_reference_history")?;
+        let name = reference.require_name("get_reference_history")?;
+        let operation = GetReferenceHistory {
+            r#ref: name.to_string(),
+            scan_commits,
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<ReferenceHistoryResponse>()
+            .await?)
+    }
 
-        let parsed: EntriesResponse = response.json().await?;
+    /// Lists the keys of all entries, in their path form; see
+    /// [`ContentKey::from_path_string`] to get the elements back.
+    ///
+    /// [`ContentKey::from_path_string`]: crate::models::ContentKey::from_path_string
+    pub async fn list_entries(&self, reference: &RefSpec) -> Result<Vec<String>, NessieError> {
+        let entries = self
+            .list_all_entries(reference, &EntriesParams::default())
+            .await?;
+        Ok(entries.into_iter().map(|e| e.name.to_string()).collect())
+    }
 
-        let entries = parsed
-            .entries
-            .into_iter()
-            .map(|e| e.name.to_string())
-            .collect();
+    /// Lists one page of the entries matching `params`.
+    pub async fn list_entries_with(
+        &self,
+        reference: &RefSpec,
+        params: &EntriesParams,
+    ) -> Result<EntriesResponse, NessieError> {
+        if self.api_version == ApiVersion::V1 {
+            return self.list_entries_v1(reference, params).await;
+        }
+        let operation = GetEntriesV2 {
+            r#ref: reference.to_string(),
+            content: params.with_content.then_some(true),
+            filter: params.cel_filter(),
+            key: params.keys.clone(),
+            max_key: params.max_key.as_ref().map(ToString::to_string),
+            max_records: params.max_records,
+            min_key: params.min_key.as_ref().map(ToString::to_string),
+            page_token: params.page_token.clone(),
+            prefix_key: params.prefix_key.as_ref().map(ToString::to_string),
+        };
+        let response = self.send(self.request_for(&operation)?).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<EntriesResponse>()
+            .await?)
+    }
 
-        Ok(entries)
+    /// Lists all entries matching `params`, following the pages from
+    /// `params.page_token` on.
+    pub async fn list_all_entries(
+        &self,
+        reference: &RefSpec,
+        params: &EntriesParams,
+    ) -> Result<Vec<Entry>, NessieError> {
+        let mut params = params.clone();
+        let mut entries = Vec::new();
+        loop {
+            let page = self.list_entries_with(reference, &params).await?;
+            entries.extend(page.entries);
+            match page.token {
+                Some(token) if page.has_more => params.page_token = Some(token),
+                _ => return Ok(entries),
+            }
+        }
     }
 
+    /// Commits `operations` on top of the expected head of `branch`. The
+    /// returned `target_branch` is the new head to chain further commits on.
     pub async fn commit_entry(
         &self,
-        reference: Reference,
+        branch: &RefSpec,
         operations: crate::models::Operations,
     ) -> Result<crate::models::CommitResponse, NessieError> {
+        let name = branch.require_name("commit_entry")?;
+        let hash = branch.require_hash("commit_entry")?;
+        if self.api_version == ApiVersion::V1 {
+            return self.commit_entry_v1(name, hash, &operations).await;
+        }
         let url = self.base_url.join(&format!(
             "trees/{}@{}/history/commit",
-            reference.name,
-            reference.hash.unwrap()
+            encode_path_segment(name),
+            encode_path_segment(hash)
         ))?;
-        let response = self.client.post(url).json(&operations).send().await?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "<failed to read body>".to_string());
-            println!("{}", status);
-            println!("{}", body);
-            return Err(NessieError::InvalidResponse(format!(
-                "HTTP {}: {}",
-                status, body
-            )));
-        }
-        Ok(response.json::<CommitResponse>().await?)
+        let response = self.send(self.client.post(url).json(&operations)).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<CommitResponse>()
+            .await?)
+    }
+}
+
+/// Returns `base_url` with its trailing `v1`/`v2` segment replaced by the
+/// segment of `api_version`. A URL without a version segment is kept as given,
+/// as it may point at a proxy or mount that already selects the API version.
+pub(crate) fn versioned_base_url(
+    base_url: &Url,
+    api_version: ApiVersion,
+) -> Result<Url, NessieError> {
+    let mut url = base_url.clone();
+    let mut segments: Vec<String> = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    if matches!(segments.last().map(String::as_str), Some("v1" | "v2")) {
+        segments.pop();
+        segments.push(api_version.path_segment().to_string());
+    }
+
+    url.path_segments_mut()
+        .map_err(|_| {
+            NessieError::InvalidConfiguration(format!("{} cannot be a base URL", base_url))
+        })?
+        .clear()
+        .extend(&segments)
+        // Trailing slash, so that relative paths are joined below the version.
+        .push("");
+    Ok(url)
+}
+
+/// The API version named by the last path segment of `base_url`, if any.
+pub(crate) fn api_version_of(base_url: &Url) -> Option<ApiVersion> {
+    let last = base_url
+        .path_segments()?
+        .rfind(|segment| !segment.is_empty())?;
+    match last {
+        "v1" => Some(ApiVersion::V1),
+        "v2" => Some(ApiVersion::V2),
+        _ => None,
     }
 }
@@ -0,0 +1,97 @@
+//! Path form and validation of [`ContentKey`], the key of a content object
+//! in a Nessie tree.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::NessieError;
+pub use crate::generated::models::ContentKey;
+
+/// Stands for a `.` inside a key element in the path form of a content key.
+const GROUP_SEPARATOR: char = '\u{1D}';
+
+impl ContentKey {
+    pub fn new<I, S>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            elements: elements.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The path form Nessie uses in URLs and `key` query parameters: the
+    /// elements joined with `.`, dots inside an element written as `\u001D`.
+    pub fn to_path_string(&self) -> String {
+        self.elements
+            .iter()
+            .map(|element| element.replace('.', &GROUP_SEPARATOR.to_string()))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Parses the path form written by [`Self::to_path_string`].
+    pub fn from_path_string(path: &str) -> Result<Self, NessieError> {
+        let key = Self::new(
+            path.split('.')
+                .map(|element| element.replace(GROUP_SEPARATOR, ".")),
+        );
+        key.validate()?;
+        Ok(key)
+    }
+
+    /// Checks the rules Nessie enforces on keys: at least one element, no
+    /// empty element and no NUL character.
+    pub fn validate(&self) -> Result<(), NessieError> {
+        if self.elements.is_empty() {
+            return Err(NessieError::InvalidKey(
+                "a content key needs at least one element".to_string(),
+            ));
+        }
+        if let Some(element) = self
+            .elements
+            .iter()
+            .find(|element| element.is_empty() || element.contains('\0'))
+        {
+            return Err(NessieError::InvalidKey(format!(
+                "invalid element {:?} in {:?}",
+                element, self.elements
+            )));
+        }
+        Ok(())
+    }
+
+    /// The key without its last element, e.g. the namespace of a table.
+    pub fn parent(&self) -> Option<Self> {
+        match self.elements.as_slice() {
+            [] | [_] => None,
+            [parent @ .., _] => Some(Self::new(parent.iter().cloned())),
+        }
+    }
+
+    /// The last element.
+    pub fn name(&self) -> &str {
+        self.elements.last().map(String::as_str).unwrap_or_default()
+    }
+
+    /// Whether `self` is `prefix` or lies below it.
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        self.elements.starts_with(&prefix.elements)
+    }
+}
+
+/// Writes the path form, see [`ContentKey::to_path_string`].
+impl fmt::Display for ContentKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_path_string())
+    }
+}
+
+impl FromStr for ContentKey {
+    type Err = NessieError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::from_path_string(path)
+    }
+}
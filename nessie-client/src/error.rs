@@ -10,16 +10,41 @@ pub enum NessieError {
 
     #[error("URL parse error: {0}")]
     Url(#[from] url::ParseError),
+
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
+
+    #[error("Invalid client configuration: {0}")]
+    InvalidConfiguration(String),
+
+    #[error("Invalid reference: {0}")]
+    InvalidReference(String),
+
+    #[error("Invalid content key: {0}")]
+    InvalidKey(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
+
+    #[error("Iceberg error: {0}")]
+    Iceberg(#[from] iceberg::Error),
 }
 
 impl From<NessieError> for iceberg::Error {
     fn from(value: NessieError) -> Self {
-        iceberg::Error::new(iceberg::ErrorKind::DataInvalid, "test")
-    }
-}
-
-impl From<iceberg::Error> for NessieError {
-    fn from(value: iceberg::Error) -> Self {
-        todo!()
+        let kind = match &value {
+            NessieError::InvalidKey(_) | NessieError::InvalidReference(_) => {
+                iceberg::ErrorKind::DataInvalid
+            }
+            NessieError::Unsupported(_) => iceberg::ErrorKind::FeatureUnsupported,
+            _ => iceberg::ErrorKind::Unexpected,
+        };
+        iceberg::Error::new(kind, value.to_string()).with_source(value)
     }
 }
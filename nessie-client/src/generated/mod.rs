@@ -0,0 +1,79 @@
+//! Models and operations generated from the Nessie OpenAPI specification
+//! (`swagger.yml` at the root of the repository).
+//!
+//! `models` holds one type per schema of the specification; `_V1`/`_V2`
+//! schemas become `V1`/`V2` suffixed types. `operations` holds one type per
+//! endpoint, carrying its path and query parameters and its request body.
+//! Any operation can be sent with [`NessieClient::execute`]:
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), nessie_client::error::NessieError> {
+//! use nessie_client::client::NessieClient;
+//! use nessie_client::generated::operations::GetCommitLogV2;
+//!
+//! let client = NessieClient::new("http://localhost:19120/api/v2/")?;
+//! let log = client
+//!     .execute(&GetCommitLogV2 {
+//!         r#ref: "main".to_string(),
+//!         max_records: Some(10),
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! `models.rs` and `operations.rs` are regenerated with
+//! `cargo run -p nessie-codegen` when the specification is updated.
+//!
+//! [`NessieClient::execute`]: crate::client::NessieClient::execute
+
+use std::fmt;
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::ApiVersion;
+
+pub mod models;
+pub mod operations;
+
+/// An endpoint of the Nessie REST API.
+pub trait Operation {
+    /// Request payload, `()` if there is none.
+    type Body: Serialize;
+    /// Response payload, `()` if the server answers with an empty body.
+    type Response: DeserializeOwned;
+
+    const METHOD: Method;
+    /// Path template of the endpoint in the specification.
+    const PATH: &'static str;
+    const API_VERSION: ApiVersion;
+
+    /// Path of this request relative to the versioned base URL.
+    fn path(&self) -> String;
+
+    /// Query parameters that are set, repeated for multi-valued ones.
+    fn query(&self) -> Vec<(&'static str, String)>;
+
+    fn body(&self) -> Option<&Self::Body>;
+}
+
+/// Percent-encodes `value` for use as one URL path segment. Only unreserved
+/// characters and the `@`, `*` and `:` of reference specifications are kept
+/// as they are.
+pub(crate) fn encode_path_segment<T: fmt::Display + ?Sized>(value: &T) -> String {
+    let value = value.to_string();
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'@' | b'*' | b':' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,1739 @@
+// Generated by nessie-codegen from swagger.yml (Nessie API 0.101.1).
+// Do not edit by hand: run `cargo run -p nessie-codegen` instead.
+
+#![allow(clippy::large_enum_variant, clippy::upper_case_acronyms)]
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddedContent {
+    pub key: ContentKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddedContentV2 {
+    pub key: ContentKeyV2,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Branch {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchV1 {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchV2 {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CommitConsistency {
+    #[serde(rename = "NOT_CHECKED")]
+    NotChecked,
+    #[serde(rename = "COMMIT_CONSISTENT")]
+    CommitConsistent,
+    #[serde(rename = "COMMIT_CONTENT_INCONSISTENT")]
+    CommitContentInconsistent,
+    #[serde(rename = "COMMIT_INCONSISTENT")]
+    CommitInconsistent,
+}
+
+impl CommitConsistency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitConsistency::NotChecked => "NOT_CHECKED",
+            CommitConsistency::CommitConsistent => "COMMIT_CONSISTENT",
+            CommitConsistency::CommitContentInconsistent => "COMMIT_CONTENT_INCONSISTENT",
+            CommitConsistency::CommitInconsistent => "COMMIT_INCONSISTENT",
+        }
+    }
+}
+
+impl fmt::Display for CommitConsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CommitConsistencyV2 {
+    #[serde(rename = "NOT_CHECKED")]
+    NotChecked,
+    #[serde(rename = "COMMIT_CONSISTENT")]
+    CommitConsistent,
+    #[serde(rename = "COMMIT_CONTENT_INCONSISTENT")]
+    CommitContentInconsistent,
+    #[serde(rename = "COMMIT_INCONSISTENT")]
+    CommitInconsistent,
+}
+
+impl CommitConsistencyV2 {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitConsistencyV2::NotChecked => "NOT_CHECKED",
+            CommitConsistencyV2::CommitConsistent => "COMMIT_CONSISTENT",
+            CommitConsistencyV2::CommitContentInconsistent => "COMMIT_CONTENT_INCONSISTENT",
+            CommitConsistencyV2::CommitInconsistent => "COMMIT_INCONSISTENT",
+        }
+    }
+}
+
+impl fmt::Display for CommitConsistencyV2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_off_by: Option<String>,
+    #[serde(default)]
+    pub all_signed_off_by: Vec<String>,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    #[serde(default)]
+    pub all_properties: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub parent_commit_hashes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMetaV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_off_by: Option<String>,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMetaV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<String>,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub all_signed_off_by: Vec<String>,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub all_properties: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub parent_commit_hashes: Vec<String>,
+}
+
+/// Commit Response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitResponse {
+    pub target_branch: Branch,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_contents: Option<Vec<AddedContent>>,
+}
+
+/// Commit Response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitResponseV2 {
+    pub target_branch: BranchV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_contents: Option<Vec<AddedContentV2>>,
+}
+
+/// Per Content Key conflict details.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Conflict {
+    /// Conflict type (enum).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict_type: Option<serde_json::Value>,
+    /// The conflicting Content Key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<serde_json::Value>,
+    /// Conflict details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<serde_json::Value>,
+}
+
+/// Per Content Key conflict details.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictV2 {
+    /// Conflict type (enum).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict_type: Option<serde_json::Value>,
+    /// The conflicting Content Key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<serde_json::Value>,
+    /// Conflict details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Content {
+    #[serde(rename = "ICEBERG_TABLE")]
+    IcebergTable(IcebergTable),
+    #[serde(rename = "DELTA_LAKE_TABLE")]
+    DeltaLakeTable(DeltaLakeTable),
+    #[serde(rename = "ICEBERG_VIEW")]
+    IcebergView(IcebergView),
+    #[serde(rename = "NAMESPACE")]
+    Namespace(Namespace),
+    #[serde(rename = "UDF")]
+    Udf(UDF),
+    /// A kind this version of the specification does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKey {
+    #[serde(default)]
+    pub elements: Vec<String>,
+}
+
+/// Merge Per-Content-Key details.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKeyDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_behavior: Option<MergeBehavior>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict: Option<Conflict>,
+}
+
+/// Merge Per-Content-Key details.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKeyDetailsV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKeyV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_behavior: Option<MergeBehaviorV1>,
+}
+
+/// Merge Per-Content-Key details.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKeyDetailsV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKeyV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_behavior: Option<MergeBehaviorV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict: Option<ConflictV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKeyV1 {
+    #[serde(default)]
+    pub elements: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentKeyV2 {
+    #[serde(default)]
+    pub elements: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMetadata {
+    pub variant: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMetadataV2 {
+    pub variant: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentResponseV2 {
+    pub content: ContentV2,
+    pub effective_reference: ReferenceV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<DocumentationV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWithKey {
+    pub key: ContentKey,
+    pub content: Content,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWithKeyV1 {
+    pub key: ContentKeyV1,
+    pub content: ContentV1,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWithKeyV2 {
+    pub key: ContentKeyV2,
+    pub content: ContentV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<DocumentationV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ContentV1 {
+    #[serde(rename = "ICEBERG_TABLE")]
+    IcebergTable(IcebergTableV1),
+    #[serde(rename = "DELTA_LAKE_TABLE")]
+    DeltaLakeTable(DeltaLakeTableV1),
+    #[serde(rename = "ICEBERG_VIEW")]
+    IcebergView(IcebergViewV1),
+    #[serde(rename = "NAMESPACE")]
+    Namespace(NamespaceV1),
+    #[serde(rename = "UDF")]
+    Udf(UDFV1),
+    /// A kind this version of the specification does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ContentV2 {
+    #[serde(rename = "ICEBERG_TABLE")]
+    IcebergTable(IcebergTableV2),
+    #[serde(rename = "DELTA_LAKE_TABLE")]
+    DeltaLakeTable(DeltaLakeTableV2),
+    #[serde(rename = "ICEBERG_VIEW")]
+    IcebergView(IcebergViewV2),
+    #[serde(rename = "NAMESPACE")]
+    Namespace(NamespaceV2),
+    #[serde(rename = "UDF")]
+    Udf(UDFV2),
+    /// A kind this version of the specification does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Used to delete an existing content key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delete {
+    pub key: ContentKey,
+}
+
+/// Used to delete an existing content key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteV1 {
+    pub key: ContentKeyV1,
+}
+
+/// Used to delete an existing content key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteV2 {
+    pub key: ContentKeyV2,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaLakeTable {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub metadata_location_history: Vec<String>,
+    #[serde(default)]
+    pub checkpoint_location_history: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checkpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaLakeTableV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub metadata_location_history: Vec<String>,
+    #[serde(default)]
+    pub checkpoint_location_history: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checkpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaLakeTableV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub metadata_location_history: Vec<String>,
+    #[serde(default)]
+    pub checkpoint_location_history: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checkpoint: Option<String>,
+}
+
+/// Detached commit hash.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Detached {
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadata>,
+}
+
+/// Detached commit hash.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetachedV1 {
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV1>,
+}
+
+/// Detached commit hash.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetachedV2 {
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Content>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntryV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKeyV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<ContentV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<ContentV1>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntryV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKeyV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<ContentV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<ContentV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diffs: Option<Vec<DiffEntry>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_from_reference: Option<Reference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_to_reference: Option<Reference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffResponseV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diffs: Option<Vec<DiffEntryV1>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffResponseV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diffs: Option<Vec<DiffEntryV2>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_from_reference: Option<ReferenceV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_to_reference: Option<ReferenceV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Documentation {
+    pub mime_type: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentationV2 {
+    pub mime_type: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntriesResponseV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub entries: Vec<EntryV1>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntriesResponseV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub entries: Vec<EntryV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_reference: Option<ReferenceV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Type>,
+    pub name: ContentKeyV1,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Type>,
+    pub name: ContentKeyV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<ContentV2>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FetchOption {
+    #[serde(rename = "MINIMAL")]
+    Minimal,
+    #[serde(rename = "ALL")]
+    All,
+}
+
+impl FetchOption {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FetchOption::Minimal => "MINIMAL",
+            FetchOption::All => "ALL",
+        }
+    }
+}
+
+impl fmt::Display for FetchOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Garbage collector config object.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageCollectorConfig {
+    /// The default cutoff policy. Policies can be one of: - number of commits
+    /// as an integer value - a duration (see java.time.Duration) - an ISO
+    /// instant - 'NONE', means everything's considered as live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_cutoff_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_ref_cutoff_policies: Option<Vec<ReferenceCutoffPolicy>>,
+    /// Files that have been created after 'gc-start-time -
+    /// new-files-grace-period' are not being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_files_grace_period: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_file_count_per_content: Option<i32>,
+}
+
+/// Garbage collector config object.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageCollectorConfigV2 {
+    /// The default cutoff policy. Policies can be one of: - number of commits
+    /// as an integer value - a duration (see java.time.Duration) - an ISO
+    /// instant - 'NONE', means everything's considered as live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_cutoff_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_ref_cutoff_policies: Option<Vec<ReferenceCutoffPolicyV2>>,
+    /// Files that have been created after 'gc-start-time -
+    /// new-files-grace-period' are not being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_files_grace_period: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_file_count_per_content: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMultipleContentsRequest {
+    #[serde(default)]
+    pub requested_keys: Vec<ContentKey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMultipleContentsResponse {
+    #[serde(default)]
+    pub contents: Vec<ContentWithKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_reference: Option<Reference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMultipleContentsResponseV1 {
+    #[serde(default)]
+    pub contents: Vec<ContentWithKeyV1>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMultipleContentsResponseV2 {
+    #[serde(default)]
+    pub contents: Vec<ContentWithKeyV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_reference: Option<ReferenceV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNamespacesResponseV1 {
+    #[serde(default)]
+    pub namespaces: Vec<NamespaceV1>,
+}
+
+/// Represents the state of an Iceberg table in Nessie. An Iceberg table is
+/// globally identified via its unique 'Content.id'.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergTable {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Represents the state of an Iceberg table in Nessie. An Iceberg table is
+/// globally identified via its unique 'Content.id'.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergTableV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Represents the state of an Iceberg table in Nessie. An Iceberg table is
+/// globally identified via its unique 'Content.id'.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergTableV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergViewV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergViewV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub metadata_location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub commit_meta: CommitMeta,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_parents: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_commit_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Vec<Operation>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntryV1 {
+    pub commit_meta: CommitMetaV1,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_parents: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_commit_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Vec<OperationV1>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntryV2 {
+    pub commit_meta: CommitMetaV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_commit_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Vec<OperationV2>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub log_entries: Vec<LogEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogResponseV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub log_entries: Vec<LogEntryV1>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogResponseV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub log_entries: Vec<LogEntryV2>,
+}
+
+/// Merge Operation.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Merge {
+    pub from_ref_name: String,
+    pub from_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_merge_modes: Option<Vec<MergeKeyBehavior>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_key_merge_mode: Option<MergeBehavior>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_additional_info: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_conflict_as_result: Option<bool>,
+}
+
+/// Merge Operation.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Merge1 {
+    /// Optional commit message for this merge request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The hash of the last commit to merge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_hash: Option<String>,
+    /// The name of the reference that contains the 'source' commits for the
+    /// requested merge or transplant operation.
+    pub from_ref_name: String,
+    /// Specific merge behaviour requests by content key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_merge_modes: Option<Vec<MergeKeyBehavior>>,
+    /// The default merge mode. If not set, `NORMAL` is assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_key_merge_mode: Option<MergeBehavior>,
+    /// When set to 'true' instructs the server to validate the request but to
+    /// avoid committing any changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// Whether to provide optional response data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_additional_info: Option<bool>,
+    /// When set to 'true' instructs the server to produce normal (non-error)
+    /// responses in case a conflict is detected and report conflict details in
+    /// the response payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_conflict_as_result: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_meta: Option<CommitMeta>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MergeBehavior {
+    #[serde(rename = "NORMAL")]
+    Normal,
+    #[serde(rename = "FORCE")]
+    Force,
+    #[serde(rename = "DROP")]
+    Drop,
+}
+
+impl MergeBehavior {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeBehavior::Normal => "NORMAL",
+            MergeBehavior::Force => "FORCE",
+            MergeBehavior::Drop => "DROP",
+        }
+    }
+}
+
+impl fmt::Display for MergeBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MergeBehaviorV1 {
+    #[serde(rename = "NORMAL")]
+    Normal,
+    #[serde(rename = "FORCE")]
+    Force,
+    #[serde(rename = "DROP")]
+    Drop,
+}
+
+impl MergeBehaviorV1 {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeBehaviorV1::Normal => "NORMAL",
+            MergeBehaviorV1::Force => "FORCE",
+            MergeBehaviorV1::Drop => "DROP",
+        }
+    }
+}
+
+impl fmt::Display for MergeBehaviorV1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MergeBehaviorV2 {
+    #[serde(rename = "NORMAL")]
+    Normal,
+    #[serde(rename = "FORCE")]
+    Force,
+    #[serde(rename = "DROP")]
+    Drop,
+}
+
+impl MergeBehaviorV2 {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeBehaviorV2::Normal => "NORMAL",
+            MergeBehaviorV2::Force => "FORCE",
+            MergeBehaviorV2::Drop => "DROP",
+        }
+    }
+}
+
+impl fmt::Display for MergeBehaviorV2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeKeyBehavior {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<ContentKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_behavior: Option<MergeBehavior>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_target_content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_target_documentation: Option<Documentation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_documentation: Option<Documentation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<ContentMetadata>>,
+}
+
+/// Merge Response.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resultant_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<ContentKeyDetails>>,
+}
+
+/// Merge Response.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeResponseV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resultant_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<ContentKeyDetailsV1>>,
+}
+
+/// Merge Response.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeResponseV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resultant_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_target_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<ContentKeyDetailsV2>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Namespace {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub elements: Vec<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceUpdate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property_updates: Option<HashMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property_removals: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub elements: Vec<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub elements: Vec<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+/// Configuration object to tell a client how a server is configured.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NessieConfiguration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_supported_api_version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_supported_api_version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actual_api_version: Option<i32>,
+    /// Semver version representing the behavior of the Nessie server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_ancestor_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_creation_timestamp: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oldest_possible_commit_timestamp: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<HashMap<String, String>>,
+}
+
+/// Configuration object to tell a client how a server is configured.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NessieConfigurationV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_supported_api_version: Option<i32>,
+}
+
+/// Configuration object to tell a client how a server is configured.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NessieConfigurationV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_supported_api_version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_supported_api_version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actual_api_version: Option<i32>,
+    /// Semver version representing the behavior of the Nessie server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_ancestor_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_creation_timestamp: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oldest_possible_commit_timestamp: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<HashMap<String, String>>,
+}
+
+/// Describes an operation to be performed against one content object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Operation {
+    #[serde(rename = "PUT")]
+    Put(Put),
+    #[serde(rename = "UNCHANGED")]
+    Unchanged(Unchanged),
+    #[serde(rename = "DELETE")]
+    Delete(Delete),
+}
+
+/// Describes an operation to be performed against one content object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum OperationV1 {
+    #[serde(rename = "PUT")]
+    Put(PutV1),
+    #[serde(rename = "UNCHANGED")]
+    Unchanged(UnchangedV1),
+    #[serde(rename = "DELETE")]
+    Delete(DeleteV1),
+}
+
+/// Describes an operation to be performed against one content object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum OperationV2 {
+    #[serde(rename = "PUT")]
+    Put(PutV2),
+    #[serde(rename = "UNCHANGED")]
+    Unchanged(UnchangedV2),
+    #[serde(rename = "DELETE")]
+    Delete(DeleteV2),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operations {
+    pub commit_meta: CommitMeta,
+    #[serde(default)]
+    pub operations: Vec<Operation>,
+}
+
+/// Used to add new content or to update existing content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Put {
+    pub key: ContentKey,
+    pub content: Content,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<ContentMetadata>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+}
+
+/// Used to add new content or to update existing content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutV1 {
+    pub key: ContentKeyV1,
+    pub content: ContentV1,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_content: Option<ContentV1>,
+}
+
+/// Used to add new content or to update existing content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutV2 {
+    pub key: ContentKeyV2,
+    pub content: ContentV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<ContentMetadataV2>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<DocumentationV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Reference {
+    #[serde(rename = "TAG")]
+    Tag(Tag),
+    #[serde(rename = "BRANCH")]
+    Branch(Branch),
+    #[serde(rename = "DETACHED")]
+    Detached(Detached),
+}
+
+/// Cutoff policies per reference names. Supplied as a ref-name-pattern=policy
+/// tuple. Reference name patterns are regular expressions.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceCutoffPolicy {
+    /// Reference name patterns as a regular expressions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_name_pattern: Option<String>,
+    /// Policies can be one of: - number of commits as an integer value - a
+    /// duration (see java.time.Duration) - an ISO instant - 'NONE', means
+    /// everything's considered as live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+}
+
+/// Cutoff policies per reference names. Supplied as a ref-name-pattern=policy
+/// tuple. Reference name patterns are regular expressions.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceCutoffPolicyV2 {
+    /// Reference name patterns as a regular expressions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_name_pattern: Option<String>,
+    /// Policies can be one of: - number of commits as an integer value - a
+    /// duration (see java.time.Duration) - an ISO instant - 'NONE', means
+    /// everything's considered as live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+}
+
+/// Describes the consistency status of a named reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryResponse {
+    pub reference: Reference,
+    /// Consistency status of the current HEAD commit.
+    pub current: ReferenceHistoryState,
+    /// Consistency status of the recorded recent HEADs of the reference,
+    /// including re-assign operations.
+    #[serde(default)]
+    pub previous: Vec<ReferenceHistoryState>,
+    /// Combined consistency status of the commit-log of the reference, if
+    /// requested by the client.
+    pub commit_log_consistency: CommitConsistency,
+}
+
+/// Describes the consistency status of a named reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryResponseV2 {
+    pub reference: ReferenceV2,
+    /// Consistency status of the current HEAD commit.
+    pub current: ReferenceHistoryStateV2,
+    /// Consistency status of the recorded recent HEADs of the reference,
+    /// including re-assign operations.
+    #[serde(default)]
+    pub previous: Vec<ReferenceHistoryStateV2>,
+    /// Combined consistency status of the commit-log of the reference, if
+    /// requested by the client.
+    pub commit_log_consistency: CommitConsistencyV2,
+}
+
+/// Describes the consistency status of a commit within a
+/// `ReferenceHistoryResponse` object.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryState {
+    /// Nessie commit ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_hash: Option<String>,
+    /// Consistency status of the commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_consistency: Option<CommitConsistency>,
+    /// Meta information from the commit, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CommitMeta>,
+}
+
+/// Describes the consistency status of a commit within a
+/// `ReferenceHistoryResponse` object.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryStateV2 {
+    /// Nessie commit ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_hash: Option<String>,
+    /// Consistency status of the commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_consistency: Option<CommitConsistencyV2>,
+    /// Meta information from the commit, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CommitMetaV2>,
+}
+
+/// Only returned by the server when explicitly requested by the client and
+/// contains the following information:
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_ahead: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_behind: Option<i32>,
+    #[serde(
+        rename = "commitMetaOfHEAD",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub commit_meta_of_head: Option<CommitMeta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_total_commits: Option<i64>,
+}
+
+/// Only returned by the server when explicitly requested by the client and
+/// contains the following information:
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceMetadataV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_ahead: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_behind: Option<i32>,
+    #[serde(
+        rename = "commitMetaOfHEAD",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub commit_meta_of_head: Option<CommitMetaV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_total_commits: Option<i64>,
+}
+
+/// Only returned by the server when explicitly requested by the client and
+/// contains the following information:
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceMetadataV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_ahead: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_commits_behind: Option<i32>,
+    #[serde(
+        rename = "commitMetaOfHEAD",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub commit_meta_of_head: Option<CommitMetaV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_ancestor_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_total_commits: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferenceType {
+    #[serde(rename = "branch")]
+    Branch,
+    #[serde(rename = "tag")]
+    Tag,
+}
+
+impl ReferenceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReferenceType::Branch => "branch",
+            ReferenceType::Tag => "tag",
+        }
+    }
+}
+
+impl fmt::Display for ReferenceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ReferenceV1 {
+    #[serde(rename = "TAG")]
+    Tag(TagV1),
+    #[serde(rename = "BRANCH")]
+    Branch(BranchV1),
+    #[serde(rename = "DETACHED")]
+    Detached(DetachedV1),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ReferenceV2 {
+    #[serde(rename = "TAG")]
+    Tag(TagV2),
+    #[serde(rename = "BRANCH")]
+    Branch(BranchV2),
+    #[serde(rename = "DETACHED")]
+    Detached(DetachedV2),
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferencesResponseV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub references: Vec<ReferenceV1>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferencesResponseV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub references: Vec<ReferenceV2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RepositoryConfig {
+    #[serde(rename = "GARBAGE_COLLECTOR")]
+    GarbageCollector(GarbageCollectorConfig),
+    /// A kind this version of the specification does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryConfigResponse {
+    /// The existing configuration objects for the requested types will be
+    /// returned. Non-existing config objects will not be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configs: Option<Vec<RepositoryConfig>>,
+}
+
+/// The existing configuration objects for the requested types will be returned.
+/// Non-existing config objects will not be returned.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryConfigResponseV2 {
+    /// The existing configuration objects for the requested types will be
+    /// returned. Non-existing config objects will not be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configs: Option<Vec<RepositoryConfigV2>>,
+}
+
+/// Declares the type of a Nessie repository config object, which is currently
+/// only GARBAGE_COLLECTOR, which is the discriminator mapping value of the
+/// 'RepositoryConfig' type.
+pub type RepositoryConfigType = String;
+
+/// When a repository configuration for the same type as in the request object
+/// did not exist, the response object will be null. Otherwise, if the
+/// configuration was updated, the old value will be returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RepositoryConfigV2 {
+    #[serde(rename = "GARBAGE_COLLECTOR")]
+    GarbageCollector(GarbageCollectorConfigV2),
+    /// A kind this version of the specification does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleReferenceResponse {
+    pub reference: Reference,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleReferenceResponseV2 {
+    pub reference: ReferenceV2,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagV1 {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagV2 {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadataV2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transplant {
+    pub from_ref_name: String,
+    #[serde(default)]
+    pub hashes_to_transplant: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_merge_modes: Option<Vec<MergeKeyBehavior>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_key_merge_mode: Option<MergeBehavior>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_additional_info: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_conflict_as_result: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transplant1 {
+    /// Commit message for this transplant request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Lists the hashes of commits that should be transplanted into the target
+    /// branch.
+    #[serde(default)]
+    pub hashes_to_transplant: Vec<String>,
+    /// The name of the reference that contains the 'source' commits for the
+    /// requested merge or transplant operation.
+    pub from_ref_name: String,
+    /// Specific merge behaviour requests by content key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_merge_modes: Option<Vec<MergeKeyBehavior>>,
+    /// The default merge mode. If not set, `NORMAL` is assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_key_merge_mode: Option<MergeBehavior>,
+    /// When set to 'true' instructs the server to validate the request but to
+    /// avoid committing any changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// Whether to provide optional response data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_additional_info: Option<bool>,
+    /// When set to 'true' instructs the server to produce normal (non-error)
+    /// responses in case a conflict is detected and report conflict details in
+    /// the response payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_conflict_as_result: Option<bool>,
+}
+
+/// Declares the type of a Nessie content object, which is currently one of
+/// ICEBERG_TABLE, DELTA_LAKE_TABLE, ICEBERG_VIEW, NAMESPACE or UDF, which are
+/// the discriminator mapping values of the 'Content' type.
+pub type Type = String;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UDF {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UDFV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UDFV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub sql_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Unchanged {
+    pub key: ContentKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnchangedV1 {
+    pub key: ContentKeyV1,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnchangedV2 {
+    pub key: ContentKeyV2,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRepositoryConfigRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<RepositoryConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRepositoryConfigResponse {
+    /// When a repository configuration for the same type as in the request
+    /// object did not exist, the response object will be null. Otherwise, if
+    /// the configuration was updated, the old value will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<RepositoryConfig>,
+}
+
+/// When a repository configuration for the same type as in the request object
+/// did not exist, the response object will be null. Otherwise, if the
+/// configuration was updated, the old value will be returned.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRepositoryConfigResponseV2 {
+    /// When a repository configuration for the same type as in the request
+    /// object did not exist, the response object will be null. Otherwise, if
+    /// the configuration was updated, the old value will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<RepositoryConfigV2>,
+}
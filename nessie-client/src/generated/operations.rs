@@ -0,0 +1,1668 @@
+// Generated by nessie-codegen from swagger.yml (Nessie API 0.101.1).
+// Do not edit by hand: run `cargo run -p nessie-codegen` instead.
+
+use reqwest::Method;
+
+use super::models::*;
+use super::{encode_path_segment, Operation};
+use crate::client::ApiVersion;
+
+/// `GET /v1/config`
+///
+/// List all configuration settings.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetConfig;
+
+impl Operation for GetConfig {
+    type Body = ();
+    type Response = NessieConfigurationV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/config";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        "config".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v1/contents`
+///
+/// Get multiple objects' content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetMultipleContents {
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    /// Reference to use. Defaults to default branch if not provided.
+    pub r#ref: Option<String>,
+    /// Keys to retrieve.
+    pub body: GetMultipleContentsRequest,
+}
+
+impl Operation for GetMultipleContents {
+    type Body = GetMultipleContentsRequest;
+    type Response = GetMultipleContentsResponseV1;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/contents";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        "contents".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        if let Some(value) = &self.r#ref {
+            query.push(("ref", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `GET /v1/contents/{key}`
+///
+/// Get object content associated with a key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetContent {
+    /// object name to search for.
+    pub key: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    /// Reference to use. Defaults to default branch if not provided.
+    pub r#ref: Option<String>,
+}
+
+impl Operation for GetContent {
+    type Body = ();
+    type Response = ContentV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/contents/{key}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!("contents/{}", encode_path_segment(&self.key))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        if let Some(value) = &self.r#ref {
+            query.push(("ref", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/diffs/{fromRefWithHash}...{toRefWithHash}`
+///
+/// Get a diff for two given references.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetDiff {
+    /// The 'from' reference (and optional hash) to start the diff from.
+    pub from_ref_with_hash: String,
+    /// The 'to' reference (and optional hash) to end the diff at.
+    pub to_ref_with_hash: String,
+}
+
+impl Operation for GetDiff {
+    type Body = ();
+    type Response = DiffResponseV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/diffs/{fromRefWithHash}...{toRefWithHash}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "diffs/{}...{}",
+            encode_path_segment(&self.from_ref_with_hash),
+            encode_path_segment(&self.to_ref_with_hash)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/namespaces/namespace/{ref}/{name}`
+///
+/// Retrieves a Namespace.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetNamespace {
+    /// the name of the namespace.
+    pub name: String,
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+}
+
+impl Operation for GetNamespace {
+    type Body = ();
+    type Response = NamespaceV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/namespaces/namespace/{ref}/{name}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "namespaces/namespace/{}/{}",
+            encode_path_segment(&self.r#ref),
+            encode_path_segment(&self.name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `PUT /v1/namespaces/namespace/{ref}/{name}`
+///
+/// Creates a Namespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNamespace {
+    /// the name of the namespace.
+    pub name: String,
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    pub body: Namespace,
+}
+
+impl Operation for CreateNamespace {
+    type Body = Namespace;
+    type Response = NamespaceV1;
+
+    const METHOD: Method = Method::PUT;
+    const PATH: &'static str = "/v1/namespaces/namespace/{ref}/{name}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "namespaces/namespace/{}/{}",
+            encode_path_segment(&self.r#ref),
+            encode_path_segment(&self.name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v1/namespaces/namespace/{ref}/{name}`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateProperties {
+    /// the name of the namespace.
+    pub name: String,
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    /// Namespace properties to update/delete.
+    pub body: NamespaceUpdate,
+}
+
+impl Operation for UpdateProperties {
+    type Body = NamespaceUpdate;
+    type Response = ();
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/namespaces/namespace/{ref}/{name}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "namespaces/namespace/{}/{}",
+            encode_path_segment(&self.r#ref),
+            encode_path_segment(&self.name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `DELETE /v1/namespaces/namespace/{ref}/{name}`
+///
+/// Deletes a Namespace.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DeleteNamespace {
+    /// the name of the namespace.
+    pub name: String,
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+}
+
+impl Operation for DeleteNamespace {
+    type Body = ();
+    type Response = ();
+
+    const METHOD: Method = Method::DELETE;
+    const PATH: &'static str = "/v1/namespaces/namespace/{ref}/{name}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "namespaces/namespace/{}/{}",
+            encode_path_segment(&self.r#ref),
+            encode_path_segment(&self.name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/namespaces/{ref}`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetNamespaces {
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    /// the name of the namespace.
+    pub name: Option<String>,
+}
+
+impl Operation for GetNamespaces {
+    type Body = ();
+    type Response = GetNamespacesResponseV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/namespaces/{ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!("namespaces/{}", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        if let Some(value) = &self.name {
+            query.push(("name", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/trees`
+///
+/// Get all references.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetAllReferences {
+    /// Specify how much information to be returned. Will fetch additional
+    /// metadata for references if set to 'ALL'.
+    pub fetch: Option<FetchOption>,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    /// Usable variables within the expression are:
+    pub filter: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+}
+
+impl Operation for GetAllReferences {
+    type Body = ();
+    type Response = ReferencesResponseV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/trees";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        "trees".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("maxRecords", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("pageToken", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v1/trees/branch/{branchName}/commit`
+///
+/// Commit multiple operations against the given branch expecting that branch to
+/// have the given hash as its latest commit. The hash in the successful
+/// response contains the hash of the commit that contains the operations of the
+/// invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitMultipleOperations {
+    /// Branch to change, defaults to default branch.
+    pub branch_name: String,
+    /// Expected hash of branch.
+    pub expected_hash: Option<String>,
+    /// Operations.
+    pub body: Operations,
+}
+
+impl Operation for CommitMultipleOperations {
+    type Body = Operations;
+    type Response = BranchV1;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/trees/branch/{branchName}/commit";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/branch/{}/commit",
+            encode_path_segment(&self.branch_name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.expected_hash {
+            query.push(("expectedHash", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v1/trees/branch/{branchName}/merge`
+///
+/// Merge commits from 'mergeRef' onto 'branchName'.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeRefIntoBranch {
+    /// Branch to merge into.
+    pub branch_name: String,
+    /// Expected current HEAD of 'branchName'.
+    pub expected_hash: String,
+    /// Merge operation that defines the source reference name and an optional
+    /// hash. If 'fromHash' is not present, the current 'sourceRef's HEAD will
+    /// be used.
+    pub body: Merge,
+}
+
+impl Operation for MergeRefIntoBranch {
+    type Body = Merge;
+    type Response = MergeResponseV1;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/trees/branch/{branchName}/merge";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/branch/{}/merge",
+            encode_path_segment(&self.branch_name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("expectedHash", self.expected_hash.to_string())]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v1/trees/branch/{branchName}/transplant`
+///
+/// Transplant commits from 'transplant' onto 'branchName'.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransplantCommitsIntoBranch {
+    /// Branch to transplant into.
+    pub branch_name: String,
+    /// Expected hash of tag.
+    pub expected_hash: String,
+    /// commit message.
+    pub message: Option<String>,
+    /// Hashes to transplant.
+    pub body: Transplant,
+}
+
+impl Operation for TransplantCommitsIntoBranch {
+    type Body = Transplant;
+    type Response = MergeResponseV1;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/trees/branch/{branchName}/transplant";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/branch/{}/transplant",
+            encode_path_segment(&self.branch_name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("expectedHash", self.expected_hash.to_string())];
+        if let Some(value) = &self.message {
+            query.push(("message", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `GET /v1/trees/tree`
+///
+/// Get default branch for commits and reads.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetDefaultBranch;
+
+impl Operation for GetDefaultBranch {
+    type Body = ();
+    type Response = BranchV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/trees/tree";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        "trees/tree".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v1/trees/tree`
+///
+/// Create a new reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateReference {
+    /// Source named reference.
+    pub source_ref_name: Option<String>,
+    /// Reference to create.
+    pub body: Reference,
+}
+
+impl Operation for CreateReference {
+    type Body = Reference;
+    type Response = ReferenceV1;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v1/trees/tree";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        "trees/tree".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.source_ref_name {
+            query.push(("sourceRefName", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `GET /v1/trees/tree/{ref}`
+///
+/// Fetch details of a reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetReferenceByName {
+    /// name of ref to fetch.
+    pub r#ref: String,
+    /// Specify how much information to be returned. Will fetch additional
+    /// metadata for references if set to 'ALL'.
+    pub fetch: Option<FetchOption>,
+}
+
+impl Operation for GetReferenceByName {
+    type Body = ();
+    type Response = ReferenceV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/trees/tree/{ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!("trees/tree/{}", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/trees/tree/{ref}/entries`
+///
+/// Fetch all entries for a given reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetEntries {
+    /// name of ref to fetch from.
+    pub r#ref: String,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    /// Usable variables within the expression are 'entry.namespace' (string) &
+    /// 'entry.contentType' (string).
+    pub filter: Option<String>,
+    /// a particular hash on the given ref.
+    pub hash_on_ref: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// If set > 0 will filter the results to only return namespaces/tables to
+    /// the depth of namespaceDepth. If not set or <=0 has no effect Setting
+    /// this parameter > 0 will turn off paging.
+    pub namespace_depth: Option<i32>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+}
+
+impl Operation for GetEntries {
+    type Body = ();
+    type Response = EntriesResponseV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/trees/tree/{ref}/entries";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!("trees/tree/{}/entries", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.hash_on_ref {
+            query.push(("hashOnRef", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("maxRecords", value.to_string()));
+        }
+        if let Some(value) = &self.namespace_depth {
+            query.push(("namespaceDepth", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("pageToken", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v1/trees/tree/{ref}/log`
+///
+/// Get commit log for a reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetCommitLog {
+    /// ref to show log from.
+    pub r#ref: String,
+    /// Hash on the given ref to end at (in chronological sense), the 'near' end
+    /// of the commit log, returned 'early' in the result.
+    pub end_hash: Option<String>,
+    /// Specify how much information to be returned. Will fetch additional
+    /// metadata such as parent commit hash and operations in a commit, for each
+    /// commit if set to 'ALL'.
+    pub fetch: Option<FetchOption>,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    pub filter: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+    /// Hash on the given ref to start from (in chronological sense), the 'far'
+    /// end of the commit log, returned 'late' in the result.
+    pub start_hash: Option<String>,
+}
+
+impl Operation for GetCommitLog {
+    type Body = ();
+    type Response = LogResponseV1;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v1/trees/tree/{ref}/log";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!("trees/tree/{}/log", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.end_hash {
+            query.push(("endHash", value.to_string()));
+        }
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("maxRecords", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("pageToken", value.to_string()));
+        }
+        if let Some(value) = &self.start_hash {
+            query.push(("startHash", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `PUT /v1/trees/{referenceType}/{referenceName}`
+///
+/// Set a named reference to a specific hash via a named-reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignReference {
+    /// Reference name to reassign.
+    pub reference_name: String,
+    /// Reference type to reassign.
+    pub reference_type: ReferenceType,
+    /// Expected previous hash of reference.
+    pub expected_hash: String,
+    /// Reference hash to which 'referenceName' shall be assigned to. This must
+    /// be either a 'Transaction', 'Branch' or 'Tag' via which the hash is
+    /// visible to the caller.
+    pub body: Reference,
+}
+
+impl Operation for AssignReference {
+    type Body = Reference;
+    type Response = ();
+
+    const METHOD: Method = Method::PUT;
+    const PATH: &'static str = "/v1/trees/{referenceType}/{referenceName}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/{}/{}",
+            encode_path_segment(&self.reference_type),
+            encode_path_segment(&self.reference_name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("expectedHash", self.expected_hash.to_string())]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `DELETE /v1/trees/{referenceType}/{referenceName}`
+///
+/// Delete a reference endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteReference {
+    /// Reference name to delete.
+    pub reference_name: String,
+    /// Reference type to delete.
+    pub reference_type: ReferenceType,
+    /// Expected hash of tag.
+    pub expected_hash: String,
+}
+
+impl Operation for DeleteReference {
+    type Body = ();
+    type Response = ();
+
+    const METHOD: Method = Method::DELETE;
+    const PATH: &'static str = "/v1/trees/{referenceType}/{referenceName}";
+    const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/{}/{}",
+            encode_path_segment(&self.reference_type),
+            encode_path_segment(&self.reference_name)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("expectedHash", self.expected_hash.to_string())]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/config`
+///
+/// Returns repository and server settings relevant to clients.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetConfigV2;
+
+impl Operation for GetConfigV2 {
+    type Body = ();
+    type Response = NessieConfigurationV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/config";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        "config".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/config/repository`
+///
+/// Returns repository configurations of the requested types.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetRepositoryConfig {
+    pub r#type: Vec<String>,
+}
+
+impl Operation for GetRepositoryConfig {
+    type Body = ();
+    type Response = RepositoryConfigResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/config/repository";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        "config/repository".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.r#type {
+            query.push(("type", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v2/config/repository`
+///
+/// Create or update a repository configuration.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UpdateRepositoryConfig;
+
+impl Operation for UpdateRepositoryConfig {
+    type Body = ();
+    type Response = UpdateRepositoryConfigResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/config/repository";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        "config/repository".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees`
+///
+/// Get information about all branches and tags.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetAllReferencesV2 {
+    /// Specifies how much extra information is to be retrived from the server.
+    pub fetch: Option<FetchOption>,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    /// Usable variables within the expression are:
+    pub filter: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+}
+
+impl Operation for GetAllReferencesV2 {
+    type Body = ();
+    type Response = ReferencesResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        "trees".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("max-records", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("page-token", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v2/trees`
+///
+/// Create a new branch or tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateReferenceV2 {
+    /// A reference name.
+    pub name: String,
+    /// Type of the reference to be created.
+    pub r#type: String,
+    /// Source reference data from which the new reference is to be created.
+    pub body: Reference,
+}
+
+impl Operation for CreateReferenceV2 {
+    type Body = Reference;
+    type Response = SingleReferenceResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/trees";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        "trees".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.to_string()),
+            ("type", self.r#type.to_string()),
+        ]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v2/trees/{branch}/history/commit`
+///
+/// Commit one or more operations against the given 'branch'.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitV2 {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history) on a branch. This reference is specified in this form: -
+    /// name@hash - Identifies the 'hash' commit on the named branch.
+    pub branch: String,
+    /// Operations to commit.
+    pub body: Operations,
+}
+
+impl Operation for CommitV2 {
+    type Body = Operations;
+    type Response = CommitResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/trees/{branch}/history/commit";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/history/commit", encode_path_segment(&self.branch))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v2/trees/{branch}/history/merge`
+///
+/// Merge commits from another reference onto 'branch'.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeV2 {
+    /// A reference to a specific version of the contents tree (a point in
+    /// history) on a branch. This reference is specified in this form: -
+    /// name@hash - Identifies the 'hash' commit on the named branch.
+    pub branch: String,
+    /// Merge operation that defines the source reference name and an optional
+    /// hash. If 'fromHash' is not present, the current 'sourceRef's HEAD will
+    /// be used.
+    pub body: Merge1,
+}
+
+impl Operation for MergeV2 {
+    type Body = Merge1;
+    type Response = MergeResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/trees/{branch}/history/merge";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/history/merge", encode_path_segment(&self.branch))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `POST /v2/trees/{branch}/history/transplant`
+///
+/// Transplant commits specified by the 'Transplant' payload object onto the
+/// given 'branch'.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransplantV2 {
+    /// A reference to a specific version of the contents tree (a point in
+    /// history) on a branch. This reference is specified in this form: -
+    /// name@hash - Identifies the 'hash' commit on the named branch.
+    pub branch: String,
+    /// Commits to transplant.
+    pub body: Transplant1,
+}
+
+impl Operation for TransplantV2 {
+    type Body = Transplant1;
+    type Response = MergeResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/trees/{branch}/history/transplant";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/{}/history/transplant",
+            encode_path_segment(&self.branch)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `GET /v2/trees/{from-ref}/diff/{to-ref}`
+///
+/// Get contents that differ in the trees specified by the two given references.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetDiffV2 {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub from_ref: String,
+    /// Same reference spec as in the 'from-ref' parameter but identifying the
+    /// other tree for comparison.
+    pub to_ref: String,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    pub filter: Option<String>,
+    /// Restrict the result to one or more keys.
+    pub key: Vec<ContentKey>,
+    /// The upper bound of the content key range to retrieve (inclusive). The
+    /// content keys of all returned entries will be less than or equal to the
+    /// max-value. Content-keys are compared as a 'whole', unlike prefix-keys.
+    pub max_key: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// The lower bound of the content key range to retrieve (inclusive). The
+    /// content keys of all returned entries will be greater than or equal to
+    /// the min-value. Content-keys are compared as a 'whole', unlike
+    /// prefix-keys.
+    pub min_key: Option<String>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+    /// The content key prefix to retrieve (inclusive). A content key matches a
+    /// given prefix, a content key's elements starts with all elements of the
+    /// prefix-key. Key prefixes exactly match key-element boundaries.
+    pub prefix_key: Option<String>,
+}
+
+impl Operation for GetDiffV2 {
+    type Body = ();
+    type Response = DiffResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{from-ref}/diff/{to-ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/{}/diff/{}",
+            encode_path_segment(&self.from_ref),
+            encode_path_segment(&self.to_ref)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        for value in &self.key {
+            query.push(("key", value.to_string()));
+        }
+        if let Some(value) = &self.max_key {
+            query.push(("max-key", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("max-records", value.to_string()));
+        }
+        if let Some(value) = &self.min_key {
+            query.push(("min-key", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("page-token", value.to_string()));
+        }
+        if let Some(value) = &self.prefix_key {
+            query.push(("prefix-key", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees/{ref}`
+///
+/// Fetch details of a reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetReferenceByNameV2 {
+    /// Specifies a reference to a particular commit history branch or tag.
+    pub r#ref: String,
+    /// Specify how much information to be returned. Will fetch additional
+    /// metadata for references if set to 'ALL'.
+    pub fetch: Option<FetchOption>,
+}
+
+impl Operation for GetReferenceByNameV2 {
+    type Body = ();
+    type Response = SingleReferenceResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `PUT /v2/trees/{ref}`
+///
+/// Set a named reference to a specific hash via another reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignReferenceV2 {
+    /// Specifies a named branch or tag reference with its expected HEAD 'hash'
+    /// value.
+    pub r#ref: String,
+    /// Optional expected type of the reference being reassigned.
+    pub r#type: Option<String>,
+    /// Reference to which the 'ref' (from the path parameter) shall be
+    /// assigned. This must be either a 'Detached' commit, 'Branch' or 'Tag' via
+    /// which the hash is visible to the caller.
+    pub body: Reference,
+}
+
+impl Operation for AssignReferenceV2 {
+    type Body = Reference;
+    type Response = SingleReferenceResponseV2;
+
+    const METHOD: Method = Method::PUT;
+    const PATH: &'static str = "/v2/trees/{ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.r#type {
+            query.push(("type", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `DELETE /v2/trees/{ref}`
+///
+/// Delete a reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DeleteReferenceV2 {
+    /// Specifies a named branch or tag reference with its expected HEAD 'hash'
+    /// value.
+    pub r#ref: String,
+    /// Optional expected type of the reference being deleted.
+    pub r#type: Option<String>,
+}
+
+impl Operation for DeleteReferenceV2 {
+    type Body = ();
+    type Response = SingleReferenceResponseV2;
+
+    const METHOD: Method = Method::DELETE;
+    const PATH: &'static str = "/v2/trees/{ref}";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.r#type {
+            query.push(("type", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees/{ref}/contents`
+///
+/// Get multiple content objects.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetSeveralContents {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub r#ref: String,
+    /// If set to 'true', access control checks will check for write/create
+    /// privilege in addition to read privileges.
+    pub for_write: Option<bool>,
+    /// The key to a content object.
+    pub key: Vec<String>,
+    /// Whether to return the documentation, if it exists. Default is to not
+    /// return the documentation.
+    pub with_doc: Option<bool>,
+}
+
+impl Operation for GetSeveralContents {
+    type Body = ();
+    type Response = GetMultipleContentsResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}/contents";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/contents", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.for_write {
+            query.push(("for-write", value.to_string()));
+        }
+        for value in &self.key {
+            query.push(("key", value.to_string()));
+        }
+        if let Some(value) = &self.with_doc {
+            query.push(("with-doc", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `POST /v2/trees/{ref}/contents`
+///
+/// Get multiple content objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetMultipleContentsV2 {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub r#ref: String,
+    /// If set to 'true', access control checks will check for write/create
+    /// privilege in addition to read privileges.
+    pub for_write: Option<bool>,
+    /// Whether to return the documentation, if it exists. Default is to not
+    /// return the documentation.
+    pub with_doc: Option<bool>,
+    /// Keys to retrieve.
+    pub body: GetMultipleContentsRequest,
+}
+
+impl Operation for GetMultipleContentsV2 {
+    type Body = GetMultipleContentsRequest;
+    type Response = GetMultipleContentsResponseV2;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/v2/trees/{ref}/contents";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/contents", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.for_write {
+            query.push(("for-write", value.to_string()));
+        }
+        if let Some(value) = &self.with_doc {
+            query.push(("with-doc", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+/// `GET /v2/trees/{ref}/contents/{key}`
+///
+/// Get the content object associated with a key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetContentV2 {
+    /// The key to a content object.
+    pub key: String,
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub r#ref: String,
+    /// If set to 'true', access control checks will check for write/create
+    /// privilege in addition to read privileges.
+    pub for_write: Option<bool>,
+    /// Whether to return the documentation, if it exists. Default is to not
+    /// return the documentation.
+    pub with_doc: Option<bool>,
+}
+
+impl Operation for GetContentV2 {
+    type Body = ();
+    type Response = ContentResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}/contents/{key}";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!(
+            "trees/{}/contents/{}",
+            encode_path_segment(&self.r#ref),
+            encode_path_segment(&self.key)
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.for_write {
+            query.push(("for-write", value.to_string()));
+        }
+        if let Some(value) = &self.with_doc {
+            query.push(("with-doc", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees/{ref}/entries`
+///
+/// Fetch all entries for a given reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetEntriesV2 {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub r#ref: String,
+    /// Optionally request to return 'Content' objects for the returned keys.
+    pub content: Option<bool>,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    /// Usable variables within the expression are 'entry.namespace' (string) &
+    /// 'entry.contentType' (string).
+    pub filter: Option<String>,
+    /// Restrict the result to one or more keys.
+    pub key: Vec<ContentKey>,
+    /// The upper bound of the content key range to retrieve (inclusive). The
+    /// content keys of all returned entries will be less than or equal to the
+    /// max-value. Content-keys are compared as a 'whole', unlike prefix-keys.
+    pub max_key: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// The lower bound of the content key range to retrieve (inclusive). The
+    /// content keys of all returned entries will be greater than or equal to
+    /// the min-value. Content-keys are compared as a 'whole', unlike
+    /// prefix-keys.
+    pub min_key: Option<String>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+    /// The content key prefix to retrieve (inclusive). A content key matches a
+    /// given prefix, a content key's elements starts with all elements of the
+    /// prefix-key. Key prefixes exactly match key-element boundaries.
+    pub prefix_key: Option<String>,
+}
+
+impl Operation for GetEntriesV2 {
+    type Body = ();
+    type Response = EntriesResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}/entries";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/entries", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.content {
+            query.push(("content", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        for value in &self.key {
+            query.push(("key", value.to_string()));
+        }
+        if let Some(value) = &self.max_key {
+            query.push(("max-key", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("max-records", value.to_string()));
+        }
+        if let Some(value) = &self.min_key {
+            query.push(("min-key", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("page-token", value.to_string()));
+        }
+        if let Some(value) = &self.prefix_key {
+            query.push(("prefix-key", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees/{ref}/history`
+///
+/// Get commit log for a particular reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetCommitLogV2 {
+    /// A reference to a particular version of the contents tree (a point in
+    /// history).
+    pub r#ref: String,
+    /// Specify how much information to be returned. Will fetch additional
+    /// metadata such as parent commit hash and operations in a commit, for each
+    /// commit if set to 'ALL'.
+    pub fetch: Option<FetchOption>,
+    /// A Common Expression Language (CEL) expression. An intro to CEL can be
+    /// found at https://github.com/google/cel-spec/blob/master/doc/intro.md.
+    pub filter: Option<String>,
+    /// Hash on the given ref to identify the commit where the operation of
+    /// fetching the log should stop, i.e. the 'far' end of the commit log,
+    /// returned late in the result.
+    pub limit_hash: Option<String>,
+    /// maximum number of entries to return, just a hint for the server.
+    pub max_records: Option<i32>,
+    /// paging continuation token, as returned in the previous value of the
+    /// field 'token' in the corresponding 'EntriesResponse' or 'LogResponse' or
+    /// 'ReferencesResponse' or 'RefLogResponse'.
+    pub page_token: Option<String>,
+}
+
+impl Operation for GetCommitLogV2 {
+    type Body = ();
+    type Response = LogResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}/history";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/history", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.fetch {
+            query.push(("fetch", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.limit_hash {
+            query.push(("limit-hash", value.to_string()));
+        }
+        if let Some(value) = &self.max_records {
+            query.push(("max-records", value.to_string()));
+        }
+        if let Some(value) = &self.page_token {
+            query.push(("page-token", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+/// `GET /v2/trees/{ref}/recent-changes`
+///
+/// Fetch recent pointer changes of a reference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GetReferenceHistory {
+    /// Specifies a reference to a particular commit history branch or tag.
+    pub r#ref: String,
+    /// Optional parameter, specifies the number of commits to scan from the
+    /// reference's current HEAD, limited to the given amount of commits.
+    /// Default is to not scan the commit log. The server may impose a hard
+    /// limit on the amount of commits from the commit log.
+    pub scan_commits: Option<i32>,
+}
+
+impl Operation for GetReferenceHistory {
+    type Body = ();
+    type Response = ReferenceHistoryResponseV2;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/v2/trees/{ref}/recent-changes";
+    const API_VERSION: ApiVersion = ApiVersion::V2;
+
+    fn path(&self) -> String {
+        format!("trees/{}/recent-changes", encode_path_segment(&self.r#ref))
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.scan_commits {
+            query.push(("scan-commits", value.to_string()));
+        }
+        query
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
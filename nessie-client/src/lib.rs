@@ -1,3 +1,12 @@
+pub mod auth;
+pub mod blocking;
+pub mod builder;
 pub mod client;
+pub mod content_key;
 pub mod error;
+pub mod generated;
 pub mod models;
+pub mod ref_spec;
+#[cfg(feature = "test-server")]
+pub mod test_server;
+mod v1;
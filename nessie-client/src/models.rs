@@ -1,12 +1,117 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::generated::models as generated;
+
+// Types of the specification that need no wrapping.
+pub use crate::content_key::ContentKey;
+pub use crate::generated::models::{CommitConsistency, Content, FetchOption, IcebergTable};
+
+/// Server settings relevant to clients, returned by `GET /config`.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NessieConfiguration {
+    pub default_branch: Option<String>,
+    pub min_supported_api_version: Option<i32>,
+    pub max_supported_api_version: Option<i32>,
+    /// The API version of the endpoint that served the request.
+    pub actual_api_version: Option<i32>,
+    /// Semver of the server behavior, e.g. `2.0.0`.
+    pub spec_version: Option<String>,
+    /// Hash of the empty "beginning of time" commit.
+    pub no_ancestor_hash: Option<String>,
+    pub repository_creation_timestamp: Option<String>,
+    pub oldest_possible_commit_timestamp: Option<String>,
+    #[serde(default)]
+    pub additional_properties: HashMap<String, String>,
+}
+
+/// Kinds of repository-wide configuration objects.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RepositoryConfigType {
+    GarbageCollector,
+}
+
+impl std::fmt::Display for RepositoryConfigType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryConfigType::GarbageCollector => write!(f, "GARBAGE_COLLECTOR"),
+        }
+    }
+}
+
+/// A repository-wide configuration object, discriminated by its `type`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RepositoryConfig {
+    GarbageCollector(GarbageCollectorConfig),
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings of the Nessie garbage collector.
+///
+/// Cutoff policies are a number of commits, an ISO-8601 duration, an ISO
+/// instant or `NONE` (everything is live).
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageCollectorConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_cutoff_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub per_ref_cutoff_policies: Vec<ReferenceCutoffPolicy>,
+    /// ISO-8601 duration, e.g. `P1D`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_files_grace_period: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_file_count_per_content: Option<i32>,
+}
+
+/// Cutoff policy for the references whose name matches a regular expression.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceCutoffPolicy {
+    pub reference_name_pattern: String,
+    pub policy: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RepositoryConfigResponse {
+    #[serde(default)]
+    pub configs: Vec<RepositoryConfig>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateRepositoryConfigRequest {
+    pub config: RepositoryConfig,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRepositoryConfigResponse {
+    /// `None` if no configuration of that type existed before.
+    pub previous: Option<RepositoryConfig>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ReferenceType {
     Branch,
     Tag,
+    Detached,
+}
+
+impl std::fmt::Display for ReferenceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReferenceType::Branch => "BRANCH",
+            ReferenceType::Tag => "TAG",
+            ReferenceType::Detached => "DETACHED",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -15,6 +120,64 @@ pub struct Reference {
     #[serde(rename = "type")]
     pub ref_type: ReferenceType,
     pub hash: Option<String>,
+    /// Only returned when requested with [`FetchOption::All`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ReferenceMetadata>,
+}
+
+/// The reference as a request payload; metadata is never sent.
+impl From<&Reference> for generated::Reference {
+    fn from(reference: &Reference) -> Self {
+        let name = reference.name.clone();
+        let hash = reference.hash.clone();
+        match reference.ref_type {
+            ReferenceType::Branch => generated::Reference::Branch(generated::Branch {
+                name,
+                hash,
+                metadata: None,
+            }),
+            ReferenceType::Tag => generated::Reference::Tag(generated::Tag {
+                name,
+                hash,
+                metadata: None,
+            }),
+            ReferenceType::Detached => generated::Reference::Detached(generated::Detached {
+                hash: hash.unwrap_or_default(),
+                metadata: None,
+            }),
+        }
+    }
+}
+
+/// Extra information about a reference, relative to the default branch.
+///
+/// Tags only carry `commit_meta_of_head` and `num_total_commits`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceMetadata {
+    pub num_commits_ahead: Option<i32>,
+    pub num_commits_behind: Option<i32>,
+    #[serde(rename = "commitMetaOfHEAD")]
+    pub commit_meta_of_head: Option<CommitMeta>,
+    pub common_ancestor_hash: Option<String>,
+    pub num_total_commits: Option<i64>,
+}
+
+/// Query parameters of the reference listing endpoint.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListReferencesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<FetchOption>,
+    /// A CEL expression over `ref`, `refType`, `metadata` and `commit`, e.g.
+    /// `refType == 'BRANCH' && commit.author == 'etl'`. `metadata` and `commit`
+    /// are only populated with [`FetchOption::All`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_records: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +185,7 @@ pub struct Reference {
 pub struct ReferencesResponse {
     pub token: Option<String>,
     pub references: Vec<Reference>,
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -30,63 +194,275 @@ pub struct ReferenceResponse {
     pub reference: Reference,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "type", rename_all = "UPPERCASE")]
-pub enum ContentEntry {
-    IcebergTable {
-        id: Option<String>,
-        metadata_location: String,
-        name: String,
-        schema_id: Option<i32>,
-        spec_id: Option<i32>,
-        snapshot_id: Option<i64>,
-        sort_order_id: Option<i32>,
-    },
-    DeltaLakeTable {
-        id: String,
-        metadata_location_history: Vec<String>,
-        checkpoint_location_history: Vec<String>,
-        last_checkpoint: String,
-    },
-    View {
-        name: String,
-    },
-    Namespace {
-        name: String,
-    },
-    Unknown,
+/// A past or present HEAD of a reference.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryState {
+    // Older servers name this field `pointer`.
+    #[serde(alias = "pointer")]
+    pub commit_hash: String,
+    pub commit_consistency: CommitConsistency,
+    pub meta: Option<CommitMeta>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct EntriesResponse {
-    pub entries: Vec<Entry>,
+/// The recorded recent HEADs of a reference, including re-assignments.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceHistoryResponse {
+    pub reference: Reference,
+    pub current: ReferenceHistoryState,
+    /// Most recent first.
+    pub previous: Vec<ReferenceHistoryState>,
+    /// Combined status of the scanned commit log, `NOT_CHECKED` unless
+    /// commits were scanned.
+    pub commit_log_consistency: CommitConsistency,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct EntryResponse {
-    pub entry: Entry,
+/// Conditions and paging of an entries listing.
+///
+/// The key conditions are sent as query parameters. `content_types` and the
+/// depth bounds are turned into a CEL expression, combined with `filter`.
+#[derive(Debug, Clone, Default)]
+pub struct EntriesParams {
+    /// Only these keys.
+    pub keys: Vec<ContentKey>,
+    /// Only keys greater than or equal to this one.
+    pub min_key: Option<ContentKey>,
+    /// Only keys less than or equal to this one.
+    pub max_key: Option<ContentKey>,
+    /// Only this key and the keys below it.
+    pub prefix_key: Option<ContentKey>,
+    /// Only entries of these content types, e.g. `ICEBERG_TABLE`.
+    pub content_types: Vec<String>,
+    /// Only keys with at least this many elements.
+    pub min_depth: Option<usize>,
+    /// Only keys with at most this many elements.
+    pub max_depth: Option<usize>,
+    /// A CEL expression over `entry.namespace`, `entry.contentType` and
+    /// `entry.keyElements`.
+    pub filter: Option<String>,
+    /// Also return the content of each entry.
+    pub with_content: bool,
+    pub max_records: Option<i32>,
+    pub page_token: Option<String>,
+}
+
+impl EntriesParams {
+    /// The entries directly below `parent`, or at the root if `None`.
+    pub fn children_of(parent: Option<&ContentKey>) -> Self {
+        let depth = parent.map_or(0, |parent| parent.elements.len()) + 1;
+        Self {
+            prefix_key: parent.cloned(),
+            min_depth: Some(depth),
+            max_depth: Some(depth),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the listing to entries of `content_type`.
+    pub fn of_type(mut self, content_type: &str) -> Self {
+        self.content_types.push(content_type.to_string());
+        self
+    }
+
+    /// The CEL expression sent as `filter`, if any condition needs one.
+    pub fn cel_filter(&self) -> Option<String> {
+        let mut conditions = Vec::new();
+        if let Some(filter) = &self.filter {
+            conditions.push(format!("({})", filter));
+        }
+        match self.content_types.as_slice() {
+            [] => {}
+            [content_type] => {
+                conditions.push(format!("entry.contentType == {}", cel_string(content_type)))
+            }
+            content_types => conditions.push(format!(
+                "entry.contentType in [{}]",
+                content_types
+                    .iter()
+                    .map(|content_type| cel_string(content_type))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+        match (self.min_depth, self.max_depth) {
+            (Some(min), Some(max)) if min == max => {
+                conditions.push(format!("size(entry.keyElements) == {}", min))
+            }
+            (min, max) => {
+                if let Some(min) = min {
+                    conditions.push(format!("size(entry.keyElements) >= {}", min));
+                }
+                if let Some(max) = max {
+                    conditions.push(format!("size(entry.keyElements) <= {}", max));
+                }
+            }
+        }
+        (!conditions.is_empty()).then(|| conditions.join(" && "))
+    }
+}
+
+/// `value` as a single-quoted CEL string literal.
+fn cel_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('\'');
+    for c in value.chars() {
+        if c == '\\' || c == '\'' {
+            literal.push('\\');
+        }
+        literal.push(c);
+    }
+    literal.push('\'');
+    literal
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntriesResponse {
+    pub entries: Vec<Entry>,
+    #[serde(default)]
+    pub has_more: bool,
+    /// Token of the next page, if any.
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Entry {
     pub name: ContentKey,
     #[serde(rename = "type")]
     pub content_type: String, // e.g., "ICEBERG_TABLE"
+    pub content_id: Option<String>,
+    /// Only returned when requested with [`EntriesParams::with_content`].
+    pub content: Option<Content>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct CommitResponse {}
+/// Outcome of a commit.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitResponse {
+    /// The committed branch, at the hash of the new commit.
+    pub target_branch: Reference,
+    /// Content created by `Put` operations of the commit.
+    #[serde(default)]
+    pub added_contents: Vec<AddedContent>,
+}
 
-#[derive(Debug, Serialize, Deserialize)]
+impl CommitResponse {
+    /// The id Nessie assigned to the content created at `key`, if the
+    /// commit created one.
+    pub fn added_content_id(&self, key: &ContentKey) -> Option<&str> {
+        self.added_contents
+            .iter()
+            .find(|added| &added.key == key)
+            .map(|added| added.content_id.as_str())
+    }
+}
+
+/// Content created by a commit. Servers send `contentId` although the
+/// specification leaves it out of `AddedContent`.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+pub struct AddedContent {
+    pub key: ContentKey,
+    pub content_id: String,
+}
+
+/// Metadata of a commit.
+///
+/// Responses of servers that only fill the single-valued `author`,
+/// `signedOffBy` and `properties` fields are read into their multi-valued
+/// counterparts.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase", from = "generated::CommitMeta")]
 pub struct CommitMeta {
-    pub author: String,
-    pub author_time: String, // Consider `chrono::DateTime<Utc>` for strict typing
-    pub message: String,
+    /// Set by the server.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signed_off_by: Option<String>,
-    #[serde(default)]
-    pub properties: HashMap<String, String>,
+    pub hash: Option<String>,
+    /// Set by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<String>,
+    /// Set by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_time: Option<DateTime<Utc>>,
+    /// `Name <email>` of each author, the committer if empty.
+    pub authors: Vec<String>,
+    /// The commit time if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_time: Option<DateTime<Utc>>,
+    pub message: String,
+    pub all_signed_off_by: Vec<String>,
+    pub all_properties: HashMap<String, Vec<String>>,
+    /// Set by the server: the parent first, then the merged commits.
+    pub parent_commit_hashes: Vec<String>,
+}
+
+impl CommitMeta {
+    /// The first author, if any.
+    pub fn author(&self) -> Option<&str> {
+        self.authors.first().map(String::as_str)
+    }
+
+    /// The first value of a property, if any.
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.all_properties
+            .get(name)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
+}
+
+impl From<generated::CommitMeta> for CommitMeta {
+    fn from(meta: generated::CommitMeta) -> Self {
+        let authors = if meta.authors.is_empty() {
+            meta.author.into_iter().collect()
+        } else {
+            meta.authors
+        };
+        let all_signed_off_by = if meta.all_signed_off_by.is_empty() {
+            meta.signed_off_by.into_iter().collect()
+        } else {
+            meta.all_signed_off_by
+        };
+        let all_properties = if meta.all_properties.is_empty() {
+            meta.properties
+                .into_iter()
+                .map(|(name, value)| (name, vec![value]))
+                .collect()
+        } else {
+            meta.all_properties
+        };
+        Self {
+            hash: meta.hash,
+            committer: meta.committer,
+            commit_time: meta.commit_time,
+            authors,
+            author_time: meta.author_time,
+            message: meta.message,
+            all_signed_off_by,
+            all_properties,
+            parent_commit_hashes: meta.parent_commit_hashes,
+        }
+    }
+}
+
+/// The metadata in the shape of API v1, which only knows one author, one
+/// sign-off and single-valued properties.
+impl From<&CommitMeta> for generated::CommitMetaV1 {
+    fn from(meta: &CommitMeta) -> Self {
+        Self {
+            author: meta.author().map(str::to_string),
+            author_time: meta.author_time,
+            message: meta.message.clone(),
+            signed_off_by: meta.all_signed_off_by.first().cloned(),
+            properties: meta
+                .all_properties
+                .iter()
+                .filter_map(|(name, values)| Some((name.clone(), values.first()?.clone())))
+                .collect(),
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -102,30 +478,3 @@ pub enum Operation {
     Put { key: ContentKey, content: Content },
     Delete { key: ContentKey },
 }
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ContentKey {
-    pub elements: Vec<String>, // ["db", "table"]
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Content {
-    #[serde(rename = "type")]
-    pub content_type: String, // e.g. "ICEBERG_TABLE"
-    pub metadata_location: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_id: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub spec_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order_id: Option<i32>,
-}
-
-impl std::fmt::Display for ContentKey {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.elements.join("."))
-    }
-}
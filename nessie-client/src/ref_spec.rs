@@ -0,0 +1,332 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+
+use crate::error::NessieError;
+use crate::models::{Reference, ReferenceType};
+
+/// Placeholder Nessie accepts in place of the default branch name.
+pub const DEFAULT_BRANCH: &str = "-";
+
+/// A commit relative to the one identified by the name and hash of a [`RefSpec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelativeCommitSpec {
+    /// `~N`: the N-th predecessor.
+    Ancestor(u32),
+    /// `^N`: the N-th parent, `^2` being the merge parent.
+    Parent(u32),
+    /// `*T`: the commit that was current at the given instant.
+    Timestamp(DateTime<Utc>),
+}
+
+impl fmt::Display for RelativeCommitSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelativeCommitSpec::Ancestor(n) => write!(f, "~{}", n),
+            RelativeCommitSpec::Parent(n) => write!(f, "^{}", n),
+            RelativeCommitSpec::Timestamp(timestamp) => {
+                // Nessie requires between 1 and 9 fractional digits.
+                let nanos = timestamp.timestamp_subsec_nanos();
+                let precision = if nanos % 1_000_000 == 0 {
+                    SecondsFormat::Millis
+                } else if nanos % 1_000 == 0 {
+                    SecondsFormat::Micros
+                } else {
+                    SecondsFormat::Nanos
+                };
+                write!(f, "*{}", timestamp.to_rfc3339_opts(precision, true))
+            }
+        }
+    }
+}
+
+/// Identifies a commit the way Nessie reference path parameters do.
+///
+/// The textual form is `[name][@hash][relative...]`:
+///
+/// - `main`: the head of `main`;
+/// - `main@2e1cfa82`: commit `2e1cfa82`, expected to be on `main`;
+/// - `@2e1cfa82`: the detached commit `2e1cfa82`;
+/// - `-`: the head of the default branch;
+/// - `main~3`, `main^2`, `main*2025-01-06T10:00:00.000Z`: relative commits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefSpec {
+    name: Option<String>,
+    hash: Option<String>,
+    relative: Vec<RelativeCommitSpec>,
+}
+
+impl RefSpec {
+    /// The head of the named branch or tag.
+    pub fn named(name: &str) -> Result<Self, NessieError> {
+        validate_name(name)?;
+        Ok(Self {
+            name: Some(name.to_string()),
+            hash: None,
+            relative: Vec::new(),
+        })
+    }
+
+    /// The given commit of the named branch or tag.
+    pub fn at(name: &str, hash: &str) -> Result<Self, NessieError> {
+        validate_name(name)?;
+        validate_hash(hash)?;
+        Ok(Self {
+            name: Some(name.to_string()),
+            hash: Some(hash.to_string()),
+            relative: Vec::new(),
+        })
+    }
+
+    /// A commit that is not looked up through any branch or tag.
+    pub fn detached(hash: &str) -> Result<Self, NessieError> {
+        validate_hash(hash)?;
+        Ok(Self {
+            name: None,
+            hash: Some(hash.to_string()),
+            relative: Vec::new(),
+        })
+    }
+
+    /// The head of the server's default branch.
+    pub fn default_branch() -> Self {
+        Self {
+            name: Some(DEFAULT_BRANCH.to_string()),
+            hash: None,
+            relative: Vec::new(),
+        }
+    }
+
+    pub fn ancestor(mut self, n: u32) -> Self {
+        self.relative.push(RelativeCommitSpec::Ancestor(n));
+        self
+    }
+
+    pub fn parent(mut self, n: u32) -> Self {
+        self.relative.push(RelativeCommitSpec::Parent(n));
+        self
+    }
+
+    pub fn as_of(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.relative.push(RelativeCommitSpec::Timestamp(timestamp));
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    pub fn relative(&self) -> &[RelativeCommitSpec] {
+        &self.relative
+    }
+
+    pub fn is_detached(&self) -> bool {
+        self.name.is_none()
+    }
+
+    /// Returns the hash, or an error naming `operation` if there is none.
+    ///
+    /// Operations that modify a reference need the head the caller expects it
+    /// to have.
+    pub fn require_hash(&self, operation: &str) -> Result<&str, NessieError> {
+        self.hash.as_deref().ok_or_else(|| {
+            NessieError::InvalidReference(format!(
+                "{} requires an expected hash, got '{}'",
+                operation, self
+            ))
+        })
+    }
+
+    /// The commit as the source or target reference of `operation`, which
+    /// needs its hash. Nessie only reads the name and hash of that reference,
+    /// so named specs are sent as branches and the others as detached commits.
+    pub(crate) fn to_reference(&self, operation: &str) -> Result<Reference, NessieError> {
+        let hash = self.require_hash(operation)?;
+        if !self.relative.is_empty() {
+            return Err(NessieError::InvalidReference(format!(
+                "{} requires a commit without relative parts, got '{}'",
+                operation, self
+            )));
+        }
+        Ok(Reference {
+            name: self.name.clone().unwrap_or_default(),
+            ref_type: if self.name.is_some() {
+                ReferenceType::Branch
+            } else {
+                ReferenceType::Detached
+            },
+            hash: Some(hash.to_string()),
+            metadata: None,
+        })
+    }
+
+    /// Returns the name, or an error naming `operation` if the spec is detached.
+    pub fn require_name(&self, operation: &str) -> Result<&str, NessieError> {
+        self.name.as_deref().ok_or_else(|| {
+            NessieError::InvalidReference(format!(
+                "{} requires a branch or tag name, got '{}'",
+                operation, self
+            ))
+        })
+    }
+}
+
+impl fmt::Display for RefSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{}", name)?;
+        }
+        if let Some(hash) = &self.hash {
+            write!(f, "@{}", hash)?;
+        }
+        for relative in &self.relative {
+            write!(f, "{}", relative)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RefSpec {
+    type Err = NessieError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            NessieError::InvalidReference(format!("'{}' is not a valid reference: {}", s, reason))
+        };
+        let is_relative = |c: char| matches!(c, '~' | '^' | '*');
+
+        let name_end = s
+            .find(|c: char| c == '@' || is_relative(c))
+            .unwrap_or(s.len());
+        let name = &s[..name_end];
+        let mut rest = &s[name_end..];
+
+        let name = if name.is_empty() {
+            None
+        } else {
+            validate_name(name)?;
+            Some(name.to_string())
+        };
+
+        let mut hash = None;
+        if let Some(after_at) = rest.strip_prefix('@') {
+            let hash_end = after_at.find(is_relative).unwrap_or(after_at.len());
+            if hash_end == 0 {
+                return Err(invalid("'@' must be followed by a hash"));
+            }
+            validate_hash(&after_at[..hash_end])?;
+            hash = Some(after_at[..hash_end].to_string());
+            rest = &after_at[hash_end..];
+        }
+
+        if name.is_none() && hash.is_none() {
+            return Err(invalid("a name or a hash is required"));
+        }
+
+        let mut relative = Vec::new();
+        while let Some(op) = rest.chars().next() {
+            let value_end = rest[1..]
+                .find(is_relative)
+                .map(|i| i + 1)
+                .unwrap_or(rest.len());
+            let value = &rest[1..value_end];
+            rest = &rest[value_end..];
+
+            let spec = match op {
+                '~' => RelativeCommitSpec::Ancestor(
+                    value.parse().map_err(|_| invalid("'~' expects a number"))?,
+                ),
+                '^' => RelativeCommitSpec::Parent(
+                    value.parse().map_err(|_| invalid("'^' expects a number"))?,
+                ),
+                '*' => RelativeCommitSpec::Timestamp(parse_timestamp(value).ok_or_else(|| {
+                    invalid("'*' expects an ISO-8601 instant or milliseconds since epoch")
+                })?),
+                _ => return Err(invalid("unexpected character")),
+            };
+            relative.push(spec);
+        }
+
+        Ok(Self {
+            name,
+            hash,
+            relative,
+        })
+    }
+}
+
+impl From<&Reference> for RefSpec {
+    fn from(reference: &Reference) -> Self {
+        Self {
+            name: Some(reference.name.clone()),
+            hash: reference.hash.clone(),
+            relative: Vec::new(),
+        }
+    }
+}
+
+impl From<Reference> for RefSpec {
+    fn from(reference: Reference) -> Self {
+        Self::from(&reference)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        let millis = value.parse().ok()?;
+        return Utc.timestamp_millis_opt(millis).single();
+    }
+    if !value.ends_with('Z') || !value.contains('.') {
+        return None;
+    }
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+/// Branch and tag names start with a letter, end with a letter, digit, `_`
+/// or `-`, may contain `.` and `/` in between but never `..`.
+pub fn validate_name(name: &str) -> Result<(), NessieError> {
+    if name == DEFAULT_BRANCH {
+        return Ok(());
+    }
+
+    let bytes = name.as_bytes();
+    let valid = match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => {
+            first.is_ascii_alphabetic()
+                && (last.is_ascii_alphanumeric() || matches!(last, b'_' | b'-'))
+                && bytes
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'/' | b'_' | b'-'))
+                && !name.contains("..")
+        }
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(NessieError::InvalidReference(format!(
+            "'{}' is not a valid branch or tag name",
+            name
+        )))
+    }
+}
+
+/// Commit hashes are 8 to 64 hexadecimal characters.
+pub fn validate_hash(hash: &str) -> Result<(), NessieError> {
+    if (8..=64).contains(&hash.len()) && hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(NessieError::InvalidReference(format!(
+            "'{}' is not a valid commit hash",
+            hash
+        )))
+    }
+}
@@ -0,0 +1,281 @@
+//! In-memory Nessie server speaking the v2 REST API, for tests that cannot
+//! run the Nessie container.
+//!
+//! It keeps branches, tags, commits and contents in memory and implements
+//! references, entries, contents, commit log, commits, merges, diffs and the
+//! conflicts Nessie reports for them. Authentication and repository
+//! configuration are not supported, and of CEL filters only the entries
+//! conditions of [`crate::models::EntriesParams`] are.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), nessie_client::error::NessieError> {
+//! use nessie_client::client::NessieClient;
+//! use nessie_client::test_server::NessieTestServer;
+//!
+//! let server = NessieTestServer::start().await?;
+//! let client = NessieClient::new(&server.base_url())?;
+//! assert_eq!(client.default_branch().await?, "main");
+//! # Ok(())
+//! # }
+//! ```
+
+mod http;
+mod repository;
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::error::NessieError;
+use http::{percent_decode, read_request, write_response, Request, Response};
+use repository::{EntriesFilter, Repository, ServerError};
+
+pub use repository::NO_ANCESTOR;
+
+/// A running in-memory Nessie server, stopped when dropped.
+#[derive(Debug)]
+pub struct NessieTestServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl NessieTestServer {
+    /// Starts a server on a random local port with an empty `main` branch.
+    pub async fn start() -> Result<Self, NessieError> {
+        Self::start_with_default_branch("main").await
+    }
+
+    pub async fn start_with_default_branch(default_branch: &str) -> Result<Self, NessieError> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| NessieError::InvalidConfiguration(format!("cannot bind: {}", e)))?;
+        let address = listener
+            .local_addr()
+            .map_err(|e| NessieError::InvalidConfiguration(e.to_string()))?;
+        let repository = Arc::new(Mutex::new(Repository::new(default_branch)));
+
+        let task = tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    let Ok(request) = read_request(&mut socket).await else {
+                        return;
+                    };
+                    let response = handle(&mut repository.lock().unwrap(), &request);
+                    let _ = write_response(&mut socket, &response).await;
+                });
+            }
+        });
+
+        Ok(Self { address, task })
+    }
+
+    /// `http://127.0.0.1:<port>`
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// URL of the v2 API, to create a [`crate::client::NessieClient`] with.
+    pub fn base_url(&self) -> String {
+        format!("{}/api/v2/", self.uri())
+    }
+}
+
+impl Drop for NessieTestServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn handle(repository: &mut Repository, request: &Request) -> Response {
+    route(repository, request).unwrap_or_else(ServerError::into_response)
+}
+
+fn route(repository: &mut Repository, request: &Request) -> Result<Response, ServerError> {
+    let segments: Vec<String> = request
+        .segments
+        .iter()
+        .map(|segment| percent_decode(segment))
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    let method = request.method.as_str();
+
+    let body = match segments.as_slice() {
+        ["api", "v2", "config"] if method == "GET" => repository.config(),
+        ["api", "v2", "trees"] => match method {
+            "GET" => {
+                repository.list_references(max_records(request)?, request.param("page-token"))?
+            }
+            "POST" => repository.create_reference(
+                required(request, "name")?,
+                required(request, "type")?,
+                &json_body(request)?,
+            )?,
+            _ => return Err(ServerError::method_not_allowed(method)),
+        },
+        ["api", "v2", "trees", reference, rest @ ..] => tree(repository, request, reference, rest)?,
+        _ => {
+            return Err(ServerError::not_found(
+                "UNKNOWN",
+                format!("{} /{} is not served", method, segments.join("/")),
+            ))
+        }
+    };
+    Ok(Response::ok(body))
+}
+
+fn tree(
+    repository: &mut Repository,
+    request: &Request,
+    reference: &str,
+    rest: &[&str],
+) -> Result<Value, ServerError> {
+    let method = request.method.as_str();
+    match (method, rest) {
+        ("GET", []) => repository.get_reference(reference),
+        ("PUT", []) => {
+            repository.assign_reference(reference, request.param("type"), &json_body(request)?)
+        }
+        ("DELETE", []) => repository.delete_reference(reference, request.param("type")),
+        ("GET", ["entries"]) => {
+            let mut filter = EntriesFilter {
+                keys: request.params("key").into_iter().map(parse_key).collect(),
+                min_key: request.param("min-key").map(parse_key),
+                max_key: request.param("max-key").map(parse_key),
+                prefix_key: request.param("prefix-key").map(parse_key),
+                content_types: None,
+                min_depth: None,
+                max_depth: None,
+                with_content: request.param("content") == Some("true"),
+                max_records: max_records(request)?,
+                page_token: request.param("page-token").map(str::to_string),
+            };
+            if let Some(expression) = request.param("filter") {
+                apply_cel_filter(&mut filter, expression)?;
+            }
+            repository.entries(reference, &filter)
+        }
+        ("GET", ["contents", key]) => repository.content(reference, &parse_key(key)),
+        ("GET", ["contents"]) => {
+            let keys: Vec<_> = request.params("key").into_iter().map(parse_key).collect();
+            repository.contents(reference, &keys)
+        }
+        ("POST", ["contents"]) => {
+            let body = json_body(request)?;
+            let keys = body["requestedKeys"]
+                .as_array()
+                .map(|keys| {
+                    keys.iter()
+                        .filter_map(|key| serde_json::from_value(key["elements"].clone()).ok())
+                        .collect::<Vec<Vec<String>>>()
+                })
+                .unwrap_or_default();
+            repository.contents(reference, &keys)
+        }
+        ("GET", ["history"]) => repository.history(
+            reference,
+            request.param("fetch") == Some("ALL"),
+            max_records(request)?,
+            request.param("page-token"),
+        ),
+        ("POST", ["history", "commit"]) => repository.commit(reference, &json_body(request)?),
+        ("POST", ["history", "merge"]) => repository.merge(reference, &json_body(request)?),
+        ("GET", ["diff", to]) => repository.diff(reference, to),
+        ("GET", ["recent-changes"]) => repository.recent_changes(reference),
+        _ => Err(ServerError::not_found(
+            "UNKNOWN",
+            format!(
+                "{} trees/{}/{} is not served",
+                method,
+                reference,
+                rest.join("/")
+            ),
+        )),
+    }
+}
+
+/// Parses a content key from its path form: elements separated by `.`, with
+/// dots inside elements written as `\u{1D}`.
+fn parse_key(value: &str) -> Vec<String> {
+    value
+        .split('.')
+        .map(|element| element.replace('\u{1D}', "."))
+        .collect()
+}
+
+/// Applies a CEL entries filter. Only conjunctions of the conditions
+/// [`crate::models::EntriesParams`] generates are understood: on
+/// `entry.contentType` with `==` or `in`, and on `size(entry.keyElements)`.
+fn apply_cel_filter(filter: &mut EntriesFilter, expression: &str) -> Result<(), ServerError> {
+    let unsupported = |condition: &str| {
+        ServerError::bad_request(format!(
+            "CEL condition '{}' is not supported by the test server",
+            condition
+        ))
+    };
+    let quoted = |value: &str| {
+        value
+            .trim()
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+            .map(str::to_string)
+    };
+
+    for condition in expression.split("&&").map(str::trim) {
+        if let Some(types) = condition
+            .strip_prefix("entry.contentType in [")
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            let types = types
+                .split(',')
+                .map(quoted)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| unsupported(condition))?;
+            filter.content_types = Some(types);
+        } else if let Some(value) = condition.strip_prefix("entry.contentType ==") {
+            filter.content_types = Some(vec![quoted(value).ok_or_else(|| unsupported(condition))?]);
+        } else if let Some(comparison) = condition.strip_prefix("size(entry.keyElements) ") {
+            let (operator, value) = comparison
+                .split_once(' ')
+                .ok_or_else(|| unsupported(condition))?;
+            let value: usize = value.trim().parse().map_err(|_| unsupported(condition))?;
+            match operator {
+                ">=" => filter.min_depth = Some(value),
+                "<=" => filter.max_depth = Some(value),
+                "==" => {
+                    filter.min_depth = Some(value);
+                    filter.max_depth = Some(value);
+                }
+                _ => return Err(unsupported(condition)),
+            }
+        } else {
+            return Err(unsupported(condition));
+        }
+    }
+    Ok(())
+}
+
+fn required<'a>(request: &'a Request, name: &str) -> Result<&'a str, ServerError> {
+    request
+        .param(name)
+        .ok_or_else(|| ServerError::bad_request(format!("query parameter '{}' is required", name)))
+}
+
+fn max_records(request: &Request) -> Result<Option<usize>, ServerError> {
+    request
+        .param("max-records")
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| ServerError::bad_request(format!("invalid max-records '{}'", value)))
+        })
+        .transpose()
+}
+
+fn json_body(request: &Request) -> Result<Value, ServerError> {
+    serde_json::from_slice(&request.body)
+        .map_err(|e| ServerError::bad_request(format!("invalid JSON body: {}", e)))
+}
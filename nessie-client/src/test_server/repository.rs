@@ -0,0 +1,971 @@
+//! The versioned store behind [`super::NessieTestServer`]: named references
+//! over a graph of commits, each holding the full set of contents at that
+//! commit.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use super::http::{reason, Response};
+use crate::models::{CommitMeta, ReferenceType};
+use crate::ref_spec::validate_name;
+
+/// Hash of the empty commit every reference starts from.
+pub const NO_ANCESTOR: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+
+type Key = Vec<String>;
+type Contents = BTreeMap<Key, Value>;
+
+/// An error answered in Nessie's error format.
+#[derive(Debug)]
+pub(crate) struct ServerError {
+    status: u16,
+    code: &'static str,
+    message: String,
+    conflicts: Vec<Value>,
+}
+
+impl ServerError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: 400,
+            code: "BAD_REQUEST",
+            message: message.into(),
+            conflicts: Vec::new(),
+        }
+    }
+
+    pub fn not_found(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status: 404,
+            code,
+            message: message.into(),
+            conflicts: Vec::new(),
+        }
+    }
+
+    pub fn conflict(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status: 409,
+            code,
+            message: message.into(),
+            conflicts: Vec::new(),
+        }
+    }
+
+    pub fn method_not_allowed(method: &str) -> Self {
+        Self {
+            status: 405,
+            code: "UNKNOWN",
+            message: format!("{} is not supported here", method),
+            conflicts: Vec::new(),
+        }
+    }
+
+    /// Conflicts of a commit or merge, one per key.
+    fn reference_conflicts(conflicts: Vec<Value>) -> Self {
+        let message = conflicts
+            .iter()
+            .filter_map(|conflict| conflict["message"].as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self {
+            status: 409,
+            code: "REFERENCE_CONFLICT",
+            message,
+            conflicts,
+        }
+    }
+
+    pub fn into_response(self) -> Response {
+        let details = if self.conflicts.is_empty() {
+            Value::Null
+        } else {
+            json!({ "conflicts": self.conflicts })
+        };
+        Response {
+            status: self.status,
+            body: json!({
+                "status": self.status,
+                "reason": reason(self.status),
+                "message": self.message,
+                "errorCode": self.code,
+                "errorDetails": details,
+                "serverStackTrace": null,
+            }),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, ServerError>;
+
+#[derive(Debug)]
+struct NamedReference {
+    ref_type: ReferenceType,
+    hash: String,
+    /// Earlier heads, most recent first.
+    previous: Vec<String>,
+}
+
+impl NamedReference {
+    fn move_to(&mut self, hash: String) {
+        let previous = std::mem::replace(&mut self.hash, hash);
+        self.previous.insert(0, previous);
+    }
+}
+
+#[derive(Debug)]
+struct Commit {
+    /// The parent first, then the merged commit, if any.
+    parents: Vec<String>,
+    meta: Option<CommitMeta>,
+    operations: Vec<Value>,
+    contents: Contents,
+}
+
+/// A reference resolved to a commit.
+#[derive(Debug, Clone)]
+struct Resolved {
+    /// `None` for detached commits.
+    name: Option<String>,
+    ref_type: ReferenceType,
+    hash: String,
+    /// Whether the request named a hash, as commits and assignments require.
+    explicit_hash: bool,
+}
+
+impl Resolved {
+    fn to_json(&self) -> Value {
+        reference_json(
+            self.name.as_deref().unwrap_or("DETACHED"),
+            self.ref_type,
+            &self.hash,
+        )
+    }
+}
+
+fn reference_json(name: &str, ref_type: ReferenceType, hash: &str) -> Value {
+    json!({ "type": ref_type, "name": name, "hash": hash })
+}
+
+fn key_json(key: &Key) -> Value {
+    json!({ "elements": key })
+}
+
+fn key_string(key: &Key) -> String {
+    key.join(".")
+}
+
+fn parse_key_json(value: &Value) -> Result<Key> {
+    value["elements"]
+        .as_array()
+        .filter(|elements| !elements.is_empty())
+        .and_then(|elements| {
+            elements
+                .iter()
+                .map(|element| element.as_str().map(str::to_string))
+                .collect()
+        })
+        .ok_or_else(|| ServerError::bad_request(format!("invalid content key {}", value)))
+}
+
+fn conflict_json(conflict_type: &str, key: &Key, message: String) -> Value {
+    json!({ "conflictType": conflict_type, "key": key_json(key), "message": message })
+}
+
+fn is_namespace(content: Option<&Value>) -> bool {
+    content.is_some_and(|content| content["type"] == "NAMESPACE")
+}
+
+/// Returns one page of `items`; page tokens are offsets.
+fn page<T>(
+    items: Vec<T>,
+    max_records: Option<usize>,
+    token: Option<&str>,
+) -> Result<(Vec<T>, Option<String>)> {
+    let offset = match token {
+        Some(token) => token
+            .parse::<usize>()
+            .map_err(|_| ServerError::bad_request(format!("invalid page token '{}'", token)))?,
+        None => 0,
+    };
+    let total = items.len();
+    let items: Vec<T> = items
+        .into_iter()
+        .skip(offset)
+        .take(max_records.unwrap_or(usize::MAX))
+        .collect();
+    let next = offset + items.len();
+    Ok((items, (next < total).then(|| next.to_string())))
+}
+
+fn reference_type(value: &str) -> Result<ReferenceType> {
+    match value.to_ascii_uppercase().as_str() {
+        "BRANCH" => Ok(ReferenceType::Branch),
+        "TAG" => Ok(ReferenceType::Tag),
+        _ => Err(ServerError::bad_request(format!(
+            "invalid reference type '{}'",
+            value
+        ))),
+    }
+}
+
+fn same_type(a: ReferenceType, b: ReferenceType) -> bool {
+    std::mem::discriminant(&a) == std::mem::discriminant(&b)
+}
+
+/// Filters of the entries listing.
+#[derive(Debug, Default)]
+pub(crate) struct EntriesFilter {
+    pub keys: Vec<Key>,
+    pub min_key: Option<Key>,
+    pub max_key: Option<Key>,
+    pub prefix_key: Option<Key>,
+    pub content_types: Option<Vec<String>>,
+    pub min_depth: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub with_content: bool,
+    pub max_records: Option<usize>,
+    pub page_token: Option<String>,
+}
+
+impl EntriesFilter {
+    fn matches(&self, key: &Key, content_type: &str) -> bool {
+        (self.keys.is_empty() || self.keys.contains(key))
+            && self
+                .content_types
+                .as_ref()
+                .is_none_or(|types| types.iter().any(|t| t == content_type))
+            && self.min_depth.is_none_or(|min| key.len() >= min)
+            && self.max_depth.is_none_or(|max| key.len() <= max)
+            && self.min_key.as_ref().is_none_or(|min| key >= min)
+            && self.max_key.as_ref().is_none_or(|max| key <= max)
+            && self
+                .prefix_key
+                .as_ref()
+                .is_none_or(|prefix| key.starts_with(prefix))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Repository {
+    default_branch: String,
+    references: BTreeMap<String, NamedReference>,
+    commits: HashMap<String, Commit>,
+    sequence: u64,
+    created: DateTime<Utc>,
+}
+
+impl Repository {
+    pub fn new(default_branch: &str) -> Self {
+        let root = Commit {
+            parents: Vec::new(),
+            meta: None,
+            operations: Vec::new(),
+            contents: Contents::new(),
+        };
+        Self {
+            default_branch: default_branch.to_string(),
+            references: BTreeMap::from([(
+                default_branch.to_string(),
+                NamedReference {
+                    ref_type: ReferenceType::Branch,
+                    hash: NO_ANCESTOR.to_string(),
+                    previous: Vec::new(),
+                },
+            )]),
+            commits: HashMap::from([(NO_ANCESTOR.to_string(), root)]),
+            sequence: 0,
+            created: Utc::now(),
+        }
+    }
+
+    fn next_id(&mut self) -> (u64, u64) {
+        self.sequence += 1;
+        let mut hasher = DefaultHasher::new();
+        (self.created, self.sequence).hash(&mut hasher);
+        (hasher.finish(), self.sequence)
+    }
+
+    fn next_hash(&mut self) -> String {
+        let (hash, sequence) = self.next_id();
+        format!("{:016x}{:048x}", hash, sequence)
+    }
+
+    fn next_content_id(&mut self) -> String {
+        let (hash, sequence) = self.next_id();
+        format!(
+            "{:08x}-{:04x}-4{:03x}-8000-{:012x}",
+            hash >> 32,
+            (hash >> 16) & 0xffff,
+            hash & 0xfff,
+            sequence
+        )
+    }
+
+    fn find_commit(&self, hash: &str) -> Result<String> {
+        if self.commits.contains_key(hash) {
+            return Ok(hash.to_string());
+        }
+        let mut matches = self.commits.keys().filter(|known| known.starts_with(hash));
+        match (matches.next(), matches.next()) {
+            (Some(found), None) if hash.len() >= 8 => Ok(found.clone()),
+            _ => Err(ServerError::not_found(
+                "REFERENCE_NOT_FOUND",
+                format!("Commit '{}' not found", hash),
+            )),
+        }
+    }
+
+    /// Every commit reachable from `hash`, itself included.
+    fn ancestors(&self, hash: &str) -> HashSet<String> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([hash.to_string()]);
+        while let Some(next) = queue.pop_front() {
+            if seen.insert(next.clone()) {
+                queue.extend(self.commits[&next].parents.iter().cloned());
+            }
+        }
+        seen
+    }
+
+    fn resolve(&self, spec: &str) -> Result<Resolved> {
+        if spec.contains(['^', '*']) {
+            return Err(ServerError::bad_request(format!(
+                "relative commit specs other than '~' are not supported: {}",
+                spec
+            )));
+        }
+        let (spec, ancestor) = match spec.split_once('~') {
+            Some((spec, n)) => (
+                spec,
+                n.parse::<usize>()
+                    .map_err(|_| ServerError::bad_request(format!("invalid ancestor '~{}'", n)))?,
+            ),
+            None => (spec, 0),
+        };
+        let (name, hash) = match spec.split_once('@') {
+            Some((name, hash)) => (name, Some(hash)),
+            None => (spec, None),
+        };
+        let name = if name == "-" {
+            self.default_branch.as_str()
+        } else {
+            name
+        };
+
+        let mut resolved = if name.is_empty() {
+            let hash = hash.ok_or_else(|| ServerError::bad_request("empty reference"))?;
+            Resolved {
+                name: None,
+                ref_type: ReferenceType::Detached,
+                hash: self.find_commit(hash)?,
+                explicit_hash: true,
+            }
+        } else {
+            let reference = self.references.get(name).ok_or_else(|| {
+                ServerError::not_found(
+                    "REFERENCE_NOT_FOUND",
+                    format!("Named reference '{}' not found", name),
+                )
+            })?;
+            let hash = match hash {
+                Some(hash) => {
+                    let hash = self.find_commit(hash)?;
+                    if !self.ancestors(&reference.hash).contains(&hash) {
+                        return Err(ServerError::not_found(
+                            "REFERENCE_NOT_FOUND",
+                            format!("Could not find commit '{}' in reference '{}'", hash, name),
+                        ));
+                    }
+                    hash
+                }
+                None => reference.hash.clone(),
+            };
+            Resolved {
+                name: Some(name.to_string()),
+                ref_type: reference.ref_type,
+                hash,
+                explicit_hash: spec.contains('@'),
+            }
+        };
+
+        for _ in 0..ancestor {
+            resolved.hash = self.commits[&resolved.hash]
+                .parents
+                .first()
+                .cloned()
+                .ok_or_else(|| {
+                    ServerError::not_found("REFERENCE_NOT_FOUND", "ancestor does not exist")
+                })?;
+        }
+        Ok(resolved)
+    }
+
+    /// Resolves a named reference that is about to be changed.
+    fn resolve_for_update(&self, spec: &str, type_param: Option<&str>) -> Result<Resolved> {
+        let resolved = self.resolve(spec)?;
+        let name = resolved
+            .name
+            .as_deref()
+            .ok_or_else(|| ServerError::bad_request("detached commits cannot be changed"))?;
+        if let Some(expected) = type_param {
+            if !same_type(reference_type(expected)?, resolved.ref_type) {
+                return Err(ServerError::not_found(
+                    "REFERENCE_NOT_FOUND",
+                    format!("'{}' is not a {}", name, expected),
+                ));
+            }
+        }
+        if !resolved.explicit_hash {
+            return Err(ServerError::bad_request(format!(
+                "the expected hash of '{}' is required",
+                name
+            )));
+        }
+        Ok(resolved)
+    }
+
+    fn head_of(&self, resolved: &Resolved) -> &str {
+        let name = resolved.name.as_deref().expect("named reference");
+        &self.references[name].hash
+    }
+
+    pub fn config(&self) -> Value {
+        json!({
+            "defaultBranch": self.default_branch,
+            "minSupportedApiVersion": 1,
+            "maxSupportedApiVersion": 2,
+            "actualApiVersion": 2,
+            "specVersion": "2.2.0",
+            "noAncestorHash": NO_ANCESTOR,
+            "repositoryCreationTimestamp": self.created,
+            "oldestPossibleCommitTimestamp": self.created,
+            "additionalProperties": {},
+        })
+    }
+
+    pub fn list_references(
+        &self,
+        max_records: Option<usize>,
+        token: Option<&str>,
+    ) -> Result<Value> {
+        let references = self
+            .references
+            .iter()
+            .map(|(name, reference)| reference_json(name, reference.ref_type, &reference.hash))
+            .collect();
+        let (references, token) = page(references, max_records, token)?;
+        Ok(json!({
+            "references": references,
+            "hasMore": token.is_some(),
+            "token": token,
+        }))
+    }
+
+    pub fn get_reference(&self, spec: &str) -> Result<Value> {
+        Ok(json!({ "reference": self.resolve(spec)?.to_json() }))
+    }
+
+    pub fn create_reference(
+        &mut self,
+        name: &str,
+        type_param: &str,
+        source: &Value,
+    ) -> Result<Value> {
+        validate_name(name).map_err(|e| ServerError::bad_request(e.to_string()))?;
+        let ref_type = reference_type(type_param)?;
+        if self.references.contains_key(name) {
+            return Err(ServerError::conflict(
+                "REFERENCE_ALREADY_EXISTS",
+                format!("Named reference '{}' already exists", name),
+            ));
+        }
+        let hash = match source["hash"].as_str() {
+            Some(hash) => self.find_commit(hash)?,
+            None => NO_ANCESTOR.to_string(),
+        };
+        self.references.insert(
+            name.to_string(),
+            NamedReference {
+                ref_type,
+                hash: hash.clone(),
+                previous: Vec::new(),
+            },
+        );
+        Ok(json!({ "reference": reference_json(name, ref_type, &hash) }))
+    }
+
+    pub fn assign_reference(
+        &mut self,
+        spec: &str,
+        type_param: Option<&str>,
+        target: &Value,
+    ) -> Result<Value> {
+        let resolved = self.resolve_for_update(spec, type_param)?;
+        if self.head_of(&resolved) != resolved.hash {
+            return Err(ServerError::conflict(
+                "REFERENCE_CONFLICT",
+                format!(
+                    "Named-reference '{}' is not at expected hash '{}'",
+                    resolved.name.as_deref().unwrap_or_default(),
+                    resolved.hash
+                ),
+            ));
+        }
+        let target = target["hash"]
+            .as_str()
+            .ok_or_else(|| ServerError::bad_request("the target has no hash"))?;
+        let target = self.find_commit(target)?;
+
+        let name = resolved.name.clone().unwrap_or_default();
+        let reference = self.references.get_mut(&name).expect("resolved");
+        reference.move_to(target.clone());
+        Ok(json!({ "reference": reference_json(&name, resolved.ref_type, &target) }))
+    }
+
+    pub fn delete_reference(&mut self, spec: &str, type_param: Option<&str>) -> Result<Value> {
+        let resolved = self.resolve_for_update(spec, type_param)?;
+        let name = resolved.name.clone().unwrap_or_default();
+        if name == self.default_branch {
+            return Err(ServerError::bad_request(format!(
+                "Default branch '{}' cannot be deleted",
+                name
+            )));
+        }
+        if self.head_of(&resolved) != resolved.hash {
+            return Err(ServerError::conflict(
+                "REFERENCE_CONFLICT",
+                format!(
+                    "Named-reference '{}' is not at expected hash '{}'",
+                    name, resolved.hash
+                ),
+            ));
+        }
+        self.references.remove(&name);
+        Ok(json!({ "reference": resolved.to_json() }))
+    }
+
+    pub fn entries(&self, spec: &str, filter: &EntriesFilter) -> Result<Value> {
+        let resolved = self.resolve(spec)?;
+        let entries = self.commits[&resolved.hash]
+            .contents
+            .iter()
+            .filter(|(key, content)| {
+                filter.matches(key, content["type"].as_str().unwrap_or_default())
+            })
+            .map(|(key, content)| {
+                let mut entry = json!({
+                    "type": content["type"],
+                    "name": key_json(key),
+                    "contentId": content["id"],
+                });
+                if filter.with_content {
+                    entry["content"] = content.clone();
+                }
+                entry
+            })
+            .collect();
+        let (entries, token) = page(entries, filter.max_records, filter.page_token.as_deref())?;
+        Ok(json!({
+            "entries": entries,
+            "effectiveReference": resolved.to_json(),
+            "hasMore": token.is_some(),
+            "token": token,
+        }))
+    }
+
+    pub fn content(&self, spec: &str, key: &Key) -> Result<Value> {
+        let resolved = self.resolve(spec)?;
+        let content = self.commits[&resolved.hash]
+            .contents
+            .get(key)
+            .ok_or_else(|| {
+                ServerError::not_found(
+                    "CONTENT_NOT_FOUND",
+                    format!("Could not find content for key '{}'", key_string(key)),
+                )
+            })?;
+        Ok(json!({ "content": content, "effectiveReference": resolved.to_json() }))
+    }
+
+    pub fn contents(&self, spec: &str, keys: &[Key]) -> Result<Value> {
+        let resolved = self.resolve(spec)?;
+        let contents = &self.commits[&resolved.hash].contents;
+        let found: Vec<Value> = keys
+            .iter()
+            .filter_map(|key| {
+                let content = contents.get(key)?;
+                Some(json!({ "key": key_json(key), "content": content }))
+            })
+            .collect();
+        Ok(json!({ "contents": found, "effectiveReference": resolved.to_json() }))
+    }
+
+    pub fn history(
+        &self,
+        spec: &str,
+        with_operations: bool,
+        max_records: Option<usize>,
+        token: Option<&str>,
+    ) -> Result<Value> {
+        let resolved = self.resolve(spec)?;
+        let mut entries = Vec::new();
+        let mut hash = resolved.hash;
+        while let Some(meta) = &self.commits[&hash].meta {
+            let commit = &self.commits[&hash];
+            let mut entry = json!({
+                "commitMeta": meta,
+                "parentCommitHash": commit.parents[0],
+            });
+            if with_operations {
+                entry["operations"] = json!(commit.operations);
+            }
+            entries.push(entry);
+            hash = commit.parents[0].clone();
+        }
+        let (entries, token) = page(entries, max_records, token)?;
+        Ok(json!({
+            "logEntries": entries,
+            "hasMore": token.is_some(),
+            "token": token,
+        }))
+    }
+
+    pub fn recent_changes(&self, spec: &str) -> Result<Value> {
+        let resolved = self.resolve(spec)?;
+        let name = resolved
+            .name
+            .as_deref()
+            .ok_or_else(|| ServerError::bad_request("detached commits have no history"))?;
+        let state = |hash: &str| {
+            json!({
+                "commitHash": hash,
+                "commitConsistency": "NOT_CHECKED",
+                "meta": self.commits[hash].meta,
+            })
+        };
+        let reference = &self.references[name];
+        Ok(json!({
+            "reference": reference_json(name, reference.ref_type, &reference.hash),
+            "current": state(&reference.hash),
+            "previous": reference.previous.iter().map(|hash| state(hash)).collect::<Vec<_>>(),
+            "commitLogConsistency": "NOT_CHECKED",
+        }))
+    }
+
+    pub fn diff(&self, from_spec: &str, to_spec: &str) -> Result<Value> {
+        let from = self.resolve(from_spec)?;
+        let to = self.resolve(to_spec)?;
+        let from_contents = &self.commits[&from.hash].contents;
+        let to_contents = &self.commits[&to.hash].contents;
+        let keys: BTreeSet<&Key> = from_contents.keys().chain(to_contents.keys()).collect();
+        let diffs: Vec<Value> = keys
+            .into_iter()
+            .filter(|key| from_contents.get(*key) != to_contents.get(*key))
+            .map(|key| {
+                json!({
+                    "key": key_json(key),
+                    "from": from_contents.get(key),
+                    "to": to_contents.get(key),
+                })
+            })
+            .collect();
+        Ok(json!({
+            "effectiveFromReference": from.to_json(),
+            "effectiveToReference": to.to_json(),
+            "diffs": diffs,
+            "hasMore": false,
+        }))
+    }
+
+    /// Adds a commit on top of `parents[0]` and moves `branch` to it.
+    fn add_commit(
+        &mut self,
+        branch: &str,
+        parents: Vec<String>,
+        meta: CommitMeta,
+        operations: Vec<Value>,
+        contents: Contents,
+    ) -> String {
+        let hash = self.next_hash();
+        let now = Utc::now();
+        let meta = CommitMeta {
+            hash: Some(hash.clone()),
+            committer: Some(String::new()),
+            commit_time: Some(now),
+            author_time: meta.author_time.or(Some(now)),
+            parent_commit_hashes: parents.clone(),
+            ..meta
+        };
+        self.commits.insert(
+            hash.clone(),
+            Commit {
+                parents,
+                meta: Some(meta),
+                operations,
+                contents,
+            },
+        );
+        self.references
+            .get_mut(branch)
+            .expect("committed on an existing branch")
+            .move_to(hash.clone());
+        hash
+    }
+
+    /// Commits operations on a branch.
+    ///
+    /// Like Nessie, the expected hash may be behind the head of the branch as
+    /// long as none of the keys changed since then.
+    pub fn commit(&mut self, spec: &str, body: &Value) -> Result<Value> {
+        let resolved = self.resolve_for_update(spec, Some("BRANCH"))?;
+        let branch = resolved.name.clone().unwrap_or_default();
+        let head = self.head_of(&resolved).to_string();
+        let meta: CommitMeta = serde_json::from_value(body["commitMeta"].clone())
+            .map_err(|e| ServerError::bad_request(format!("invalid commit meta: {}", e)))?;
+        let operations = body["operations"].as_array().cloned().unwrap_or_default();
+
+        let expected_contents = &self.commits[&resolved.hash].contents;
+        let mut contents = self.commits[&head].contents.clone();
+        let mut conflicts = Vec::new();
+        let mut pending_ids = Vec::new();
+        let mut deleted_ids: Vec<String> = Vec::new();
+        let mut applied = Vec::new();
+
+        for operation in operations {
+            let key = parse_key_json(&operation["key"])?;
+            if expected_contents.get(&key) != contents.get(&key) {
+                conflicts.push(conflict_json(
+                    "VALUE_DIFFERS",
+                    &key,
+                    format!("Key '{}' has conflicting changes", key_string(&key)),
+                ));
+                continue;
+            }
+
+            match operation["type"].as_str().unwrap_or_default() {
+                "PUT" => {
+                    let mut content = operation["content"].clone();
+                    let existing = contents.get(&key);
+                    // A content deleted earlier in the commit may be put
+                    // again under a new key: a rename.
+                    let renamed = existing.is_none()
+                        && content["id"]
+                            .as_str()
+                            .is_some_and(|id| deleted_ids.iter().any(|deleted| deleted == id));
+                    if existing.is_none() {
+                        let parent = &key[..key.len() - 1];
+                        if !parent.is_empty() && !is_namespace(contents.get(parent)) {
+                            conflicts.push(conflict_json(
+                                "NAMESPACE_ABSENT",
+                                &key,
+                                format!("Namespace '{}' must exist", key_string(&parent.to_vec())),
+                            ));
+                            continue;
+                        }
+                    }
+                    match content["id"].as_str() {
+                        None => {
+                            if existing.is_some() {
+                                conflicts.push(conflict_json(
+                                    "KEY_EXISTS",
+                                    &key,
+                                    format!("Key '{}' already exists", key_string(&key)),
+                                ));
+                                continue;
+                            }
+                            pending_ids.push(key.clone());
+                        }
+                        Some(_) if renamed => {}
+                        Some(id) => {
+                            if existing.is_none_or(|existing| existing["id"] != id) {
+                                conflicts.push(conflict_json(
+                                    "CONTENT_NOT_FOUND",
+                                    &key,
+                                    format!(
+                                        "Content '{}' not found at key '{}'",
+                                        id,
+                                        key_string(&key)
+                                    ),
+                                ));
+                                continue;
+                            }
+                        }
+                    }
+                    if content["type"] == "NAMESPACE" {
+                        content["elements"] = json!(key);
+                    }
+                    contents.insert(key, content.clone());
+                    applied.push(
+                        json!({ "type": "PUT", "key": operation["key"], "content": content }),
+                    );
+                }
+                "DELETE" => {
+                    let Some(existing) = contents.get(&key) else {
+                        conflicts.push(conflict_json(
+                            "KEY_DOES_NOT_EXIST",
+                            &key,
+                            format!("Key '{}' does not exist", key_string(&key)),
+                        ));
+                        continue;
+                    };
+                    if existing["type"] == "NAMESPACE"
+                        && contents
+                            .keys()
+                            .any(|other| other.len() > key.len() && other.starts_with(&key))
+                    {
+                        conflicts.push(conflict_json(
+                            "NAMESPACE_NOT_EMPTY",
+                            &key,
+                            format!("Namespace '{}' is not empty", key_string(&key)),
+                        ));
+                        continue;
+                    }
+                    if let Some(id) = existing["id"].as_str() {
+                        deleted_ids.push(id.to_string());
+                    }
+                    contents.remove(&key);
+                    applied.push(json!({ "type": "DELETE", "key": operation["key"] }));
+                }
+                "UNCHANGED" => {}
+                other => {
+                    return Err(ServerError::bad_request(format!(
+                        "unknown operation type '{}'",
+                        other
+                    )))
+                }
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(ServerError::reference_conflicts(conflicts));
+        }
+
+        // Ids are assigned once the commit is known to succeed.
+        let mut added = Vec::new();
+        for key in pending_ids {
+            let id = self.next_content_id();
+            contents.get_mut(&key).expect("put above")["id"] = json!(id);
+            for operation in &mut applied {
+                if operation["key"]["elements"] == json!(key) {
+                    operation["content"]["id"] = json!(id);
+                }
+            }
+            added.push(json!({ "key": key_json(&key), "contentId": id }));
+        }
+
+        let hash = self.add_commit(&branch, vec![head], meta, applied, contents);
+        Ok(json!({
+            "targetBranch": reference_json(&branch, ReferenceType::Branch, &hash),
+            "addedContents": added,
+        }))
+    }
+
+    /// Merges the changes of `fromHash` since the common ancestor into a
+    /// branch, failing on keys that both sides changed differently.
+    pub fn merge(&mut self, spec: &str, body: &Value) -> Result<Value> {
+        let target = self.resolve(spec)?;
+        let branch = target
+            .name
+            .clone()
+            .filter(|_| matches!(target.ref_type, ReferenceType::Branch))
+            .ok_or_else(|| ServerError::bad_request("only branches can be merged into"))?;
+        let head = self.head_of(&target).to_string();
+        let from_hash = body["fromHash"]
+            .as_str()
+            .ok_or_else(|| ServerError::bad_request("fromHash is required"))?;
+        let from_hash = self.find_commit(from_hash)?;
+        let from_name = body["fromRefName"]
+            .as_str()
+            .unwrap_or("DETACHED")
+            .to_string();
+        let dry_run = body["dryRun"].as_bool().unwrap_or(false);
+
+        let from_ancestors = self.ancestors(&from_hash);
+        let common_ancestor = {
+            let mut queue = VecDeque::from([head.clone()]);
+            let mut seen = HashSet::new();
+            loop {
+                let next = queue.pop_front().expect("the root is a common ancestor");
+                if from_ancestors.contains(&next) {
+                    break next;
+                }
+                if seen.insert(next.clone()) {
+                    queue.extend(self.commits[&next].parents.iter().cloned());
+                }
+            }
+        };
+
+        let base = &self.commits[&common_ancestor].contents;
+        let source = &self.commits[&from_hash].contents;
+        let mut contents = self.commits[&head].contents.clone();
+        let changed: BTreeSet<Key> = base
+            .keys()
+            .chain(source.keys())
+            .filter(|key| base.get(*key) != source.get(*key))
+            .cloned()
+            .collect();
+
+        let mut details = Vec::new();
+        let mut conflicts = Vec::new();
+        for key in &changed {
+            let target_changed = base.get(key) != contents.get(key);
+            let conflict = if target_changed && contents.get(key) != source.get(key) {
+                let conflict = conflict_json(
+                    "VALUE_DIFFERS",
+                    key,
+                    format!("Key '{}' has conflicting changes", key_string(key)),
+                );
+                conflicts.push(conflict.clone());
+                conflict
+            } else {
+                Value::Null
+            };
+            details.push(json!({
+                "key": key_json(key),
+                "mergeBehavior": "NORMAL",
+                "conflict": conflict,
+            }));
+            match source.get(key) {
+                Some(content) => contents.insert(key.clone(), content.clone()),
+                None => contents.remove(key),
+            };
+        }
+        if !conflicts.is_empty() {
+            return Err(ServerError::reference_conflicts(conflicts));
+        }
+
+        let applied = !dry_run && !changed.is_empty();
+        let resultant = if applied {
+            let meta = CommitMeta {
+                message: body["message"]
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("Merged {} at {}", from_name, from_hash)),
+                ..Default::default()
+            };
+            self.add_commit(
+                &branch,
+                vec![head.clone(), from_hash],
+                meta,
+                Vec::new(),
+                contents,
+            )
+        } else {
+            head.clone()
+        };
+        Ok(json!({
+            "resultantTargetHash": resultant,
+            "commonAncestor": common_ancestor,
+            "targetBranch": branch,
+            "effectiveTargetHash": head,
+            "expectedHash": target.explicit_hash.then_some(&target.hash),
+            "wasApplied": applied,
+            "wasSuccessful": true,
+            "details": details,
+        }))
+    }
+}
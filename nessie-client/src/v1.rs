@@ -0,0 +1,274 @@
+//! Mapping of the [`NessieClient`] operations onto the legacy `/api/v1`
+//! endpoints, for servers that do not expose `/api/v2`.
+//!
+//! v1 names references and hashes in separate path and query parameters,
+//! returns bare references instead of wrapping them, and answers reference
+//! deletion and assignment with an empty body.
+
+use serde::Serialize;
+
+use crate::client::NessieClient;
+use crate::error::NessieError;
+use crate::generated::encode_path_segment;
+use crate::generated::models::CommitMetaV1;
+use crate::models::{
+    CommitResponse, EntriesParams, EntriesResponse, FetchOption, ListReferencesParams, Operation,
+    Operations, Reference, ReferenceResponse, ReferenceType, ReferencesResponse,
+};
+use crate::ref_spec::RefSpec;
+
+/// Query parameters of `GET /v1/trees`, which are camel-cased unlike in v2.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListReferencesParamsV1<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fetch: Option<FetchOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_records: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<&'a str>,
+}
+
+/// Query parameters of `GET /v1/trees/tree/{ref}/entries`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntriesParamsV1<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash_on_ref: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_records: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<&'a str>,
+}
+
+/// Commit payload of `POST /v1/trees/branch/{branchName}/commit`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OperationsV1<'a> {
+    commit_meta: CommitMetaV1,
+    operations: &'a [Operation],
+}
+
+/// v1 addresses references by their lower-cased type in paths.
+fn type_segment(ref_type: ReferenceType) -> Result<&'static str, NessieError> {
+    match ref_type {
+        ReferenceType::Branch => Ok("branch"),
+        ReferenceType::Tag => Ok("tag"),
+        ReferenceType::Detached => Err(NessieError::InvalidReference(
+            "detached commits cannot be modified".to_string(),
+        )),
+    }
+}
+
+/// v1 only looks references up by name; hashes are passed separately where
+/// supported.
+fn require_plain_name<'a>(reference: &'a RefSpec, operation: &str) -> Result<&'a str, NessieError> {
+    let name = reference.require_name(operation)?;
+    if reference.hash().is_some() || !reference.relative().is_empty() {
+        return Err(NessieError::Unsupported(format!(
+            "{} with '{}' requires Nessie API v2",
+            operation, reference
+        )));
+    }
+    Ok(name)
+}
+
+impl NessieClient {
+    pub(crate) async fn list_references_v1(
+        &self,
+        params: &ListReferencesParams,
+    ) -> Result<ReferencesResponse, NessieError> {
+        let url = self.base_url.join("trees")?;
+        let query = ListReferencesParamsV1 {
+            fetch: params.fetch,
+            filter: params.filter.as_deref(),
+            max_records: params.max_records,
+            page_token: params.page_token.as_deref(),
+        };
+        let response = self.send(self.client.get(url).query(&query)).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<ReferencesResponse>()
+            .await?)
+    }
+
+    pub(crate) async fn get_reference_v1(
+        &self,
+        reference: &RefSpec,
+        fetch: Option<FetchOption>,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let name = require_plain_name(reference, "get_reference")?;
+        let url = self
+            .base_url
+            .join(&format!("trees/tree/{}", encode_path_segment(name)))?;
+        let mut request = self.client.get(url);
+        if let Some(fetch) = fetch {
+            request = request.query(&[("fetch", fetch)]);
+        }
+        let response = self.send(request).await?;
+        let reference = Self::check_status(response)
+            .await?
+            .json::<Reference>()
+            .await?;
+        Ok(ReferenceResponse { reference })
+    }
+
+    pub(crate) async fn create_reference_v1(
+        &self,
+        name: &str,
+        ref_type: ReferenceType,
+        source: &Reference,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let url = self.base_url.join("trees/tree")?;
+        let new_reference = Reference {
+            name: name.to_string(),
+            ref_type,
+            hash: source.hash.clone(),
+            metadata: None,
+        };
+        let mut request = self.client.post(url).json(&new_reference);
+        if !matches!(source.ref_type, ReferenceType::Detached) {
+            request = request.query(&[("sourceRefName", source.name.as_str())]);
+        }
+        let response = self.send(request).await?;
+        let reference = Self::check_status(response)
+            .await?
+            .json::<Reference>()
+            .await?;
+        Ok(ReferenceResponse { reference })
+    }
+
+    pub(crate) async fn delete_reference_v1(
+        &self,
+        name: &str,
+        expected_hash: &str,
+    ) -> Result<ReferenceResponse, NessieError> {
+        // The type is part of the v1 path, so look it up first.
+        let mut reference = self
+            .get_reference_v1(&RefSpec::named(name)?, None)
+            .await?
+            .reference;
+        let url = self.base_url.join(&format!(
+            "trees/{}/{}",
+            type_segment(reference.ref_type)?,
+            encode_path_segment(name)
+        ))?;
+        let response = self
+            .send(
+                self.client
+                    .delete(url)
+                    .query(&[("expectedHash", expected_hash)]),
+            )
+            .await?;
+        Self::check_status(response).await?;
+
+        reference.hash = Some(expected_hash.to_string());
+        Ok(ReferenceResponse { reference })
+    }
+
+    pub(crate) async fn assign_reference_v1(
+        &self,
+        name: &str,
+        expected_hash: &str,
+        target: &Reference,
+    ) -> Result<ReferenceResponse, NessieError> {
+        let mut reference = self
+            .get_reference_v1(&RefSpec::named(name)?, None)
+            .await?
+            .reference;
+        let url = self.base_url.join(&format!(
+            "trees/{}/{}",
+            type_segment(reference.ref_type)?,
+            encode_path_segment(name)
+        ))?;
+        let response = self
+            .send(
+                self.client
+                    .put(url)
+                    .query(&[("expectedHash", expected_hash)])
+                    .json(target),
+            )
+            .await?;
+        Self::check_status(response).await?;
+
+        reference.hash = target.hash.clone();
+        Ok(ReferenceResponse { reference })
+    }
+
+    pub(crate) async fn list_entries_v1(
+        &self,
+        reference: &RefSpec,
+        params: &EntriesParams,
+    ) -> Result<EntriesResponse, NessieError> {
+        let name = reference.require_name("list_entries")?;
+        if !params.keys.is_empty()
+            || params.min_key.is_some()
+            || params.max_key.is_some()
+            || params.prefix_key.is_some()
+            || params.with_content
+        {
+            return Err(NessieError::Unsupported(
+                "key conditions and contents of entries require Nessie API v2".to_string(),
+            ));
+        }
+        let url = self
+            .base_url
+            .join(&format!("trees/tree/{}/entries", encode_path_segment(name)))?;
+
+        // `hashOnRef` takes the hash and relative part of the spec.
+        let relative: String = reference
+            .relative()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let hash_on_ref = format!("{}{}", reference.hash().unwrap_or_default(), relative);
+
+        let query = EntriesParamsV1 {
+            hash_on_ref: Some(hash_on_ref.as_str()).filter(|hash| !hash.is_empty()),
+            filter: params.cel_filter(),
+            max_records: params.max_records,
+            page_token: params.page_token.as_deref(),
+        };
+        let response = self.send(self.client.get(url).query(&query)).await?;
+        Ok(Self::check_status(response)
+            .await?
+            .json::<EntriesResponse>()
+            .await?)
+    }
+
+    pub(crate) async fn commit_entry_v1(
+        &self,
+        name: &str,
+        expected_hash: &str,
+        operations: &Operations,
+    ) -> Result<CommitResponse, NessieError> {
+        let url = self.base_url.join(&format!(
+            "trees/branch/{}/commit",
+            encode_path_segment(name)
+        ))?;
+        let response = self
+            .send(
+                self.client
+                    .post(url)
+                    .query(&[("expectedHash", expected_hash)])
+                    .json(&OperationsV1 {
+                        commit_meta: (&operations.commit_meta).into(),
+                        operations: &operations.operations,
+                    }),
+            )
+            .await?;
+        // v1 answers with the branch alone and does not report added content.
+        let target_branch = Self::check_status(response)
+            .await?
+            .json::<Reference>()
+            .await?;
+        Ok(CommitResponse {
+            target_branch,
+            added_contents: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,216 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::{ApiVersion, NessieClient},
+    error::NessieError,
+    models::{CommitMeta, Operations, ReferenceType},
+    ref_spec::RefSpec,
+};
+
+const HASH: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+const NEW_HASH: &str = "11223344556677889900aabbccddeeff11223344556677889900aabbccddeeff";
+
+/// Answers like a server that only exposes `/api/v1`.
+async fn start_v1_stand_in() -> (String, common::Requests) {
+    start_stand_in(|request| {
+        let path = request.path.split('?').next().unwrap();
+        match (request.method.as_str(), path) {
+            (_, p) if p.starts_with("/api/v2/") => (404, "{}".to_string()),
+            ("GET", "/api/v1/config") => (
+                200,
+                r#"{"defaultBranch":"main","maxSupportedApiVersion":1}"#.to_string(),
+            ),
+            ("GET", "/api/v1/trees") => (
+                200,
+                format!(r#"{{"hasMore":false,"references":[{{"type":"BRANCH","name":"main","hash":"{HASH}"}}]}}"#),
+            ),
+            ("GET", "/api/v1/trees/tree/main") => (
+                200,
+                format!(r#"{{"type":"BRANCH","name":"main","hash":"{HASH}"}}"#),
+            ),
+            ("GET", "/api/v1/trees/tree/main/entries") => (
+                200,
+                r#"{"hasMore":false,"entries":[{"type":"ICEBERG_TABLE","name":{"elements":["db","t"]}}]}"#
+                    .to_string(),
+            ),
+            ("POST", "/api/v1/trees/tree") => (
+                200,
+                format!(r#"{{"type":"BRANCH","name":"dev","hash":"{HASH}"}}"#),
+            ),
+            ("DELETE", "/api/v1/trees/branch/main") | ("PUT", "/api/v1/trees/branch/main") => {
+                (204, String::new())
+            }
+            ("POST", "/api/v1/trees/branch/main/commit") => (
+                200,
+                format!(r#"{{"type":"BRANCH","name":"main","hash":"{NEW_HASH}"}}"#),
+            ),
+            _ => (400, "{}".to_string()),
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_negotiation_falls_back_to_v1() {
+    let (base_url, _) = start_v1_stand_in().await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .build_negotiated()
+        .await
+        .expect("negotiation error");
+    assert_eq!(nc.api_version(), ApiVersion::V1);
+
+    let refs = nc.list_references().await.expect("references error");
+    assert_eq!(refs[0].name, "main");
+}
+
+#[tokio::test]
+async fn test_negotiation_needs_version_segment_to_fall_back() {
+    let (base_url, _) =
+        start_stand_in(|_| (404, r#"{"message":"Not found","status":404}"#.to_string())).await;
+
+    let result = NessieClient::builder(&format!("{base_url}/api/"))
+        .build_negotiated()
+        .await;
+    assert!(
+        matches!(result, Err(NessieError::InvalidConfiguration(ref message)) if message.contains("v1 or v2 segment")),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_unversioned_base_url_is_kept() {
+    let (base_url, requests) = start_v1_stand_in().await;
+    let nc = NessieClient::builder(&format!("{base_url}/nessie"))
+        .api_version(ApiVersion::V1)
+        .build()
+        .unwrap();
+    let _ = nc.list_references().await;
+    assert_eq!(requests.lock().unwrap()[0].path, "/nessie/trees");
+}
+
+#[tokio::test]
+async fn test_negotiation_prefers_v2() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            r#"{"defaultBranch":"main","minSupportedApiVersion":1,"maxSupportedApiVersion":2}"#
+                .to_string(),
+        )
+    })
+    .await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v1/"))
+        .build_negotiated()
+        .await
+        .expect("negotiation error");
+    assert_eq!(nc.api_version(), ApiVersion::V2);
+    assert_eq!(requests.lock().unwrap()[0].path, "/api/v2/config");
+}
+
+#[tokio::test]
+async fn test_v1_requests() {
+    let (base_url, requests) = start_v1_stand_in().await;
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .api_version(ApiVersion::V1)
+        .build()
+        .unwrap();
+
+    let main = nc
+        .get_reference(&RefSpec::named("main").unwrap())
+        .await
+        .expect("reference error")
+        .reference;
+    assert_eq!(main.hash.as_deref(), Some(HASH));
+
+    let entries = nc
+        .list_entries(&RefSpec::at("main", HASH).unwrap())
+        .await
+        .expect("entries error");
+    assert_eq!(entries, vec!["db.t"]);
+
+    let dev = nc
+        .create_reference("dev", ReferenceType::Branch, &RefSpec::from(&main))
+        .await
+        .expect("create error");
+    assert_eq!(dev.reference.name, "dev");
+
+    let assigned = nc
+        .assign_reference(
+            &RefSpec::at("main", HASH).unwrap(),
+            &RefSpec::detached(NEW_HASH).unwrap(),
+        )
+        .await
+        .expect("assign error");
+    assert_eq!(assigned.reference.hash.as_deref(), Some(NEW_HASH));
+
+    let committed = nc
+        .commit_entry(
+            &RefSpec::at("main", HASH).unwrap(),
+            Operations {
+                commit_meta: CommitMeta {
+                    authors: vec!["test_author".to_string(), "co_author".to_string()],
+                    author_time: Some("2025-01-06T10:00:00Z".parse().unwrap()),
+                    message: "empty commit".to_string(),
+                    all_signed_off_by: vec!["reviewer".to_string()],
+                    all_properties: [("job".to_string(), vec!["etl".to_string()])].into(),
+                    ..Default::default()
+                },
+                operations: vec![],
+            },
+        )
+        .await
+        .expect("commit error");
+    assert_eq!(committed.target_branch.hash.as_deref(), Some(NEW_HASH));
+    assert!(committed.added_contents.is_empty());
+
+    nc.delete_reference(&RefSpec::at("main", HASH).unwrap())
+        .await
+        .expect("delete error");
+
+    let requests = requests.lock().unwrap();
+    let calls: Vec<_> = requests
+        .iter()
+        .map(|r| format!("{} {}", r.method, r.path))
+        .collect();
+    assert_eq!(
+        calls,
+        vec![
+            "GET /api/v1/trees/tree/main".to_string(),
+            format!("GET /api/v1/trees/tree/main/entries?hashOnRef={HASH}"),
+            "POST /api/v1/trees/tree?sourceRefName=main".to_string(),
+            "GET /api/v1/trees/tree/main".to_string(),
+            format!("PUT /api/v1/trees/branch/main?expectedHash={HASH}"),
+            format!("POST /api/v1/trees/branch/main/commit?expectedHash={HASH}"),
+            "GET /api/v1/trees/tree/main".to_string(),
+            format!("DELETE /api/v1/trees/branch/main?expectedHash={HASH}"),
+        ]
+    );
+
+    // v1 only knows a single author, sign-off and property value.
+    let commit: serde_json::Value = serde_json::from_str(&requests[5].body).unwrap();
+    assert_eq!(
+        commit["commitMeta"],
+        serde_json::json!({
+            "author": "test_author",
+            "signedOffBy": "reviewer",
+            "message": "empty commit",
+            "authorTime": "2025-01-06T10:00:00Z",
+            "properties": {"job": "etl"}
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_v2_only_operations_are_rejected_on_v1() {
+    let nc = NessieClient::builder("http://localhost:19120/api/v1")
+        .build()
+        .unwrap();
+    assert_eq!(nc.api_version(), ApiVersion::V1);
+
+    let result = nc
+        .get_reference_history(&RefSpec::named("main").unwrap(), None)
+        .await;
+    assert!(matches!(result, Err(NessieError::Unsupported(_))));
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::{start_stand_in, Requests, REFERENCES_BODY};
+use nessie_client::{
+    auth::{BasicAuthentication, BearerAuthentication, OAuth2Authentication},
+    builder::NessieClientBuilder,
+    client::NessieClient,
+};
+
+/// Serves `/token` with a client-credentials response and every other path
+/// with a single-branch reference listing.
+async fn start_token_stand_in(expires_in: u64) -> (String, Requests) {
+    let issued = AtomicUsize::new(0);
+    start_stand_in(move |request| {
+        if request.path == "/token" {
+            let issued = issued.fetch_add(1, Ordering::SeqCst) + 1;
+            (
+                200,
+                format!(
+                    r#"{{"access_token":"token-{issued}","token_type":"bearer","expires_in":{expires_in}}}"#
+                ),
+            )
+        } else {
+            (200, REFERENCES_BODY.to_string())
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_bearer_authentication() {
+    let (base_url, requests) = start_token_stand_in(3600).await;
+
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/"))
+        .unwrap()
+        .with_authentication(BearerAuthentication::new("static-token"));
+
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer static-token")
+    );
+}
+
+#[tokio::test]
+async fn test_basic_authentication() {
+    let (base_url, requests) = start_token_stand_in(3600).await;
+
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/"))
+        .unwrap()
+        .with_authentication(BasicAuthentication::new("nessie", "secret"));
+
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    // base64("nessie:secret")
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Basic bmVzc2llOnNlY3JldA==")
+    );
+}
+
+#[tokio::test]
+async fn test_oauth2_token_is_cached() {
+    let (base_url, requests) = start_token_stand_in(3600).await;
+
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/"))
+        .unwrap()
+        .with_authentication(
+            OAuth2Authentication::new(format!("{base_url}/token"), "client", "secret")
+                .with_scope("catalog"),
+        );
+
+    nc.list_references().await.expect("references error");
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    let token_requests: Vec<_> = requests.iter().filter(|r| r.path == "/token").collect();
+    assert_eq!(token_requests.len(), 1);
+    assert_eq!(token_requests[0].method, "POST");
+    assert!(token_requests[0]
+        .body
+        .contains("grant_type=client_credentials"));
+    assert!(token_requests[0].body.contains("client_id=client"));
+    assert!(token_requests[0].body.contains("scope=catalog"));
+
+    let api_requests: Vec<_> = requests.iter().filter(|r| r.path != "/token").collect();
+    assert_eq!(api_requests.len(), 2);
+    for request in api_requests {
+        assert_eq!(request.header("authorization"), Some("Bearer token-1"));
+    }
+}
+
+#[tokio::test]
+async fn test_oauth2_token_is_refreshed_before_expiry() {
+    // Tokens expire within the default refresh margin, so each call refreshes.
+    let (base_url, requests) = start_token_stand_in(5).await;
+
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/"))
+        .unwrap()
+        .with_authentication(OAuth2Authentication::new(
+            format!("{base_url}/token"),
+            "client",
+            "secret",
+        ));
+
+    nc.list_references().await.expect("references error");
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    let authorizations: Vec<_> = requests
+        .iter()
+        .filter(|r| r.path != "/token")
+        .map(|r| r.header("authorization").unwrap())
+        .collect();
+    assert_eq!(authorizations, vec!["Bearer token-1", "Bearer token-2"]);
+}
+
+#[tokio::test]
+async fn test_oauth2_token_is_fetched_with_the_configured_client() {
+    let (base_url, requests) = start_token_stand_in(3600).await;
+
+    let nc = NessieClientBuilder::new(&format!("{base_url}/api/v2/"))
+        .user_agent("configured-client")
+        .authentication(OAuth2Authentication::new(
+            format!("{base_url}/token"),
+            "client",
+            "secret",
+        ))
+        .build()
+        .unwrap();
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    let token_request = requests.iter().find(|r| r.path == "/token").unwrap();
+    assert_eq!(
+        token_request.header("user-agent"),
+        Some("configured-client")
+    );
+}
+
+#[tokio::test]
+async fn test_debug_output_redacts_secrets() {
+    let (base_url, _requests) = start_token_stand_in(3600).await;
+    let oauth2 = OAuth2Authentication::new(format!("{base_url}/token"), "client", "oauth2-secret");
+    // Cache an access token, which must not show either.
+    oauth2.access_token().await.unwrap();
+
+    let outputs = [
+        format!("{:?}", BearerAuthentication::new("bearer-secret")),
+        format!("{:?}", BasicAuthentication::new("nessie", "basic-secret")),
+        format!("{oauth2:?}"),
+        format!(
+            "{:?}",
+            NessieClient::new(&format!("{base_url}/api/v2/"))
+                .unwrap()
+                .with_authentication(BearerAuthentication::new("bearer-secret"))
+        ),
+    ];
+    for output in outputs {
+        assert!(output.contains("<redacted>"), "{output}");
+        for secret in ["bearer-secret", "basic-secret", "oauth2-secret", "token-1"] {
+            assert!(!output.contains(secret), "{output}");
+        }
+    }
+}
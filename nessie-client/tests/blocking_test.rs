@@ -0,0 +1,69 @@
+mod common;
+
+use common::{start_stand_in, Requests, REFERENCES_BODY};
+use nessie_client::{blocking::BlockingNessieClient, client::NessieClient};
+use tokio::runtime::Runtime;
+
+const CONFIG_BODY: &str = r#"{"defaultBranch":"main","maxSupportedApiVersion":2}"#;
+
+/// Starts the stand-in on a runtime of its own, which keeps serving while the
+/// test thread blocks.
+fn start_server() -> (Runtime, String, Requests) {
+    let server = Runtime::new().unwrap();
+    let (base_url, requests) = server.block_on(start_stand_in(|request| {
+        if request.path.starts_with("/api/v2/config") {
+            (200, CONFIG_BODY.to_string())
+        } else {
+            (200, REFERENCES_BODY.to_string())
+        }
+    }));
+    (server, base_url, requests)
+}
+
+#[test]
+fn test_blocking_client_outside_of_a_runtime() {
+    let (_server, base_url, requests) = start_server();
+    let nc = BlockingNessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    assert_eq!(nc.default_branch().unwrap(), "main");
+    let references = nc.list_references().unwrap();
+    assert!(!references.is_empty());
+
+    let paths: Vec<_> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.path.clone())
+        .collect();
+    assert_eq!(paths, vec!["/api/v2/config", "/api/v2/trees"]);
+}
+
+#[test]
+fn test_blocking_client_inside_a_current_thread_runtime() {
+    let (_server, base_url, _) = start_server();
+    let caller = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    caller.block_on(async {
+        let nc = BlockingNessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+        assert_eq!(
+            nc.get_config().unwrap().default_branch.as_deref(),
+            Some("main")
+        );
+        // Dropping the client must not block the caller's runtime.
+        drop(nc);
+    });
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blocking_client_inside_a_multi_thread_runtime() {
+    let (base_url, _) = start_stand_in(|_| (200, REFERENCES_BODY.to_string())).await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .build_blocking()
+        .unwrap();
+    let references = nc.list_references().unwrap();
+    assert_eq!(references[0].name, "main");
+}
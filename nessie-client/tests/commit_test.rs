@@ -0,0 +1,162 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::NessieClient,
+    error::NessieError,
+    models::{CommitMeta, Content, ContentKey, IcebergTable, Operation, Operations},
+    ref_spec::RefSpec,
+};
+
+const HASH: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+const NEW_HASH: &str = "11223344556677889900aabbccddeeff11223344556677889900aabbccddeeff";
+
+fn create_table(name: &str) -> Operations {
+    Operations {
+        commit_meta: CommitMeta {
+            authors: vec!["test_author <author@example.com>".to_string()],
+            author_time: Some("2025-01-06T10:00:00Z".parse().unwrap()),
+            message: format!("Create table {name}"),
+            all_signed_off_by: vec!["reviewer <reviewer@example.com>".to_string()],
+            ..Default::default()
+        },
+        operations: vec![Operation::Put {
+            key: ContentKey {
+                elements: vec!["db".to_string(), name.to_string()],
+            },
+            content: Content::IcebergTable(IcebergTable {
+                id: None,
+                metadata_location: format!("file:///warehouse/db/{name}/metadata/00000.json"),
+                snapshot_id: Some(-1),
+                schema_id: Some(0),
+                spec_id: Some(0),
+                sort_order_id: Some(0),
+                metadata: None,
+            }),
+        }],
+    }
+}
+
+#[tokio::test]
+async fn test_commit_returns_new_head_and_added_content_ids() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            format!(
+                r#"{{"targetBranch":{{"type":"BRANCH","name":"main","hash":"{NEW_HASH}"}},"addedContents":[{{"key":{{"elements":["db","t"]}},"contentId":"6a5c4f1e-0d3b-4a8e-9f6a-2b1c0d9e8f7a"}}]}}"#
+            ),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let response = nc
+        .commit_entry(&RefSpec::at("main", HASH).unwrap(), create_table("t"))
+        .await
+        .expect("commit error");
+
+    assert_eq!(response.target_branch.name, "main");
+    assert_eq!(response.target_branch.hash.as_deref(), Some(NEW_HASH));
+    let key = ContentKey {
+        elements: vec!["db".to_string(), "t".to_string()],
+    };
+    assert_eq!(
+        response.added_content_id(&key),
+        Some("6a5c4f1e-0d3b-4a8e-9f6a-2b1c0d9e8f7a")
+    );
+    assert_eq!(
+        response.added_content_id(&ContentKey {
+            elements: vec!["db".to_string(), "other".to_string()],
+        }),
+        None
+    );
+
+    // The next commit can be chained on the returned head.
+    let next = RefSpec::from(&response.target_branch);
+    assert_eq!(next.to_string(), format!("main@{NEW_HASH}"));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].path,
+        format!("/api/v2/trees/main@{HASH}/history/commit")
+    );
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(
+        body["commitMeta"],
+        serde_json::json!({
+            "authors": ["test_author <author@example.com>"],
+            "authorTime": "2025-01-06T10:00:00Z",
+            "message": "Create table t",
+            "allSignedOffBy": ["reviewer <reviewer@example.com>"],
+            "allProperties": {},
+            "parentCommitHashes": []
+        })
+    );
+    assert_eq!(body["operations"][0]["type"], "PUT");
+    assert_eq!(body["operations"][0]["content"]["type"], "ICEBERG_TABLE");
+}
+
+#[tokio::test]
+async fn test_commit_without_added_contents() {
+    let (base_url, _) = start_stand_in(|_| {
+        (
+            200,
+            format!(r#"{{"targetBranch":{{"type":"BRANCH","name":"main","hash":"{NEW_HASH}"}}}}"#),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let response = nc
+        .commit_entry(&RefSpec::at("main", HASH).unwrap(), create_table("t"))
+        .await
+        .expect("commit error");
+    assert!(response.added_contents.is_empty());
+}
+
+#[tokio::test]
+async fn test_commit_on_stale_hash_is_a_conflict() {
+    let (base_url, _) = start_stand_in(|_| {
+        (
+            409,
+            r#"{"status":409,"reason":"Conflict","message":"Expected hash does not match"}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let error = nc
+        .commit_entry(&RefSpec::at("main", HASH).unwrap(), create_table("t"))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, NessieError::Conflict(_)));
+}
+
+#[test]
+fn test_commit_meta_reads_single_valued_fields() {
+    let meta: CommitMeta = serde_json::from_str(
+        r#"{"hash":"abcd","committer":"nessie","author":"ann","signedOffBy":"bob","message":"m","commitTime":"2025-01-06T10:00:00.123Z","properties":{"job":"etl"}}"#,
+    )
+    .unwrap();
+    assert_eq!(meta.authors, vec!["ann".to_string()]);
+    assert_eq!(meta.author(), Some("ann"));
+    assert_eq!(meta.all_signed_off_by, vec!["bob".to_string()]);
+    assert_eq!(meta.property("job"), Some("etl"));
+    assert_eq!(
+        meta.commit_time.map(|time| time.timestamp_millis()),
+        Some(1736157600123)
+    );
+
+    let meta: CommitMeta = serde_json::from_str(
+        r#"{"author":"ann","authors":["ann","carl"],"allSignedOffBy":[],"message":"m","properties":{},"allProperties":{"job":["etl","daily"]},"parentCommitHashes":["p1","p2"]}"#,
+    )
+    .unwrap();
+    assert_eq!(meta.authors, vec!["ann".to_string(), "carl".to_string()]);
+    assert_eq!(meta.property("job"), Some("etl"));
+    assert_eq!(
+        meta.parent_commit_hashes,
+        vec!["p1".to_string(), "p2".to_string()]
+    );
+}
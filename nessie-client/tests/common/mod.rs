@@ -0,0 +1,151 @@
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub type Requests = Arc<Mutex<Vec<RecordedRequest>>>;
+
+/// Starts a minimal HTTP server on a random local port. Each request is
+/// recorded and answered with the `(status, json body)` returned by `handler`.
+pub async fn start_stand_in<F>(handler: F) -> (String, Requests)
+where
+    F: Fn(&RecordedRequest) -> (u16, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let requests: Requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            let (status, body) = handler(&request);
+            recorded.lock().unwrap().push(request);
+
+            let response = format!(
+                "HTTP/1.1 {status} Stand-in\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    (format!("http://{address}"), requests)
+}
+
+async fn read_request(socket: &mut TcpStream) -> RecordedRequest {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap().split_whitespace();
+    let method = request_line.next().unwrap().to_string();
+    let path = request_line.next().unwrap().to_string();
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .map(|(_, value)| value.parse().unwrap())
+        .unwrap_or(0);
+
+    while buffer.len() < header_end + content_length {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let body =
+        String::from_utf8_lossy(&buffer[header_end..header_end + content_length]).to_string();
+
+    RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    }
+}
+
+/// A recorded request/response pair of `tests/fixtures/<name>.json`.
+pub struct Fixture {
+    pub name: String,
+    pub request: Value,
+    pub response: Value,
+    requests: Requests,
+}
+
+impl Fixture {
+    /// Serves the response of fixture `name` on a random local port and
+    /// returns the v2 API URL to point the client at.
+    pub async fn serve(name: &str) -> (String, Fixture) {
+        let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+        let recorded: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap())
+            .unwrap_or_else(|e| panic!("invalid fixture {path}: {e}"));
+        let response = recorded["response"].clone();
+        let status = response["status"].as_u64().unwrap() as u16;
+        let body = response["body"].to_string();
+
+        let (base_url, requests) = start_stand_in(move |_| (status, body.clone())).await;
+        let fixture = Fixture {
+            name: name.to_string(),
+            request: recorded["request"].clone(),
+            response,
+            requests,
+        };
+        (format!("{base_url}/api/v2/"), fixture)
+    }
+
+    /// Asserts that the client sent exactly the recorded request: method,
+    /// path with query and, if the fixture has one, the JSON body.
+    pub fn assert_request(&self) {
+        let requests = self.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1, "{}: expected one request", self.name);
+        let sent = &requests[0];
+        assert_eq!(sent.method, self.request["method"], "{}: method", self.name);
+        assert_eq!(sent.path, self.request["path"], "{}: path", self.name);
+        if let Some(expected) = self.request.get("body") {
+            let body: Value = serde_json::from_str(&sent.body)
+                .unwrap_or_else(|e| panic!("{}: body is not JSON: {e}", self.name));
+            assert_eq!(&body, expected, "{}: body", self.name);
+        }
+    }
+
+    /// The recorded response body.
+    pub fn body(&self) -> &Value {
+        &self.response["body"]
+    }
+}
+
+pub const REFERENCES_BODY: &str = r#"{"token":null,"hasMore":false,"references":[{"type":"BRANCH","name":"main","hash":"2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d"}]}"#;
@@ -0,0 +1,109 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::NessieClient,
+    models::{
+        GarbageCollectorConfig, ReferenceCutoffPolicy, RepositoryConfig, RepositoryConfigType,
+    },
+};
+
+const CONFIG_BODY: &str = r#"{
+  "defaultBranch": "trunk",
+  "minSupportedApiVersion": 1,
+  "maxSupportedApiVersion": 2,
+  "actualApiVersion": 2,
+  "specVersion": "2.1.0",
+  "noAncestorHash": "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d",
+  "repositoryCreationTimestamp": "2025-01-06T10:00:00Z",
+  "oldestPossibleCommitTimestamp": "2025-01-06T10:00:00Z",
+  "additionalProperties": {}
+}"#;
+
+#[tokio::test]
+async fn test_get_config() {
+    let (base_url, requests) = start_stand_in(|_| (200, CONFIG_BODY.to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let config = nc.get_config().await.expect("config error");
+    assert_eq!(config.default_branch.as_deref(), Some("trunk"));
+    assert_eq!(config.min_supported_api_version, Some(1));
+    assert_eq!(config.max_supported_api_version, Some(2));
+    assert_eq!(config.actual_api_version, Some(2));
+    assert_eq!(config.spec_version.as_deref(), Some("2.1.0"));
+    assert_eq!(
+        config.no_ancestor_hash.as_deref(),
+        Some("2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d")
+    );
+
+    assert_eq!(requests.lock().unwrap()[0].path, "/api/v2/config");
+}
+
+#[tokio::test]
+async fn test_default_branch_is_detected_once() {
+    let (base_url, requests) = start_stand_in(|_| (200, CONFIG_BODY.to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    assert_eq!(nc.default_branch().await.unwrap(), "trunk");
+    assert_eq!(nc.default_branch().await.unwrap(), "trunk");
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_repository_config() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            r#"{"configs":[{"type":"GARBAGE_COLLECTOR","defaultCutoffPolicy":"P30D","perRefCutoffPolicies":[{"referenceNamePattern":"release/.*","policy":"NONE"}],"newFilesGracePeriod":"P1D","expectedFileCountPerContent":100}]}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let configs = nc
+        .get_repository_config(&[RepositoryConfigType::GarbageCollector])
+        .await
+        .expect("repository config error");
+
+    assert_eq!(
+        configs,
+        vec![RepositoryConfig::GarbageCollector(GarbageCollectorConfig {
+            default_cutoff_policy: Some("P30D".to_string()),
+            per_ref_cutoff_policies: vec![ReferenceCutoffPolicy {
+                reference_name_pattern: "release/.*".to_string(),
+                policy: "NONE".to_string(),
+            }],
+            new_files_grace_period: Some("P1D".to_string()),
+            expected_file_count_per_content: Some(100),
+        })]
+    );
+    assert_eq!(
+        requests.lock().unwrap()[0].path,
+        "/api/v2/config/repository?type=GARBAGE_COLLECTOR"
+    );
+}
+
+#[tokio::test]
+async fn test_update_repository_config() {
+    let (base_url, requests) = start_stand_in(|_| (200, r#"{"previous":null}"#.to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let previous = nc
+        .update_repository_config(RepositoryConfig::GarbageCollector(GarbageCollectorConfig {
+            default_cutoff_policy: Some("10".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .expect("repository config error");
+    assert!(previous.is_none());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/api/v2/config/repository");
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"config": {"type": "GARBAGE_COLLECTOR", "defaultCutoffPolicy": "10"}})
+    );
+}
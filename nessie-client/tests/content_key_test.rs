@@ -0,0 +1,168 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::NessieClient,
+    error::NessieError,
+    generated::models::Namespace,
+    generated::operations::GetContentV2,
+    models::{CommitMeta, Content, ContentKey, IcebergTable, Operation, Operations},
+    ref_spec::RefSpec,
+    test_server::NessieTestServer,
+};
+
+fn table(metadata_location: &str) -> Content {
+    Content::IcebergTable(IcebergTable {
+        id: None,
+        metadata_location: metadata_location.to_string(),
+        snapshot_id: Some(-1),
+        schema_id: Some(0),
+        spec_id: Some(0),
+        sort_order_id: Some(0),
+        metadata: None,
+    })
+}
+
+#[test]
+fn test_path_string_escapes_dots() {
+    let key = ContentKey::new(["sales.eu", "orders.v2"]);
+
+    assert_eq!(key.to_path_string(), "sales\u{1D}eu.orders\u{1D}v2");
+    assert_eq!(key.to_string(), key.to_path_string());
+    assert_eq!(
+        ContentKey::from_path_string(&key.to_path_string()).unwrap(),
+        key
+    );
+    assert_eq!(key.to_string().parse::<ContentKey>().unwrap(), key);
+}
+
+#[test]
+fn test_path_string_round_trips_awkward_names() {
+    for elements in [
+        vec!["db", "events"],
+        vec!["a/b", "c d", "100%"],
+        vec!["données", "日本語", "🦀"],
+        vec!["q?x=1&y#z", "..", "+"],
+    ] {
+        let key = ContentKey::new(elements.clone());
+        let parsed: ContentKey = key.to_path_string().parse().unwrap();
+        assert_eq!(parsed.elements, elements);
+    }
+}
+
+#[test]
+fn test_invalid_keys() {
+    for path in ["", "db..events", "db.", ".events", "nul\0byte"] {
+        assert!(
+            matches!(
+                ContentKey::from_path_string(path),
+                Err(NessieError::InvalidKey(_))
+            ),
+            "{path:?}"
+        );
+    }
+    assert!(ContentKey::new(Vec::<String>::new()).validate().is_err());
+}
+
+#[test]
+fn test_parent_and_name() {
+    let key = ContentKey::new(["db", "sales.v2"]);
+
+    assert_eq!(key.name(), "sales.v2");
+    assert_eq!(key.parent(), Some(ContentKey::new(["db"])));
+    assert_eq!(ContentKey::new(["db"]).parent(), None);
+    assert!(key.starts_with(&ContentKey::new(["db"])));
+    assert!(!ContentKey::new(["dbx"]).starts_with(&ContentKey::new(["db"])));
+}
+
+#[tokio::test]
+async fn test_key_path_segments_are_url_encoded() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            r#"{"content":{"type":"ICEBERG_TABLE","metadataLocation":"s3://w/t.json"},"effectiveReference":{"type":"BRANCH","name":"main","hash":"2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d"}}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    nc.execute(&GetContentV2 {
+        key: ContentKey::new(["a/b", "sales.v2", "日本"]).to_path_string(),
+        r#ref: "main".to_string(),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].path,
+        "/api/v2/trees/main/contents/a%2Fb.sales%1Dv2.%E6%97%A5%E6%9C%AC"
+    );
+}
+
+#[tokio::test]
+async fn test_awkward_keys_survive_a_round_trip() {
+    let server = NessieTestServer::start().await.unwrap();
+    let nc = NessieClient::new(&server.base_url()).unwrap();
+    let main = nc.list_references().await.unwrap().remove(0);
+
+    let namespace = ContentKey::new(["sales.eu"]);
+    let tables = [
+        ContentKey::new(["sales.eu", "orders.v2"]),
+        ContentKey::new(["sales.eu", "a/b c"]),
+        ContentKey::new(["sales.eu", "données 日本"]),
+    ];
+    let mut operations = vec![Operation::Put {
+        key: namespace.clone(),
+        content: Content::Namespace(Namespace {
+            id: None,
+            elements: namespace.elements.clone(),
+            properties: Default::default(),
+        }),
+    }];
+    for (i, key) in tables.iter().enumerate() {
+        operations.push(Operation::Put {
+            key: key.clone(),
+            content: table(&format!("s3://warehouse/{i}.metadata.json")),
+        });
+    }
+    nc.commit_entry(
+        &RefSpec::from(&main),
+        Operations {
+            commit_meta: CommitMeta {
+                message: "awkward names".to_string(),
+                ..Default::default()
+            },
+            operations,
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut listed: Vec<ContentKey> = nc
+        .list_entries(&RefSpec::named("main").unwrap())
+        .await
+        .unwrap()
+        .iter()
+        .map(|entry| entry.parse().unwrap())
+        .collect();
+    listed.sort_by(|a, b| a.elements.cmp(&b.elements));
+    let mut expected = vec![namespace];
+    expected.extend(tables.iter().cloned());
+    expected.sort_by(|a, b| a.elements.cmp(&b.elements));
+    assert_eq!(listed, expected);
+
+    let content = nc
+        .execute(&GetContentV2 {
+            key: tables[1].to_path_string(),
+            r#ref: "main".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(serde_json::to_string(&content.content)
+        .unwrap()
+        .contains("s3://warehouse/1.metadata.json"));
+}
@@ -0,0 +1,425 @@
+//! Contract tests of every `NessieClient` method against the recorded
+//! request/response pairs of `tests/fixtures`, which follow `swagger.yml`.
+
+mod common;
+
+use common::Fixture;
+use nessie_client::{
+    client::NessieClient,
+    error::NessieError,
+    generated::models::Merge1,
+    generated::operations::{GetCommitLogV2, MergeV2},
+    models::{
+        CommitConsistency, CommitMeta, Content, ContentKey, FetchOption, GarbageCollectorConfig,
+        IcebergTable, ListReferencesParams, Operation, Operations, ReferenceType, RepositoryConfig,
+        RepositoryConfigType,
+    },
+    ref_spec::RefSpec,
+};
+
+const MAIN_HASH: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+const DEV_HASH: &str = "11223344556677889900aabbccddeeff11223344556677889900aabbccddeeff";
+const NEW_HASH: &str = "aabbccddeeff00112233445566778899aabbccddeeff00112233445566778899";
+
+async fn client_for(name: &str) -> (NessieClient, Fixture) {
+    let (base_url, fixture) = Fixture::serve(name).await;
+    (NessieClient::new(&base_url).unwrap(), fixture)
+}
+
+fn branch(name: &str, hash: &str) -> RefSpec {
+    RefSpec::at(name, hash).unwrap()
+}
+
+fn key(elements: &[&str]) -> ContentKey {
+    ContentKey {
+        elements: elements.iter().map(|e| e.to_string()).collect(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_config() {
+    let (nc, fixture) = client_for("get_config").await;
+
+    let config = nc.get_config().await.unwrap();
+
+    fixture.assert_request();
+    assert_eq!(config.default_branch.as_deref(), Some("main"));
+    assert_eq!(config.min_supported_api_version, Some(1));
+    assert_eq!(config.max_supported_api_version, Some(2));
+    assert_eq!(config.actual_api_version, Some(2));
+    assert_eq!(config.spec_version.as_deref(), Some("2.2.0"));
+    assert_eq!(config.no_ancestor_hash.as_deref(), Some(MAIN_HASH));
+    assert_eq!(
+        config.additional_properties.get("nessie.version"),
+        Some(&"0.99.0".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_get_repository_config() {
+    let (nc, fixture) = client_for("get_repository_config").await;
+
+    let configs = nc
+        .get_repository_config(&[RepositoryConfigType::GarbageCollector])
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    let [RepositoryConfig::GarbageCollector(gc)] = configs.as_slice() else {
+        panic!("unexpected configs {configs:?}");
+    };
+    assert_eq!(gc.default_cutoff_policy.as_deref(), Some("P30D"));
+    assert_eq!(gc.per_ref_cutoff_policies.len(), 1);
+    assert_eq!(
+        gc.per_ref_cutoff_policies[0].reference_name_pattern,
+        "release-.*"
+    );
+    assert_eq!(gc.per_ref_cutoff_policies[0].policy, "NONE");
+    assert_eq!(gc.new_files_grace_period.as_deref(), Some("P1D"));
+    assert_eq!(gc.expected_file_count_per_content, Some(1000));
+}
+
+#[tokio::test]
+async fn test_update_repository_config() {
+    let (nc, fixture) = client_for("update_repository_config").await;
+
+    let previous = nc
+        .update_repository_config(RepositoryConfig::GarbageCollector(GarbageCollectorConfig {
+            default_cutoff_policy: Some("10".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(
+        previous,
+        Some(RepositoryConfig::GarbageCollector(GarbageCollectorConfig {
+            default_cutoff_policy: Some("P30D".to_string()),
+            ..Default::default()
+        }))
+    );
+}
+
+#[tokio::test]
+async fn test_list_references_pages() {
+    let (nc, fixture) = client_for("list_references_page").await;
+
+    let page = nc
+        .list_references_with(&ListReferencesParams {
+            fetch: Some(FetchOption::All),
+            max_records: Some(1),
+            page_token: Some("cmVmczox".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert!(page.has_more);
+    assert_eq!(page.token.as_deref(), Some("cmVmczoy"));
+    assert_eq!(page.references.len(), 1);
+    let metadata = page.references[0].metadata.as_ref().unwrap();
+    assert_eq!(metadata.num_total_commits, Some(42));
+    assert_eq!(metadata.num_commits_ahead, None);
+    let head = metadata.commit_meta_of_head.as_ref().unwrap();
+    assert_eq!(head.author(), Some("etl <etl@example.com>"));
+    assert_eq!(head.property("job"), Some("etl"));
+
+    let (nc, fixture) = client_for("list_references_last_page").await;
+
+    let page = nc
+        .list_references_with(&ListReferencesParams {
+            max_records: Some(1),
+            page_token: page.token,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert!(!page.has_more);
+    assert_eq!(page.token, None);
+    assert_eq!(page.references[0].name, "dev");
+    assert!(page.references[0].metadata.is_none());
+}
+
+#[tokio::test]
+async fn test_get_reference() {
+    let (nc, fixture) = client_for("get_reference").await;
+
+    let response = nc
+        .get_reference_with(&RefSpec::named("main").unwrap(), FetchOption::All)
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.reference.name, "dev");
+    assert_eq!(response.reference.hash.as_deref(), Some(DEV_HASH));
+    let metadata = response.reference.metadata.unwrap();
+    assert_eq!(metadata.num_commits_ahead, Some(2));
+    assert_eq!(metadata.num_commits_behind, Some(1));
+    assert_eq!(metadata.common_ancestor_hash.as_deref(), Some(MAIN_HASH));
+    let head = metadata.commit_meta_of_head.unwrap();
+    assert_eq!(head.hash.as_deref(), Some(MAIN_HASH));
+    assert_eq!(head.committer.as_deref(), Some("nessie"));
+    assert_eq!(
+        head.commit_time,
+        Some("2025-01-06T10:00:00.123Z".parse().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_get_reference_not_found() {
+    let (nc, fixture) = client_for("get_reference_not_found").await;
+
+    let result = nc.get_reference(&RefSpec::named("missing").unwrap()).await;
+
+    fixture.assert_request();
+    let Err(NessieError::NotFound(body)) = result else {
+        panic!("expected NotFound, got {result:?}");
+    };
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(&body, fixture.body());
+    assert_eq!(body["errorCode"], "REFERENCE_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_create_reference() {
+    let (nc, fixture) = client_for("create_reference").await;
+
+    let response = nc
+        .create_reference("dev", ReferenceType::Branch, &branch("main", MAIN_HASH))
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.reference.name, "dev");
+    assert!(matches!(response.reference.ref_type, ReferenceType::Branch));
+    assert_eq!(response.reference.hash.as_deref(), Some(MAIN_HASH));
+}
+
+#[tokio::test]
+async fn test_create_reference_exists() {
+    let (nc, fixture) = client_for("create_reference_exists").await;
+
+    let result = nc
+        .create_reference("dev", ReferenceType::Branch, &branch("main", MAIN_HASH))
+        .await;
+
+    fixture.assert_request();
+    assert!(
+        matches!(result, Err(NessieError::Conflict(body)) if body.contains("REFERENCE_ALREADY_EXISTS"))
+    );
+}
+
+#[tokio::test]
+async fn test_delete_reference() {
+    let (nc, fixture) = client_for("delete_reference").await;
+
+    let response = nc
+        .delete_reference(&RefSpec::at("dev", DEV_HASH).unwrap())
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.reference.name, "dev");
+    assert_eq!(response.reference.hash.as_deref(), Some(DEV_HASH));
+}
+
+#[tokio::test]
+async fn test_assign_reference() {
+    let (nc, fixture) = client_for("assign_reference").await;
+
+    let response = nc
+        .assign_reference(
+            &RefSpec::at("dev", DEV_HASH).unwrap(),
+            &branch("main", MAIN_HASH),
+        )
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.reference.hash.as_deref(), Some(MAIN_HASH));
+}
+
+#[tokio::test]
+async fn test_get_reference_history() {
+    let (nc, fixture) = client_for("get_reference_history").await;
+
+    let history = nc
+        .get_reference_history(&RefSpec::named("main").unwrap(), Some(10))
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(history.reference.name, "main");
+    assert_eq!(history.current.commit_hash, MAIN_HASH);
+    assert_eq!(
+        history.current.commit_consistency,
+        CommitConsistency::CommitConsistent
+    );
+    assert!(history.current.meta.is_some());
+    assert_eq!(history.previous.len(), 1);
+    assert_eq!(history.previous[0].commit_hash, DEV_HASH);
+    assert!(history.previous[0].meta.is_none());
+    assert_eq!(
+        history.commit_log_consistency,
+        CommitConsistency::CommitConsistent
+    );
+}
+
+#[tokio::test]
+async fn test_list_entries() {
+    let (nc, fixture) = client_for("list_entries").await;
+
+    let entries = nc
+        .list_entries(&RefSpec::named("main").unwrap())
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(entries, vec!["db", "db.events"]);
+}
+
+#[tokio::test]
+async fn test_commit() {
+    let (nc, fixture) = client_for("commit").await;
+    let operations = Operations {
+        commit_meta: CommitMeta {
+            authors: vec!["etl <etl@example.com>".to_string()],
+            author_time: Some("2025-01-06T10:00:00Z".parse().unwrap()),
+            message: "Create table db.events".to_string(),
+            all_properties: [("job".to_string(), vec!["etl".to_string()])].into(),
+            ..Default::default()
+        },
+        operations: vec![
+            Operation::Put {
+                key: key(&["db", "events"]),
+                content: Content::IcebergTable(IcebergTable {
+                    id: None,
+                    metadata_location: "s3://warehouse/db/events/metadata/00000.metadata.json"
+                        .to_string(),
+                    snapshot_id: Some(-1),
+                    schema_id: Some(0),
+                    spec_id: Some(0),
+                    sort_order_id: Some(0),
+                    metadata: None,
+                }),
+            },
+            Operation::Delete {
+                key: key(&["db", "old_events"]),
+            },
+        ],
+    };
+
+    let response = nc
+        .commit_entry(&RefSpec::at("main", MAIN_HASH).unwrap(), operations)
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.target_branch.hash.as_deref(), Some(NEW_HASH));
+    assert_eq!(
+        response.added_content_id(&key(&["db", "events"])),
+        Some("6a5c4f1e-0d3b-4a8e-9f6a-2b1c0d9e8f7a")
+    );
+}
+
+#[tokio::test]
+async fn test_commit_conflict() {
+    let (nc, fixture) = client_for("commit_conflict").await;
+
+    let result = nc
+        .commit_entry(
+            &RefSpec::at("main", MAIN_HASH).unwrap(),
+            Operations {
+                commit_meta: CommitMeta::default(),
+                operations: vec![Operation::Delete {
+                    key: key(&["db", "events"]),
+                }],
+            },
+        )
+        .await;
+
+    fixture.assert_request();
+    let Err(NessieError::Conflict(body)) = result else {
+        panic!("expected Conflict, got {result:?}");
+    };
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let conflicts = &body["errorDetails"]["conflicts"];
+    assert_eq!(conflicts[0]["conflictType"], "KEY_EXISTS");
+    assert_eq!(conflicts[0]["key"]["elements"][1], "events");
+}
+
+#[tokio::test]
+async fn test_commit_log_page() {
+    let (nc, fixture) = client_for("commit_log").await;
+
+    let log = nc
+        .execute(&GetCommitLogV2 {
+            r#ref: "main".to_string(),
+            max_records: Some(1),
+            page_token: Some("bG9nOjE".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(log.has_more, Some(true));
+    assert_eq!(log.token.as_deref(), Some("bG9nOjI"));
+    assert_eq!(log.log_entries.len(), 1);
+    assert_eq!(log.log_entries[0].commit_meta.message, "Load events");
+    assert_eq!(
+        log.log_entries[0].parent_commit_hash.as_deref(),
+        Some(DEV_HASH)
+    );
+}
+
+#[tokio::test]
+async fn test_merge() {
+    let (nc, fixture) = client_for("merge").await;
+
+    let response = nc
+        .execute(&MergeV2 {
+            branch: format!("main@{MAIN_HASH}"),
+            body: Merge1 {
+                from_ref_name: "dev".to_string(),
+                from_hash: Some(DEV_HASH.to_string()),
+                message: Some("Merge dev".to_string()),
+                dry_run: Some(false),
+                ..Default::default()
+            },
+        })
+        .await
+        .unwrap();
+
+    fixture.assert_request();
+    assert_eq!(response.resultant_target_hash.as_deref(), Some(NEW_HASH));
+    assert_eq!(response.common_ancestor.as_deref(), Some(MAIN_HASH));
+    assert_eq!(response.target_branch.as_deref(), Some("main"));
+    let details = response.details.unwrap();
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].key.as_ref().unwrap().elements, ["db", "events"]);
+    assert!(details[0].conflict.is_none());
+}
+
+#[tokio::test]
+async fn test_bad_request() {
+    let (nc, fixture) = client_for("invalid_request").await;
+
+    let result = nc
+        .list_references_with(&ListReferencesParams {
+            max_records: Some(-1),
+            ..Default::default()
+        })
+        .await;
+
+    fixture.assert_request();
+    let Err(NessieError::InvalidResponse(message)) = result else {
+        panic!("expected InvalidResponse, got {result:?}");
+    };
+    assert!(message.starts_with("HTTP 400"), "{message}");
+    assert!(message.contains("must be greater than or equal to 0"));
+}
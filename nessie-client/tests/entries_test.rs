@@ -0,0 +1,258 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::{ApiVersion, NessieClient},
+    error::NessieError,
+    generated::models::Namespace,
+    models::{CommitMeta, Content, ContentKey, EntriesParams, IcebergTable, Operation, Operations},
+    ref_spec::RefSpec,
+    test_server::NessieTestServer,
+};
+
+fn namespace(elements: &[&str]) -> Operation {
+    Operation::Put {
+        key: ContentKey::new(elements.iter().copied()),
+        content: Content::Namespace(Namespace {
+            id: None,
+            elements: elements.iter().map(|e| e.to_string()).collect(),
+            properties: HashMap::new(),
+        }),
+    }
+}
+
+fn table(elements: &[&str]) -> Operation {
+    Operation::Put {
+        key: ContentKey::new(elements.iter().copied()),
+        content: Content::IcebergTable(IcebergTable {
+            id: None,
+            metadata_location: format!("s3://warehouse/{}.metadata.json", elements.join("/")),
+            snapshot_id: Some(-1),
+            schema_id: Some(0),
+            spec_id: Some(0),
+            sort_order_id: Some(0),
+            metadata: None,
+        }),
+    }
+}
+
+/// A server whose `main` holds namespaces `db`, `db.sales` and `other` with a
+/// few tables.
+async fn populated() -> (NessieTestServer, NessieClient) {
+    let server = NessieTestServer::start().await.unwrap();
+    let nc = NessieClient::new(&server.base_url()).unwrap();
+    let main = nc.list_references().await.unwrap().remove(0);
+    nc.commit_entry(
+        &RefSpec::from(&main),
+        Operations {
+            commit_meta: CommitMeta {
+                message: "populate".to_string(),
+                ..Default::default()
+            },
+            operations: vec![
+                namespace(&["db"]),
+                namespace(&["db", "sales"]),
+                namespace(&["other"]),
+                table(&["db", "events"]),
+                table(&["db", "users"]),
+                table(&["db", "sales", "orders"]),
+                table(&["other", "events"]),
+            ],
+        },
+    )
+    .await
+    .unwrap();
+    (server, nc)
+}
+
+fn names(entries: &[nessie_client::models::Entry]) -> Vec<String> {
+    let mut names: Vec<String> = entries.iter().map(|e| e.name.to_string()).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_cel_filter() {
+    assert_eq!(EntriesParams::default().cel_filter(), None);
+    assert_eq!(
+        EntriesParams::children_of(Some(&ContentKey::new(["db"])))
+            .of_type("ICEBERG_TABLE")
+            .cel_filter()
+            .as_deref(),
+        Some("entry.contentType == 'ICEBERG_TABLE' && size(entry.keyElements) == 2")
+    );
+    assert_eq!(
+        EntriesParams {
+            filter: Some("entry.namespace == 'db'".to_string()),
+            content_types: vec!["ICEBERG_TABLE".to_string(), "ICEBERG_VIEW".to_string()],
+            min_depth: Some(2),
+            ..Default::default()
+        }
+        .cel_filter()
+        .as_deref(),
+        Some(
+            "(entry.namespace == 'db') && entry.contentType in ['ICEBERG_TABLE', 'ICEBERG_VIEW'] && size(entry.keyElements) >= 2"
+        )
+    );
+    assert_eq!(
+        EntriesParams::default()
+            .of_type(r"IT'S\")
+            .cel_filter()
+            .as_deref(),
+        Some(r"entry.contentType == 'IT\'S\\'")
+    );
+}
+
+#[tokio::test]
+async fn test_entries_query_parameters() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            r#"{"hasMore":false,"token":null,"entries":[]}"#.to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    nc.list_entries_with(
+        &RefSpec::named("main").unwrap(),
+        &EntriesParams {
+            keys: vec![ContentKey::new(["db", "a.b"])],
+            min_key: Some(ContentKey::new(["db", "a"])),
+            max_key: Some(ContentKey::new(["db", "z"])),
+            prefix_key: Some(ContentKey::new(["db"])),
+            with_content: true,
+            max_records: Some(10),
+            page_token: Some("abc".to_string()),
+            ..EntriesParams::default().of_type("ICEBERG_TABLE")
+        },
+    )
+    .await
+    .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].path,
+        "/api/v2/trees/main/entries?content=true\
+         &filter=entry.contentType+%3D%3D+%27ICEBERG_TABLE%27\
+         &key=db.a%1Db&max-key=db.z&max-records=10&min-key=db.a&page-token=abc&prefix-key=db"
+    );
+}
+
+#[tokio::test]
+async fn test_children_of_namespace() {
+    let (_server, nc) = populated().await;
+    let main = RefSpec::named("main").unwrap();
+    let db = ContentKey::new(["db"]);
+
+    let tables = nc
+        .list_all_entries(
+            &main,
+            &EntriesParams::children_of(Some(&db)).of_type("ICEBERG_TABLE"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(names(&tables), vec!["db.events", "db.users"]);
+    assert!(tables.iter().all(|entry| entry.content_id.is_some()));
+    assert!(tables.iter().all(|entry| entry.content.is_none()));
+
+    let namespaces = nc
+        .list_all_entries(
+            &main,
+            &EntriesParams::children_of(None).of_type("NAMESPACE"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(names(&namespaces), vec!["db", "other"]);
+
+    let below_db = nc
+        .list_all_entries(
+            &main,
+            &EntriesParams {
+                prefix_key: Some(db),
+                min_depth: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        names(&below_db),
+        vec!["db.events", "db.sales", "db.sales.orders", "db.users"]
+    );
+}
+
+#[tokio::test]
+async fn test_key_range_and_contents() {
+    let (_server, nc) = populated().await;
+    let main = RefSpec::named("main").unwrap();
+
+    let range = nc
+        .list_all_entries(
+            &main,
+            &EntriesParams {
+                min_key: Some(ContentKey::new(["db", "f"])),
+                max_key: Some(ContentKey::new(["db", "t"])),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(names(&range), vec!["db.sales", "db.sales.orders"]);
+
+    let keyed = nc
+        .list_all_entries(
+            &main,
+            &EntriesParams {
+                keys: vec![ContentKey::new(["other", "events"])],
+                with_content: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(keyed.len(), 1);
+    let Some(Content::IcebergTable(table)) = &keyed[0].content else {
+        panic!("expected the table content, got {:?}", keyed[0].content);
+    };
+    assert_eq!(
+        table.metadata_location,
+        "s3://warehouse/other/events.metadata.json"
+    );
+}
+
+#[tokio::test]
+async fn test_pages_are_followed() {
+    let (_server, nc) = populated().await;
+    let main = RefSpec::named("main").unwrap();
+    let params = EntriesParams {
+        max_records: Some(2),
+        ..Default::default()
+    };
+
+    let page = nc.list_entries_with(&main, &params).await.unwrap();
+    assert_eq!(page.entries.len(), 2);
+    assert!(page.has_more);
+    assert!(page.token.is_some());
+
+    let all = nc.list_all_entries(&main, &params).await.unwrap();
+    assert_eq!(all.len(), 7);
+}
+
+#[tokio::test]
+async fn test_v1_rejects_key_conditions() {
+    let nc = NessieClient::builder("http://localhost:19120/api/v1/")
+        .api_version(ApiVersion::V1)
+        .build()
+        .unwrap();
+
+    let result = nc
+        .list_entries_with(
+            &RefSpec::named("main").unwrap(),
+            &EntriesParams::children_of(Some(&ContentKey::new(["db"]))),
+        )
+        .await;
+    assert!(matches!(result, Err(NessieError::Unsupported(_))));
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::NessieClient,
+    error::NessieError,
+    generated::{
+        models::{Content, IcebergTable},
+        operations::{GetCommitLogV2, GetConfig},
+    },
+};
+
+#[tokio::test]
+async fn test_execute_sends_path_and_query() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            r#"{"hasMore":false,"logEntries":[{"commitMeta":{"hash":"abcd","committer":"nessie","authors":["ann"],"allSignedOffBy":[],"message":"first","commitTime":"2025-01-06T10:00:00Z","authorTime":"2025-01-06T10:00:00Z","allProperties":{},"parentCommitHashes":["0000"]},"parentCommitHash":"0000"}]}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let log = nc
+        .execute(&GetCommitLogV2 {
+            r#ref: "main".to_string(),
+            max_records: Some(10),
+            ..Default::default()
+        })
+        .await
+        .expect("log error");
+    assert_eq!(log.log_entries.len(), 1);
+    let meta = &log.log_entries[0].commit_meta;
+    assert_eq!(meta.message, "first");
+    assert_eq!(meta.authors, vec!["ann".to_string()]);
+    assert_eq!(
+        meta.commit_time.map(|time| time.to_rfc3339()).as_deref(),
+        Some("2025-01-06T10:00:00+00:00")
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(
+        requests[0].path,
+        "/api/v2/trees/main/history?max-records=10"
+    );
+}
+
+#[tokio::test]
+async fn test_execute_rejects_operation_of_other_api_version() {
+    let (base_url, requests) = start_stand_in(|_| (200, "{}".to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let error = nc.execute(&GetConfig).await.unwrap_err();
+    assert!(matches!(error, NessieError::Unsupported(_)));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_content_follows_the_spec() {
+    let content: Content = serde_json::from_str(
+        r#"{"type":"ICEBERG_TABLE","metadataLocation":"s3://bucket/t/metadata.json","snapshotId":1,"schemaId":2,"specId":3,"sortOrderId":4}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        content,
+        Content::IcebergTable(IcebergTable {
+            id: None,
+            metadata_location: "s3://bucket/t/metadata.json".to_string(),
+            snapshot_id: Some(1),
+            schema_id: Some(2),
+            spec_id: Some(3),
+            sort_order_id: Some(4),
+            metadata: None,
+        })
+    );
+
+    let namespace: Content =
+        serde_json::from_str(r#"{"type":"NAMESPACE","elements":["db"]}"#).unwrap();
+    assert!(matches!(namespace, Content::Namespace(_)));
+
+    let unknown: Content = serde_json::from_str(r#"{"type":"SOMETHING_NEW"}"#).unwrap();
+    assert_eq!(unknown, Content::Unknown);
+}
@@ -1,23 +1,73 @@
-use testcontainers::{core::WaitFor, runners::AsyncRunner, GenericImage, ImageExt};
+use std::collections::HashMap;
 
 use nessie_client::{
     client::NessieClient,
-    models::{CommitMeta, Content, ContentKey, Operation, Operations},
+    error::NessieError,
+    generated::models::{Merge1, Namespace},
+    generated::operations::{GetCommitLogV2, GetDiffV2, MergeV2},
+    models::{
+        CommitMeta, CommitResponse, Content, ContentKey, IcebergTable, Operation, Operations,
+        Reference, ReferenceType,
+    },
+    ref_spec::RefSpec,
+    test_server::NessieTestServer,
 };
 
-#[tokio::test]
-async fn test_nessie_references() {
-    let host_port = 29120;
-    let _container = GenericImage::new("ghcr.io/projectnessie/nessie", "latest")
-        .with_wait_for(WaitFor::message_on_stdout("Installed features: [agroal, amazon-sdk-dynamodb, amazon-sdk-secretsmanager, azure-keyvault-secret, cassandra-client, cdi, google-cloud-bigtable, google-cloud-secret-manager, hibernate-validator, jdbc-h2, jdbc-mariadb, jdbc-postgresql, logging-sentry, micrometer, mongodb-client, narayana-jta, oidc, opentelemetry, reactive-routes, rest, rest-jackson, security, security-properties-file, smallrye-context-propagation, smallrye-health, smallrye-openapi, vault, vertx]"))
-        .with_mapped_port(host_port, testcontainers::core::ContainerPort::Tcp(19120))
-        .start()
+async fn start() -> (NessieTestServer, NessieClient) {
+    let server = NessieTestServer::start()
         .await
-        .unwrap();
+        .expect("error in starting the test server");
+    let nc = NessieClient::new(&server.base_url()).expect("error in creating the nessie client");
+    (server, nc)
+}
+
+fn key(elements: &[&str]) -> ContentKey {
+    ContentKey {
+        elements: elements.iter().map(|e| e.to_string()).collect(),
+    }
+}
+
+fn table(metadata_location: &str) -> Content {
+    Content::IcebergTable(IcebergTable {
+        id: None,
+        metadata_location: metadata_location.to_string(),
+        snapshot_id: Some(1),
+        schema_id: Some(0),
+        spec_id: Some(0),
+        sort_order_id: Some(0),
+        metadata: None,
+    })
+}
+
+fn namespace(elements: &[&str]) -> Content {
+    Content::Namespace(Namespace {
+        id: None,
+        elements: elements.iter().map(|e| e.to_string()).collect(),
+        properties: HashMap::new(),
+    })
+}
 
-    let base_url = format!("http://localhost:{host_port}/api/v2/");
+async fn commit(
+    nc: &NessieClient,
+    head: &Reference,
+    message: &str,
+    operations: Vec<Operation>,
+) -> Result<CommitResponse, NessieError> {
+    let operations = Operations {
+        commit_meta: CommitMeta {
+            authors: vec!["test_author".to_string()],
+            author_time: Some(chrono::prelude::Utc::now()),
+            message: message.to_string(),
+            ..Default::default()
+        },
+        operations,
+    };
+    nc.commit_entry(&RefSpec::from(head), operations).await
+}
 
-    let nc = NessieClient::new(base_url.as_str()).expect("error in creating the nessie client");
+#[tokio::test]
+async fn test_nessie_references() {
+    let (_server, nc) = start().await;
 
     let refs = nc.list_references().await.expect("references error");
     assert_eq!(refs.len(), 1);
@@ -25,42 +75,36 @@ async fn test_nessie_references() {
     let ref_name = refs[0].name.clone();
 
     let ref_main = nc
-        .get_reference(ref_name.clone())
+        .get_reference(&RefSpec::named(&ref_name).unwrap())
         .await
         .expect("reference error");
 
     assert_eq!(ref_main.reference.name, ref_name);
 
     let new_ref_response = nc
-        .create_reference("test".to_string(), "BRANCH".to_string(), refs[0].clone())
+        .create_reference("test", ReferenceType::Branch, &RefSpec::from(&refs[0]))
         .await
         .expect("error in post reference");
+    assert_eq!(new_ref_response.reference.hash, refs[0].hash);
 
     let refs = nc.list_references().await.expect("references error");
     assert_eq!(refs.len(), 2);
 
     let _ = nc
-        .delete_reference(new_ref_response.reference)
+        .delete_reference(&RefSpec::from(&new_ref_response.reference))
         .await
         .expect("error deleting");
 
     let refs = nc.list_references().await.expect("references error");
     assert_eq!(refs.len(), 1);
+
+    let missing = nc.get_reference(&RefSpec::named("test").unwrap()).await;
+    assert!(matches!(missing, Err(NessieError::NotFound(_))));
 }
 
 #[tokio::test]
 async fn test_nessie_entries() {
-    let host_port = 29121;
-    let _container = GenericImage::new("ghcr.io/projectnessie/nessie", "latest")
-        .with_wait_for(WaitFor::message_on_stdout("Installed features: [agroal, amazon-sdk-dynamodb, amazon-sdk-secretsmanager, azure-keyvault-secret, cassandra-client, cdi, google-cloud-bigtable, google-cloud-secret-manager, hibernate-validator, jdbc-h2, jdbc-mariadb, jdbc-postgresql, logging-sentry, micrometer, mongodb-client, narayana-jta, oidc, opentelemetry, reactive-routes, rest, rest-jackson, security, security-properties-file, smallrye-context-propagation, smallrye-health, smallrye-openapi, vault, vertx]"))
-        .with_mapped_port(host_port, testcontainers::core::ContainerPort::Tcp(19120))
-        .start()
-        .await
-        .unwrap();
-
-    let base_url = format!("http://localhost:{host_port}/api/v2/");
-
-    let nc = NessieClient::new(base_url.as_str()).expect("error in creating the nessie client");
+    let (_server, nc) = start().await;
 
     let refs = nc
         .list_references()
@@ -68,35 +112,220 @@ async fn test_nessie_entries() {
         .expect("error in listing references");
 
     let main_entries = nc
-        .list_entries(refs[0].clone())
+        .list_entries(&RefSpec::from(&refs[0]))
         .await
         .expect("error in listing main entries");
 
     assert_eq!(main_entries.len(), 0);
 
-    let operations = Operations {
-        commit_meta: CommitMeta {
-            author: "test_author".to_string(),
-            author_time: chrono::prelude::Utc::now().to_rfc3339(),
-            message: "test commit message".to_string(),
-            signed_off_by: None,
-            properties: todo!(),
-        },
-        operations: vec![Operation::Put {
-            key: ContentKey { elements: todo!() },
-            content: Content {
-                content_type: "ICEBERG_TABLE".to_string(),
-                metadata_location: todo!(),
-                snapshot_id: todo!(),
-                schema_id: todo!(),
-                spec_id: todo!(),
-                sort_order_id: todo!(),
+    let new_entry_response = commit(
+        &nc,
+        &refs[0],
+        "test commit message",
+        vec![
+            Operation::Put {
+                key: key(&["db"]),
+                content: namespace(&["db"]),
             },
+            Operation::Put {
+                key: key(&["db", "events"]),
+                content: table("s3://warehouse/db/events/metadata/00000.metadata.json"),
+            },
+        ],
+    )
+    .await
+    .expect("error in commiting entry");
+
+    assert_ne!(new_entry_response.target_branch.hash, refs[0].hash);
+    assert!(new_entry_response
+        .added_content_id(&key(&["db", "events"]))
+        .is_some());
+
+    let mut main_entries = nc
+        .list_entries(&RefSpec::named("main").unwrap())
+        .await
+        .expect("error in listing main entries");
+    main_entries.sort();
+    assert_eq!(main_entries, vec!["db", "db.events"]);
+
+    let log = nc
+        .execute(&GetCommitLogV2 {
+            r#ref: "main".to_string(),
+            ..Default::default()
+        })
+        .await
+        .expect("error in reading the commit log");
+    assert_eq!(log.log_entries.len(), 1);
+    assert_eq!(
+        log.log_entries[0].commit_meta.message,
+        "test commit message"
+    );
+}
+
+#[tokio::test]
+async fn test_nessie_conflicts() {
+    let (_server, nc) = start().await;
+    let main = nc.list_references().await.unwrap().remove(0);
+
+    // The namespace of a table must exist.
+    let absent = commit(
+        &nc,
+        &main,
+        "table without namespace",
+        vec![Operation::Put {
+            key: key(&["db", "events"]),
+            content: table("s3://warehouse/db/events/v1.metadata.json"),
+        }],
+    )
+    .await;
+    assert!(matches!(absent, Err(NessieError::Conflict(_))));
+
+    let created = commit(
+        &nc,
+        &main,
+        "create table",
+        vec![Operation::Put {
+            key: key(&["events"]),
+            content: table("s3://warehouse/events/v1.metadata.json"),
+        }],
+    )
+    .await
+    .unwrap();
+
+    // Committing on the old head re-creates a key that now exists.
+    let stale = commit(
+        &nc,
+        &main,
+        "create table again",
+        vec![Operation::Put {
+            key: key(&["events"]),
+            content: table("s3://warehouse/events/v1.metadata.json"),
+        }],
+    )
+    .await;
+    assert!(matches!(stale, Err(NessieError::Conflict(_))));
+
+    let dropped = commit(
+        &nc,
+        &created.target_branch,
+        "drop table",
+        vec![Operation::Delete {
+            key: key(&["events"]),
+        }],
+    )
+    .await
+    .unwrap();
+    let missing = commit(
+        &nc,
+        &dropped.target_branch,
+        "drop table again",
+        vec![Operation::Delete {
+            key: key(&["events"]),
+        }],
+    )
+    .await;
+    assert!(matches!(missing, Err(NessieError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_nessie_merge_and_diff() {
+    let (_server, nc) = start().await;
+    let main = nc.list_references().await.unwrap().remove(0);
+    let dev = nc
+        .create_reference("dev", ReferenceType::Branch, &RefSpec::from(&main))
+        .await
+        .unwrap()
+        .reference;
+
+    let committed = commit(
+        &nc,
+        &dev,
+        "create table on dev",
+        vec![Operation::Put {
+            key: key(&["events"]),
+            content: table("s3://warehouse/events/v1.metadata.json"),
+        }],
+    )
+    .await
+    .unwrap();
+
+    let diff = nc
+        .execute(&GetDiffV2 {
+            from_ref: "main".to_string(),
+            to_ref: "dev".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let diffs = diff.diffs.unwrap_or_default();
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].key.as_ref().unwrap().elements, vec!["events"]);
+    assert!(diffs[0].from.is_none());
+    assert!(diffs[0].to.is_some());
+
+    let merged = nc
+        .execute(&MergeV2 {
+            branch: format!("main@{}", main.hash.as_deref().unwrap()),
+            body: Merge1 {
+                from_ref_name: "dev".to_string(),
+                from_hash: committed.target_branch.hash.clone(),
+                ..Default::default()
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(merged.common_ancestor, main.hash);
+    assert_ne!(merged.resultant_target_hash, main.hash);
+
+    let main_entries = nc
+        .list_entries(&RefSpec::named("main").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(main_entries, vec!["events"]);
+}
+
+#[tokio::test]
+async fn test_nessie_rename_keeps_content_id() {
+    let (_server, nc) = start().await;
+    let main = nc.list_references().await.unwrap().remove(0);
+    let created = commit(
+        &nc,
+        &main,
+        "create table",
+        vec![Operation::Put {
+            key: key(&["events"]),
+            content: table("s3://warehouse/events/v1.metadata.json"),
         }],
+    )
+    .await
+    .unwrap();
+    let content_id = created.added_content_id(&key(&["events"])).unwrap();
+
+    let Content::IcebergTable(mut renamed) = table("s3://warehouse/events/v1.metadata.json") else {
+        unreachable!()
     };
+    renamed.id = Some(content_id.to_string());
+    let response = commit(
+        &nc,
+        &created.target_branch,
+        "rename table",
+        vec![
+            Operation::Delete {
+                key: key(&["events"]),
+            },
+            Operation::Put {
+                key: key(&["events_v2"]),
+                content: Content::IcebergTable(renamed),
+            },
+        ],
+    )
+    .await
+    .unwrap();
 
-    let new_entry_response = nc
-        .commit_entry(refs[0].clone(), operations)
+    assert!(response.added_contents.is_empty());
+    let main_entries = nc
+        .list_entries(&RefSpec::named("main").unwrap())
         .await
-        .expect("error in commiting entry");
+        .unwrap();
+    assert_eq!(main_entries, vec!["events_v2"]);
 }
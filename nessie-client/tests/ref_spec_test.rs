@@ -0,0 +1,114 @@
+use chrono::{TimeZone, Utc};
+
+use nessie_client::ref_spec::{RefSpec, RelativeCommitSpec};
+
+const HASH: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+
+#[test]
+fn test_parse_name() {
+    let spec: RefSpec = "main".parse().unwrap();
+    assert_eq!(spec.name(), Some("main"));
+    assert_eq!(spec.hash(), None);
+    assert!(spec.relative().is_empty());
+    assert!(!spec.is_detached());
+}
+
+#[test]
+fn test_parse_name_with_hash() {
+    let spec: RefSpec = format!("release/2025.01@{HASH}").parse().unwrap();
+    assert_eq!(spec.name(), Some("release/2025.01"));
+    assert_eq!(spec.hash(), Some(HASH));
+}
+
+#[test]
+fn test_parse_detached() {
+    let spec: RefSpec = "@2e1cfa82".parse().unwrap();
+    assert!(spec.is_detached());
+    assert_eq!(spec.hash(), Some("2e1cfa82"));
+    assert!(spec.require_name("commit").is_err());
+}
+
+#[test]
+fn test_parse_default_branch() {
+    let spec: RefSpec = "-".parse().unwrap();
+    assert_eq!(spec, RefSpec::default_branch());
+}
+
+#[test]
+fn test_parse_relative() {
+    let spec: RefSpec = "main~2^2*1736157600000".parse().unwrap();
+    assert_eq!(
+        spec.relative(),
+        &[
+            RelativeCommitSpec::Ancestor(2),
+            RelativeCommitSpec::Parent(2),
+            RelativeCommitSpec::Timestamp(Utc.with_ymd_and_hms(2025, 1, 6, 10, 0, 0).unwrap()),
+        ]
+    );
+
+    let spec: RefSpec = "main*2025-01-06T10:00:00.5Z".parse().unwrap();
+    assert_eq!(
+        spec.relative(),
+        &[RelativeCommitSpec::Timestamp(
+            Utc.timestamp_millis_opt(1736157600500).unwrap()
+        )]
+    );
+}
+
+#[test]
+fn test_format_round_trip() {
+    for text in [
+        "main".to_string(),
+        "-".to_string(),
+        format!("main@{HASH}"),
+        format!("@{HASH}~1"),
+        "dev~3^2".to_string(),
+        "main*2025-01-06T10:00:00.000Z".to_string(),
+        "main*2025-01-06T10:00:00.123456789Z".to_string(),
+    ] {
+        let spec: RefSpec = text.parse().unwrap();
+        assert_eq!(spec.to_string(), text);
+    }
+}
+
+#[test]
+fn test_builders() {
+    let spec = RefSpec::at("main", HASH).unwrap().ancestor(1);
+    assert_eq!(spec.to_string(), format!("main@{HASH}~1"));
+
+    let spec = RefSpec::named("main")
+        .unwrap()
+        .as_of(Utc.with_ymd_and_hms(2025, 1, 6, 10, 0, 0).unwrap());
+    assert_eq!(spec.to_string(), "main*2025-01-06T10:00:00.000Z");
+}
+
+#[test]
+fn test_invalid_specs() {
+    for text in [
+        "",
+        "~1",
+        "1main",
+        "main.",
+        "ma..in",
+        "main@xyz12345",
+        "main@1234",
+        "main@",
+        "main@~1",
+        "main~x",
+        "main^",
+        "main*yesterday",
+        "main*2025-01-06T10:00:00Z",
+        "main branch",
+    ] {
+        assert!(text.parse::<RefSpec>().is_err(), "{text} should not parse");
+    }
+}
+
+#[test]
+fn test_require_hash() {
+    let spec = RefSpec::named("main").unwrap();
+    assert!(spec.require_hash("delete_reference").is_err());
+
+    let spec = RefSpec::at("main", HASH).unwrap();
+    assert_eq!(spec.require_hash("delete_reference").unwrap(), HASH);
+}
@@ -0,0 +1,260 @@
+mod common;
+
+use common::start_stand_in;
+use nessie_client::{
+    client::NessieClient,
+    error::NessieError,
+    models::{CommitConsistency, FetchOption, ListReferencesParams, ReferenceType},
+    ref_spec::RefSpec,
+};
+
+const MAIN_HASH: &str = "2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d";
+const GOOD_HASH: &str = "11223344556677889900aabbccddeeff11223344556677889900aabbccddeeff";
+
+#[tokio::test]
+async fn test_assign_reference() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            format!(r#"{{"reference":{{"type":"BRANCH","name":"main","hash":"{GOOD_HASH}"}}}}"#),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let response = nc
+        .assign_reference(
+            &RefSpec::at("main", MAIN_HASH).unwrap(),
+            &RefSpec::detached(GOOD_HASH).unwrap(),
+        )
+        .await
+        .expect("error assigning reference");
+    assert_eq!(response.reference.hash.as_deref(), Some(GOOD_HASH));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, format!("/api/v2/trees/main@{MAIN_HASH}"));
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(body["type"], "DETACHED");
+    assert_eq!(body["hash"], GOOD_HASH);
+}
+
+#[tokio::test]
+async fn test_assign_reference_conflict() {
+    let (base_url, _) = start_stand_in(|_| {
+        (
+            409,
+            r#"{"message":"Expected hash does not match","status":409}"#.to_string(),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let result = nc
+        .assign_reference(
+            &RefSpec::at("release", MAIN_HASH).unwrap(),
+            &RefSpec::at("main", GOOD_HASH).unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(NessieError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_assign_reference_requires_expected_hash() {
+    let nc = NessieClient::new("http://localhost:19120/api/v2/").unwrap();
+
+    let result = nc
+        .assign_reference(
+            &RefSpec::named("main").unwrap(),
+            &RefSpec::at("dev", GOOD_HASH).unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(NessieError::InvalidReference(_))));
+}
+
+#[tokio::test]
+async fn test_create_reference_requires_source_hash() {
+    let nc = NessieClient::new("http://localhost:19120/api/v2/").unwrap();
+
+    let result = nc
+        .create_reference(
+            "dev",
+            ReferenceType::Branch,
+            &RefSpec::named("main").unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(NessieError::InvalidReference(_))));
+}
+
+const REFERENCES_WITH_METADATA: &str = r#"{
+  "token": "next-page",
+  "hasMore": true,
+  "references": [
+    {
+      "type": "BRANCH",
+      "name": "dev",
+      "hash": "da086850076827d2989c8ee1d7fd22f152f525d46a0441f2b22ad8119c0bbbe5",
+      "metadata": {
+        "numCommitsAhead": 1,
+        "numCommitsBehind": 2,
+        "commonAncestorHash": "1122334455667788112233445566778811223344556677881122334455667788",
+        "numTotalCommits": 42,
+        "commitMetaOfHEAD": {
+          "hash": "da086850076827d2989c8ee1d7fd22f152f525d46a0441f2b22ad8119c0bbbe5",
+          "committer": "",
+          "author": "nessie-author",
+          "signedOffBy": null,
+          "message": "update table X",
+          "commitTime": "2021-11-26T08:01:13.855974Z",
+          "authorTime": "2021-11-26T08:01:13.852826Z",
+          "properties": {}
+        }
+      }
+    },
+    {
+      "type": "TAG",
+      "name": "release",
+      "hash": "84c57a20c5e956af4af40f3cc34ecc8a9028b9586da135c79011b1867aa3191d",
+      "metadata": { "numTotalCommits": 40 }
+    }
+  ]
+}"#;
+
+#[tokio::test]
+async fn test_list_references_with_metadata() {
+    let (base_url, requests) =
+        start_stand_in(|_| (200, REFERENCES_WITH_METADATA.to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let response = nc
+        .list_references_with(&ListReferencesParams {
+            fetch: Some(FetchOption::All),
+            filter: Some("refType == 'BRANCH'".to_string()),
+            max_records: Some(10),
+            page_token: None,
+        })
+        .await
+        .expect("references error");
+
+    assert!(response.has_more);
+    assert_eq!(response.token.as_deref(), Some("next-page"));
+
+    let dev = response.references[0].metadata.as_ref().unwrap();
+    assert_eq!(dev.num_commits_ahead, Some(1));
+    assert_eq!(dev.num_commits_behind, Some(2));
+    assert_eq!(dev.num_total_commits, Some(42));
+    assert_eq!(
+        dev.common_ancestor_hash.as_deref(),
+        Some("1122334455667788112233445566778811223344556677881122334455667788")
+    );
+    let head = dev.commit_meta_of_head.as_ref().unwrap();
+    assert_eq!(head.author(), Some("nessie-author"));
+    assert_eq!(head.message, "update table X");
+
+    let release = response.references[1].metadata.as_ref().unwrap();
+    assert_eq!(release.num_total_commits, Some(40));
+    assert!(release.commit_meta_of_head.is_none());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].path,
+        "/api/v2/trees?fetch=ALL&filter=refType+%3D%3D+%27BRANCH%27&max-records=10"
+    );
+}
+
+#[tokio::test]
+async fn test_get_reference_with_metadata() {
+    let (base_url, requests) = start_stand_in(|_| {
+        (
+            200,
+            format!(
+                r#"{{"reference":{{"type":"BRANCH","name":"main","hash":"{MAIN_HASH}","metadata":{{"numCommitsAhead":0,"numCommitsBehind":0,"numTotalCommits":7}}}}}}"#
+            ),
+        )
+    })
+    .await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let response = nc
+        .get_reference_with(&RefSpec::named("main").unwrap(), FetchOption::All)
+        .await
+        .expect("reference error");
+    let metadata = response.reference.metadata.unwrap();
+    assert_eq!(metadata.num_total_commits, Some(7));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].path, "/api/v2/trees/main?fetch=ALL");
+}
+
+const REFERENCE_HISTORY: &str = r#"{
+  "reference": {
+    "type": "BRANCH",
+    "hash": "abcdef4242424242424242424242beef00dead42112233445566778899001122",
+    "name": "main"
+  },
+  "current": {
+    "commitHash": "abcdef4242424242424242424242beef00dead42112233445566778899001122",
+    "commitConsistency": "COMMIT_CONSISTENT",
+    "meta": {
+      "hash": "abcdef4242424242424242424242beef00dead42112233445566778899001122",
+      "committer": "",
+      "author": "nessie-author",
+      "message": "update table",
+      "commitTime": "2021-11-26T08:01:13.855974Z",
+      "authorTime": "2021-11-26T08:01:13.852826Z",
+      "properties": {}
+    }
+  },
+  "previous": [
+    {
+      "pointer": "84c57a20c5e956af4af40f3cc34ecc8a9028b9586da135c79011b1867aa3191d",
+      "commitConsistency": "COMMIT_CONTENT_INCONSISTENT"
+    }
+  ],
+  "commitLogConsistency": "NOT_CHECKED"
+}"#;
+
+#[tokio::test]
+async fn test_get_reference_history() {
+    let (base_url, requests) = start_stand_in(|_| (200, REFERENCE_HISTORY.to_string())).await;
+    let nc = NessieClient::new(&format!("{base_url}/api/v2/")).unwrap();
+
+    let history = nc
+        .get_reference_history(&RefSpec::named("main").unwrap(), Some(20))
+        .await
+        .expect("reference history error");
+
+    assert_eq!(history.reference.name, "main");
+    assert_eq!(
+        history.current.commit_hash,
+        "abcdef4242424242424242424242beef00dead42112233445566778899001122"
+    );
+    assert_eq!(
+        history.current.commit_consistency,
+        CommitConsistency::CommitConsistent
+    );
+    assert_eq!(
+        history.current.meta.as_ref().unwrap().author(),
+        Some("nessie-author")
+    );
+    assert_eq!(history.previous.len(), 1);
+    assert_eq!(
+        history.previous[0].commit_hash,
+        "84c57a20c5e956af4af40f3cc34ecc8a9028b9586da135c79011b1867aa3191d"
+    );
+    assert_eq!(
+        history.previous[0].commit_consistency,
+        CommitConsistency::CommitContentInconsistent
+    );
+    assert!(history.previous[0].meta.is_none());
+    assert_eq!(
+        history.commit_log_consistency,
+        CommitConsistency::NotChecked
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].path,
+        "/api/v2/trees/main/recent-changes?scan-commits=20"
+    );
+}
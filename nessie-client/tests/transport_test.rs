@@ -0,0 +1,113 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use common::{start_stand_in, REFERENCES_BODY};
+use nessie_client::{
+    builder::RetryPolicy, client::NessieClient, models::ReferenceType, ref_spec::RefSpec,
+};
+
+fn fast_retries(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        multiplier: 2.0,
+    }
+}
+
+#[tokio::test]
+async fn test_idempotent_request_is_retried_on_server_error() {
+    let calls = AtomicUsize::new(0);
+    let (base_url, requests) = start_stand_in(move |_| {
+        if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+            (503, "{}".to_string())
+        } else {
+            (200, REFERENCES_BODY.to_string())
+        }
+    })
+    .await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .retry_policy(fast_retries(3))
+        .build()
+        .unwrap();
+
+    let refs = nc.list_references().await.expect("references error");
+    assert_eq!(refs.len(), 1);
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_retries_are_bounded() {
+    let (base_url, requests) = start_stand_in(|_| (503, "{}".to_string())).await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .retry_policy(fast_retries(2))
+        .build()
+        .unwrap();
+
+    assert!(nc.list_references().await.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_non_idempotent_request_is_not_retried() {
+    let (base_url, requests) = start_stand_in(|_| (503, "{}".to_string())).await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .retry_policy(fast_retries(3))
+        .build()
+        .unwrap();
+
+    let reference: RefSpec =
+        "main@2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d"
+            .parse()
+            .unwrap();
+    assert!(nc
+        .create_reference("dev", ReferenceType::Branch, &reference)
+        .await
+        .is_err());
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_user_agent_and_default_headers() {
+    let (base_url, requests) = start_stand_in(|_| (200, REFERENCES_BODY.to_string())).await;
+
+    let nc = NessieClient::builder(&format!("{base_url}/api/v2/"))
+        .user_agent("etl-loader/1.2")
+        .default_header("X-Team", "data-platform")
+        .connect_timeout(Duration::from_secs(1))
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    nc.list_references().await.expect("references error");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].header("user-agent"), Some("etl-loader/1.2"));
+    assert_eq!(requests[0].header("x-team"), Some("data-platform"));
+}
+
+#[test]
+fn test_invalid_default_header_is_rejected() {
+    let result = NessieClient::builder("http://localhost:19120/api/v2/")
+        .default_header("bad header", "value")
+        .build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_backoff_is_capped() {
+    let policy = RetryPolicy {
+        max_retries: 10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        multiplier: 2.0,
+    };
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(400));
+    assert_eq!(policy.backoff(5), Duration::from_millis(500));
+}
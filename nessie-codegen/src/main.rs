@@ -0,0 +1,808 @@
+//! Generates `nessie_client::generated` from the Nessie OpenAPI specification.
+//!
+//! ```text
+//! cargo run -p nessie-codegen [-- <swagger.yml> <output directory>]
+//! ```
+//!
+//! Every schema of `components/schemas` becomes a type of `models.rs` and
+//! every operation of `paths` a type of `operations.rs` implementing
+//! `nessie_client::generated::Operation`. Inline schemas are mapped to the
+//! component schema they are identical to, or get a type of their own.
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+use serde_yaml::{Mapping, Value};
+
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+const METHODS: [&str; 6] = ["get", "put", "post", "delete", "head", "patch"];
+const DOC_WIDTH: usize = 80;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut args = env::args().skip(1);
+    let spec_path = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("swagger.yml"));
+    let out_dir = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("nessie-client/src/generated"));
+
+    let spec: Value = serde_yaml::from_str(&fs::read_to_string(&spec_path)?)?;
+    let version = spec["info"]["version"].as_str().unwrap_or("unknown");
+    let header = format!(
+        "// Generated by nessie-codegen from swagger.yml (Nessie API {}).\n\
+         // Do not edit by hand: run `cargo run -p nessie-codegen` instead.\n",
+        version
+    );
+
+    let schemas = spec["components"]["schemas"]
+        .as_mapping()
+        .cloned()
+        .ok_or("the specification has no components/schemas")?;
+    let mut generator = Generator::new(schemas);
+
+    let operations = generator.operations(
+        spec["paths"]
+            .as_mapping()
+            .ok_or("the specification has no paths")?,
+    );
+    let models = generator.models();
+
+    fs::create_dir_all(&out_dir)?;
+    let models_path = out_dir.join("models.rs");
+    let operations_path = out_dir.join("operations.rs");
+    fs::write(&models_path, format!("{}\n{}", header, models))?;
+    fs::write(&operations_path, format!("{}\n{}", header, operations))?;
+
+    // Best effort: the output is valid without formatting.
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(&models_path)
+        .arg(&operations_path)
+        .status();
+    if !matches!(formatted, Ok(status) if status.success()) {
+        eprintln!("warning: could not run rustfmt on the generated files");
+    }
+    Ok(())
+}
+
+struct Generator {
+    schemas: Mapping,
+    /// Inline schemas that still need a type of their own.
+    pending: Vec<(String, Value)>,
+    /// Names of the types emitted or queued so far.
+    named: BTreeSet<String>,
+    uses_hash_map: bool,
+    uses_date_time: bool,
+}
+
+impl Generator {
+    fn new(schemas: Mapping) -> Self {
+        let named = schemas
+            .keys()
+            .filter_map(Value::as_str)
+            .map(type_name)
+            .collect();
+        Self {
+            schemas,
+            pending: Vec::new(),
+            named,
+            uses_hash_map: false,
+            uses_date_time: false,
+        }
+    }
+
+    /// Renders every component schema and every inline schema met so far.
+    fn models(&mut self) -> String {
+        let mut body = String::new();
+        for (name, schema) in self.schemas.clone() {
+            let name = name.as_str().expect("schema names are strings");
+            let suffix = version_suffix_of_schema(name);
+            body.push_str(&self.schema_type(&type_name(name), &schema, suffix));
+        }
+        while !self.pending.is_empty() {
+            let (name, schema) = self.pending.remove(0);
+            body.push_str(&self.schema_type(&name, &schema, ""));
+        }
+
+        let mut out = String::from(
+            "#![allow(clippy::large_enum_variant, clippy::upper_case_acronyms)]\n\n",
+        );
+        if self.uses_hash_map {
+            out.push_str("use std::collections::HashMap;\n");
+        }
+        out.push_str("use std::fmt;\n\n");
+        if self.uses_date_time {
+            out.push_str("use chrono::{DateTime, Utc};\n");
+        }
+        out.push_str("use serde::{Deserialize, Serialize};\n");
+        out.push_str(&body);
+        out
+    }
+
+    fn operations(&mut self, paths: &Mapping) -> String {
+        let mut out = String::from(
+            "use reqwest::Method;\n\n\
+             use super::models::*;\n\
+             use super::{encode_path_segment, Operation};\n\
+             use crate::client::ApiVersion;\n",
+        );
+        for (path, item) in paths {
+            let path = path.as_str().expect("paths are strings");
+            for method in METHODS {
+                if let Some(operation) = item.get(method) {
+                    out.push_str(&self.operation(path, method, operation));
+                }
+            }
+        }
+        out
+    }
+
+    fn operation(&mut self, path: &str, method: &str, operation: &Value) -> String {
+        let (api_version, relative_path) = if let Some(rest) = path.strip_prefix("/v1/") {
+            ("V1", rest)
+        } else if let Some(rest) = path.strip_prefix("/v2/") {
+            ("V2", rest)
+        } else {
+            panic!("{} is not below /v1 or /v2", path);
+        };
+        let suffix = if api_version == "V1" { "_V1" } else { "_V2" };
+        let name = match operation["operationId"].as_str() {
+            Some(id) => pascal_case(id),
+            None => pascal_case(&format!("{} {}", method, path)),
+        };
+
+        let mut fields = String::new();
+        let mut path_args = Vec::new();
+        let mut required_query = Vec::new();
+        let mut optional_query = String::new();
+        let mut defaultable = true;
+
+        let parameters = operation["parameters"]
+            .as_sequence()
+            .cloned()
+            .unwrap_or_default();
+        for parameter in &parameters {
+            let param_name = parameter["name"].as_str().expect("parameters have a name");
+            let location = parameter["in"].as_str().unwrap_or("query");
+            let field = field_name(param_name);
+            let context = format!("{}{}", name, pascal_case(param_name));
+            let schema = &parameter["schema"];
+            // Parameters travel as strings; object schemas (such as the
+            // namespace of the v1 namespace endpoints) are passed in their
+            // textual form.
+            let referenced = schema["$ref"]
+                .as_str()
+                .and_then(|reference| reference.strip_prefix(SCHEMA_PREFIX))
+                .map(|name| self.schemas[name].clone());
+            let value_type = if referenced.as_ref().is_some_and(is_object) {
+                "String".to_string()
+            } else if schema.get("$ref").is_some() || schema.get("type").is_some() {
+                self.type_of(schema, &context, suffix)
+            } else {
+                "String".to_string()
+            };
+            let required = parameter["required"].as_bool().unwrap_or(false);
+
+            fields.push_str(&doc_comment(&description_of(parameter), 4));
+            match location {
+                "path" => {
+                    defaultable &= is_defaultable(&value_type);
+                    let _ = writeln!(fields, "    pub {}: {},", field, value_type);
+                    path_args.push((param_name.to_string(), field));
+                }
+                "query" if value_type.starts_with("Vec<") => {
+                    let _ = writeln!(fields, "    pub {}: {},", field, value_type);
+                    let _ = writeln!(
+                        optional_query,
+                        "        for value in &self.{} {{\n            \
+                         query.push((\"{}\", value.to_string()));\n        }}",
+                        field, param_name
+                    );
+                }
+                "query" if required => {
+                    defaultable &= is_defaultable(&value_type);
+                    let _ = writeln!(fields, "    pub {}: {},", field, value_type);
+                    required_query.push(format!(
+                        "(\"{}\", self.{}.to_string())",
+                        param_name, field
+                    ));
+                }
+                "query" => {
+                    let _ = writeln!(fields, "    pub {}: Option<{}>,", field, value_type);
+                    let _ = writeln!(
+                        optional_query,
+                        "        if let Some(value) = &self.{} {{\n            \
+                         query.push((\"{}\", value.to_string()));\n        }}",
+                        field, param_name
+                    );
+                }
+                other => panic!("unsupported parameter location {} in {}", other, name),
+            }
+        }
+
+        let body_schema = &operation["requestBody"]["content"]["application/json"]["schema"];
+        let body_type = if body_schema.is_null() {
+            None
+        } else {
+            let body_type = self.type_of(body_schema, &format!("{}Body", name), suffix);
+            let required = operation["requestBody"]["required"]
+                .as_bool()
+                .unwrap_or(false);
+            fields.push_str(&doc_comment(
+                &description_of(&operation["requestBody"]),
+                4,
+            ));
+            if required {
+                defaultable = false;
+                let _ = writeln!(fields, "    pub body: {},", body_type);
+            } else {
+                let _ = writeln!(fields, "    pub body: Option<{}>,", body_type);
+            }
+            Some((body_type, required))
+        };
+
+        let response_type = match response_schema(operation) {
+            Some(schema) => self.type_of(&schema, &format!("{}Response", name), suffix),
+            None => "()".to_string(),
+        };
+
+        let mut out = String::new();
+        let summary = operation["summary"].as_str().unwrap_or_default();
+        let _ = writeln!(out, "\n/// `{} {}`", method.to_uppercase(), path);
+        if !summary.is_empty() {
+            out.push_str("///\n");
+            out.push_str(&doc_comment(&sentence(summary), 0));
+        }
+        let derives = if defaultable {
+            "Debug, Clone, PartialEq, Default"
+        } else {
+            "Debug, Clone, PartialEq"
+        };
+        let _ = writeln!(out, "#[derive({})]", derives);
+        if fields.is_empty() {
+            let _ = writeln!(out, "pub struct {};", name);
+        } else {
+            let _ = writeln!(out, "pub struct {} {{\n{}}}", name, fields);
+        }
+
+        let _ = writeln!(out, "\nimpl Operation for {} {{", name);
+        let _ = writeln!(
+            out,
+            "    type Body = {};",
+            body_type.as_ref().map(|(t, _)| t.as_str()).unwrap_or("()")
+        );
+        let _ = writeln!(out, "    type Response = {};\n", response_type);
+        let _ = writeln!(
+            out,
+            "    const METHOD: Method = Method::{};",
+            method.to_uppercase()
+        );
+        let _ = writeln!(out, "    const PATH: &'static str = \"{}\";", path);
+        let _ = writeln!(
+            out,
+            "    const API_VERSION: ApiVersion = ApiVersion::{};\n",
+            api_version
+        );
+
+        // fn path
+        let _ = writeln!(out, "    fn path(&self) -> String {{");
+        if path_args.is_empty() {
+            let _ = writeln!(out, "        \"{}\".to_string()", relative_path);
+        } else {
+            // Parameters are declared in any order; format them in the order
+            // they appear in the path.
+            path_args.sort_by_key(|(param_name, _)| {
+                relative_path.find(&format!("{{{}}}", param_name))
+            });
+            let mut template = relative_path.to_string();
+            let mut args = Vec::new();
+            for (param_name, field) in &path_args {
+                template = template.replace(&format!("{{{}}}", param_name), "{}");
+                args.push(format!("encode_path_segment(&self.{})", field));
+            }
+            let _ = writeln!(
+                out,
+                "        format!(\"{}\", {})",
+                template,
+                args.join(", ")
+            );
+        }
+        let _ = writeln!(out, "    }}\n");
+
+        // fn query
+        let _ = writeln!(out, "    fn query(&self) -> Vec<(&'static str, String)> {{");
+        match (required_query.is_empty(), optional_query.is_empty()) {
+            (true, true) => {
+                let _ = writeln!(out, "        Vec::new()");
+            }
+            (false, true) => {
+                let _ = writeln!(out, "        vec![{}]", required_query.join(", "));
+            }
+            (true, false) => {
+                let _ = writeln!(
+                    out,
+                    "        let mut query = Vec::new();\n{}        query",
+                    optional_query
+                );
+            }
+            (false, false) => {
+                let _ = writeln!(
+                    out,
+                    "        let mut query = vec![{}];\n{}        query",
+                    required_query.join(", "),
+                    optional_query
+                );
+            }
+        }
+        let _ = writeln!(out, "    }}\n");
+
+        // fn body
+        let _ = writeln!(out, "    fn body(&self) -> Option<&Self::Body> {{");
+        match body_type {
+            None => {
+                let _ = writeln!(out, "        None");
+            }
+            Some((_, true)) => {
+                let _ = writeln!(out, "        Some(&self.body)");
+            }
+            Some((_, false)) => {
+                let _ = writeln!(out, "        self.body.as_ref()");
+            }
+        }
+        let _ = writeln!(out, "    }}\n}}");
+        out
+    }
+
+    /// Renders the type for a named schema.
+    fn schema_type(&mut self, name: &str, schema: &Value, suffix: &str) -> String {
+        let mut out = String::from("\n");
+        out.push_str(&doc_comment(&schema_description(schema), 0));
+
+        if let Some(values) = schema["enum"].as_sequence() {
+            out.push_str(&string_enum(name, values));
+        } else if let Some(mapping) = schema["discriminator"]["mapping"].as_mapping() {
+            let tag = schema["discriminator"]["propertyName"]
+                .as_str()
+                .unwrap_or("type");
+            // `anyOf` also admits kinds that are not listed.
+            let open = schema.get("anyOf").is_some();
+            out.push_str(&tagged_enum(name, tag, mapping, open));
+        } else if is_object(schema) {
+            out.push_str(&self.struct_type(name, schema, suffix));
+        } else {
+            let alias = self.type_of(schema, &format!("{}Value", name), suffix);
+            let _ = writeln!(out, "pub type {} = {};", name, alias);
+        }
+        out
+    }
+
+    fn struct_type(&mut self, name: &str, schema: &Value, suffix: &str) -> String {
+        let required: BTreeSet<&str> = schema["required"]
+            .as_sequence()
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let properties = schema["properties"].as_mapping().cloned().unwrap_or_default();
+
+        let mut fields = String::new();
+        let mut defaultable = true;
+        for (property, property_schema) in &properties {
+            let property = property.as_str().expect("property names are strings");
+            let field = field_name(property);
+            let context = format!("{}{}", name, pascal_case(property));
+            let value_type = self.type_of(property_schema, &context, suffix);
+
+            let mut serde = Vec::new();
+            if camel_case(field.trim_start_matches("r#")) != property {
+                serde.push(format!("rename = \"{}\"", property));
+            }
+            let field_type = if required.contains(property) {
+                if value_type.starts_with("Vec<") || value_type.starts_with("HashMap<") {
+                    serde.push("default".to_string());
+                }
+                defaultable &= is_defaultable(&value_type);
+                value_type
+            } else {
+                serde.push("default".to_string());
+                serde.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                format!("Option<{}>", value_type)
+            };
+
+            fields.push_str(&doc_comment(&description_of(property_schema), 4));
+            if !serde.is_empty() {
+                let _ = writeln!(fields, "    #[serde({})]", serde.join(", "));
+            }
+            let _ = writeln!(fields, "    pub {}: {},", field, field_type);
+        }
+
+        let derives = if defaultable {
+            "Debug, Clone, PartialEq, Default, Serialize, Deserialize"
+        } else {
+            "Debug, Clone, PartialEq, Serialize, Deserialize"
+        };
+        let mut out = format!("#[derive({})]\n", derives);
+        if fields.is_empty() {
+            let _ = writeln!(out, "pub struct {} {{}}", name);
+        } else {
+            out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+            let _ = writeln!(out, "pub struct {} {{\n{}}}", name, fields);
+        }
+        out
+    }
+
+    /// The Rust type of a schema, naming inline object and enum schemas after
+    /// `context` unless they are identical to a component schema.
+    fn type_of(&mut self, schema: &Value, context: &str, suffix: &str) -> String {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference
+                .strip_prefix(SCHEMA_PREFIX)
+                .unwrap_or_else(|| panic!("unsupported reference {}", reference));
+            return type_name(name);
+        }
+
+        let needs_type = schema.get("oneOf").is_some()
+            || schema.get("anyOf").is_some()
+            || schema.get("enum").is_some()
+            || schema.get("properties").is_some();
+        if needs_type {
+            return self.named_type(schema, context, suffix);
+        }
+
+        match schema["type"].as_str() {
+            Some("string") => match schema["format"].as_str() {
+                Some("date-time") => {
+                    self.uses_date_time = true;
+                    "DateTime<Utc>".to_string()
+                }
+                _ => "String".to_string(),
+            },
+            Some("integer") => match schema["format"].as_str() {
+                Some("int32") => "i32".to_string(),
+                _ => "i64".to_string(),
+            },
+            Some("number") => "f64".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("array") => {
+                let item = self.type_of(&schema["items"], &format!("{}Item", context), suffix);
+                format!("Vec<{}>", item)
+            }
+            Some("object") => {
+                self.uses_hash_map = true;
+                match &schema["additionalProperties"] {
+                    Value::Mapping(values) if !values.is_empty() => {
+                        let value = self.type_of(
+                            &schema["additionalProperties"],
+                            &format!("{}Value", context),
+                            suffix,
+                        );
+                        format!("HashMap<String, {}>", value)
+                    }
+                    _ => "HashMap<String, serde_json::Value>".to_string(),
+                }
+            }
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    fn named_type(&mut self, schema: &Value, context: &str, suffix: &str) -> String {
+        if let Some(name) = self.matching_schema(schema, suffix) {
+            return name;
+        }
+        let mut name = context.to_string();
+        let mut n = 2;
+        while self.named.contains(&name) {
+            name = format!("{}{}", context, n);
+            n += 1;
+        }
+        self.named.insert(name.clone());
+        self.pending.push((name.clone(), schema.clone()));
+        name
+    }
+
+    /// The component schema an inline schema is a copy of, preferring one of
+    /// the same API version.
+    fn matching_schema(&self, schema: &Value, suffix: &str) -> Option<String> {
+        for keep_title in [true, false] {
+            let wanted = normalize(schema, keep_title);
+            let candidates: Vec<&str> = self
+                .schemas
+                .iter()
+                .filter(|(_, candidate)| normalize(candidate, keep_title) == wanted)
+                .filter_map(|(name, _)| name.as_str())
+                .collect();
+            let preferred = candidates
+                .iter()
+                .find(|name| version_suffix_of_schema(name) == suffix)
+                .or_else(|| candidates.first());
+            if let Some(name) = preferred {
+                return Some(type_name(name));
+            }
+        }
+        None
+    }
+}
+
+fn string_enum(name: &str, values: &[Value]) -> String {
+    let values: Vec<&str> = values.iter().filter_map(Value::as_str).collect();
+    let mut out = String::from(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]\n",
+    );
+    let _ = writeln!(out, "pub enum {} {{", name);
+    for value in &values {
+        let _ = writeln!(
+            out,
+            "    #[serde(rename = \"{}\")]\n    {},",
+            value,
+            pascal_case(value)
+        );
+    }
+    let _ = writeln!(out, "}}\n\nimpl {} {{", name);
+    let _ = writeln!(out, "    pub fn as_str(&self) -> &'static str {{\n        match self {{");
+    for value in &values {
+        let _ = writeln!(
+            out,
+            "            {}::{} => \"{}\",",
+            name,
+            pascal_case(value),
+            value
+        );
+    }
+    let _ = writeln!(out, "        }}\n    }}\n}}\n");
+    let _ = writeln!(
+        out,
+        "impl fmt::Display for {} {{\n    \
+         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{\n        \
+         f.write_str(self.as_str())\n    }}\n}}",
+        name
+    );
+    out
+}
+
+fn tagged_enum(name: &str, tag: &str, mapping: &Mapping, open: bool) -> String {
+    let mut out = String::from("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+    let _ = writeln!(out, "#[serde(tag = \"{}\")]", tag);
+    let _ = writeln!(out, "pub enum {} {{", name);
+    for (value, reference) in mapping {
+        let value = value.as_str().expect("discriminator values are strings");
+        let reference = reference.as_str().expect("discriminator mappings are strings");
+        let target = reference
+            .strip_prefix(SCHEMA_PREFIX)
+            .unwrap_or_else(|| panic!("unsupported reference {}", reference));
+        let _ = writeln!(
+            out,
+            "    #[serde(rename = \"{}\")]\n    {}({}),",
+            value,
+            pascal_case(value),
+            type_name(target)
+        );
+    }
+    if open {
+        out.push_str(
+            "    /// A kind this version of the specification does not know.\n    \
+             #[serde(other)]\n    Unknown,\n",
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn response_schema(operation: &Value) -> Option<Value> {
+    let responses = operation["responses"].as_mapping()?;
+    let mut codes: Vec<(&str, &Value)> = responses
+        .iter()
+        .filter_map(|(code, response)| Some((code.as_str()?, response)))
+        .filter(|(code, _)| code.starts_with('2') || *code == "default")
+        .collect();
+    // Explicit success codes take precedence over `default`.
+    codes.sort_by_key(|(code, _)| *code == "default");
+    codes.into_iter().find_map(|(_, response)| {
+        let schema = &response["content"]["application/json"]["schema"];
+        (!schema.is_null()).then(|| schema.clone())
+    })
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema.get("properties").is_some() || schema["type"].as_str() == Some("object")
+}
+
+fn is_defaultable(rust_type: &str) -> bool {
+    ["Option<", "Vec<", "HashMap<"]
+        .iter()
+        .any(|prefix| rust_type.starts_with(prefix))
+        || matches!(rust_type, "String" | "bool" | "i32" | "i64" | "f64")
+}
+
+/// Drops the keys that do not affect the shape of a schema.
+fn normalize(schema: &Value, keep_title: bool) -> Value {
+    const IGNORED: [&str; 7] = [
+        "description",
+        "examples",
+        "example",
+        "deprecated",
+        "pattern",
+        "minLength",
+        "uniqueItems",
+    ];
+    match schema {
+        Value::Mapping(mapping) => Value::Mapping(
+            mapping
+                .iter()
+                .filter(|(key, _)| {
+                    let key = key.as_str().unwrap_or_default();
+                    !IGNORED.contains(&key) && (keep_title || key != "title")
+                })
+                .map(|(key, value)| (key.clone(), normalize(value, keep_title)))
+                .collect(),
+        ),
+        Value::Sequence(values) => Value::Sequence(
+            values
+                .iter()
+                .map(|value| normalize(value, keep_title))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn version_suffix_of_schema(name: &str) -> &'static str {
+    if name.ends_with("_V1") {
+        "_V1"
+    } else if name.ends_with("_V2") {
+        "_V2"
+    } else {
+        ""
+    }
+}
+
+fn schema_description(schema: &Value) -> String {
+    let description = description_of(schema);
+    if !description.is_empty() {
+        return description;
+    }
+    // Titles are mostly the schema name, which says nothing more.
+    match schema["title"].as_str() {
+        Some(title) if title.contains(' ') => sentence(title),
+        _ => String::new(),
+    }
+}
+
+/// The first paragraph of the description of a schema or parameter.
+fn description_of(value: &Value) -> String {
+    let description = value["description"].as_str().unwrap_or_default();
+    let paragraph = description.trim().split("\n\n").next().unwrap_or_default();
+    let joined = paragraph
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if joined.is_empty() {
+        joined
+    } else {
+        sentence(&joined)
+    }
+}
+
+fn sentence(text: &str) -> String {
+    let text = text.trim();
+    if text.ends_with(['.', ':', '?', '!']) {
+        text.to_string()
+    } else {
+        format!("{}.", text)
+    }
+}
+
+fn doc_comment(text: &str, indent: usize) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let prefix = format!("{}/// ", " ".repeat(indent));
+    let mut out = String::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && prefix.len() + line.len() + 1 + word.len() > DOC_WIDTH {
+            let _ = writeln!(out, "{}{}", prefix, line);
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    let _ = writeln!(out, "{}{}", prefix, line);
+    out
+}
+
+/// `CommitMeta_V2` becomes `CommitMetaV2`.
+fn type_name(schema_name: &str) -> String {
+    schema_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `getEntriesV2` becomes `GetEntriesV2` and `ICEBERG_TABLE` becomes
+/// `IcebergTable`.
+fn pascal_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `commitMetaOfHEAD` becomes `commit_meta_of_head`.
+fn field_name(name: &str) -> String {
+    let snake = words(name).join("_");
+    match snake.as_str() {
+        "type" | "ref" | "match" | "mod" | "use" | "fn" | "impl" | "in" | "where" => {
+            format!("r#{}", snake)
+        }
+        _ => snake,
+    }
+}
+
+/// How serde's `rename_all = "camelCase"` renames a snake case field.
+fn camel_case(snake: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+    for c in snake.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Splits camelCase, PascalCase, snake_case and kebab-case names into lower
+/// case words.
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lower)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c.to_ascii_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
@@ -1,347 +1,595 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use iceberg::io::FileIO;
+use iceberg::spec::{TableMetadata, TableMetadataBuilder};
+use iceberg::table::Table;
+use iceberg::{
+    Catalog, Error, ErrorKind, Namespace, NamespaceIdent, Result, TableCommit, TableCreation,
+    TableIdent,
+};
+use nessie_client::client::NessieClient;
+use nessie_client::error::NessieError;
+use nessie_client::generated::models::Namespace as NessieNamespace;
+use nessie_client::models::{
+    CommitMeta, CommitResponse, Content, ContentKey, EntriesParams, Entry, IcebergTable, Operation,
+    Operations,
+};
+use nessie_client::ref_spec::RefSpec;
+use uuid::Uuid;
+
+/// Content types of the entries the catalog works with.
+pub const NAMESPACE: &str = "NAMESPACE";
+pub const ICEBERG_TABLE: &str = "ICEBERG_TABLE";
+
+/// Iceberg catalog over one Nessie branch.
+///
+/// Commits of the catalog are chained: each one expects the head returned by
+/// the previous one, so the catalog only looks the branch up before its first
+/// commit and after a conflict.
+#[derive(Debug)]
 pub struct NessieCatalog {
-    client:  NessieClient,
+    client: NessieClient,
     branch: String,
-    file_io: iceberg::io::FileIO,
+    /// Location under which tables without an explicit location are created.
+    warehouse: String,
+    file_io: FileIO,
+    /// Author of the commits, `Name <email>`; Nessie records the committer
+    /// if unset.
+    author: Option<String>,
+    /// Whether missing namespaces of a table are created in the commit that
+    /// adds the table, instead of failing.
+    create_namespaces: bool,
+    head: Mutex<Option<RefSpec>>,
+    /// Nessie content ids of the tables created by this catalog, which later
+    /// `Put`s of the same table must carry.
+    content_ids: Mutex<HashMap<TableIdent, String>>,
 }
 
-#[async_trait]
-impl Catalog for NessieClient {
-    #[doc = " List namespaces inside the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn list_namespaces(
-        &self,
-        parent: Option<&NamespaceIdent>,
-    ) -> Result<Vec<NamespaceIdent>, iceberg::Error> {
-        // Get all entries from Nessie (main branch, or configurable)
-        let entries = self.list_entries("main").await?; // or self.reference()
-
-        let mut namespaces = std::collections::HashSet::new();
-
-        for entry in entries {
-            let parts: Vec<&str> = entry.split('.').collect();
-            if parts.len() > 1 {
-                let ns_parts = parts[..parts.len() - 1]
-                    .iter()
-                    .map(|s| s.to_string())
-                    .collect::<Vec<String>>();
-                let ns = NamespaceIdent::from_vec(ns_parts)?;
-                namespaces.insert(ns);
-            }
+impl NessieCatalog {
+    pub fn new(
+        client: NessieClient,
+        branch: impl Into<String>,
+        warehouse: impl Into<String>,
+        file_io: FileIO,
+    ) -> Self {
+        Self {
+            client,
+            branch: branch.into(),
+            warehouse: warehouse.into().trim_end_matches('/').to_string(),
+            file_io,
+            author: None,
+            create_namespaces: false,
+            head: Mutex::new(None),
+            content_ids: Mutex::new(HashMap::new()),
         }
+    }
 
-        let ns = namespaces.into_iter().collect();
-        Ok(ns)
+    /// Catalog over the default branch of the server, as reported by its
+    /// configuration.
+    pub async fn on_default_branch(
+        client: NessieClient,
+        warehouse: impl Into<String>,
+        file_io: FileIO,
+    ) -> Result<Self> {
+        let branch = client.default_branch().await?;
+        Ok(Self::new(client, branch, warehouse, file_io))
     }
 
-    #[doc = " Create a new namespace inside the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn create_namespace(
+    /// Sets the author recorded in the commits of the catalog.
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Creates the missing namespaces of tables that are created, registered
+    /// or renamed, in the same commit.
+    pub fn with_namespace_creation(mut self, create_namespaces: bool) -> Self {
+        self.create_namespaces = create_namespaces;
+        self
+    }
+
+    pub fn client(&self) -> &NessieClient {
+        &self.client
+    }
+
+    /// The branch the catalog reads and commits.
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Location under which tables without an explicit location are created.
+    pub fn warehouse(&self) -> &str {
+        &self.warehouse
+    }
+
+    /// Replaces the metadata of `table` with `metadata`: writes the next
+    /// metadata file and points the table to it in a Nessie commit. Fails if
+    /// the table or the branch changed since `table` was loaded.
+    ///
+    /// This is the commit of [`Catalog::update_table`], for changes built
+    /// without a [`TableCommit`].
+    pub async fn commit_table_metadata(
         &self,
-        namespace: &NamespaceIdent,
-        properties: HashMap<String, String>,
-    ) -> Result<Namespace, iceberg::Error> {
-        let ns_key = ContentKey {
-            elements: namespace.clone().inner(),
-        };
+        table: &Table,
+        metadata: TableMetadata,
+    ) -> Result<Table> {
+        let ident = table.identifier();
+        let content = self.table_content(ident).await?;
+        if table.metadata_location() != Some(content.metadata_location.as_str()) {
+            return Err(Error::new(
+                ErrorKind::CatalogCommitConflicts,
+                format!("Table {} changed since it was loaded", table_key(ident)),
+            )
+            .with_retryable(true));
+        }
 
-        let content = crate::models::Content {
-            content_type: "NAMESPACE".to_string(),
-            metadata_location: "".to_string(), // not used for namespaces
-            snapshot_id: None,
-            schema_id: None,
-            spec_id: None,
-            sort_order_id: None,
-        };
+        let version = metadata_version(&content.metadata_location).map_or(0, |v| v + 1);
+        let metadata_location = metadata_location(metadata.location(), version);
+        self.write_metadata(&metadata_location, &metadata).await?;
+
+        let key = table_key(ident);
+        self.commit(
+            format!("Update table {}", key),
+            vec![Operation::Put {
+                key,
+                content: table_content(content.id, metadata_location.clone(), &metadata),
+            }],
+        )
+        .await?;
+
+        Table::builder()
+            .metadata(metadata)
+            .metadata_location(metadata_location)
+            .identifier(ident.clone())
+            .file_io(self.file_io.clone())
+            .build()
+    }
 
-        let operation = crate::models::Operation::Put {
-            key: ns_key,
-            content,
-        };
-        let properties2 = properties.clone();
+    /// The Nessie content id of a table created through this catalog.
+    pub fn content_id(&self, table: &TableIdent) -> Option<String> {
+        self.content_ids.lock().unwrap().get(table).cloned()
+    }
 
-        // let author_time = OffsetDateTime::now_utc().format(&Rfc3339)?;
-        let author_time = "2025-06-12T10:00:00Z".to_string();
+    fn remember_content_id(&self, table: &TableIdent, content_id: &str) {
+        self.content_ids
+            .lock()
+            .unwrap()
+            .insert(table.clone(), content_id.to_string());
+    }
 
-        let commit_meta = crate::models::CommitMeta {
-            author: "iceberg-rust-client <unknown>".into(),
-            author_time: author_time,
-            message: format!("Create namespace {:?}", namespace),
-            signed_off_by: None,
-            properties: properties, // Optionally pass `properties` here
-        };
+    fn reference(&self) -> Result<RefSpec> {
+        Ok(RefSpec::named(&self.branch)?)
+    }
 
-        let operations = crate::models::Operations {
-            commit_meta: commit_meta,
-            operations: vec![operation],
-        };
+    async fn head(&self) -> Result<RefSpec> {
+        let cached = self.head.lock().unwrap().clone();
+        if let Some(head) = cached {
+            return Ok(head);
+        }
+        let branch = self
+            .client
+            .get_reference(&self.reference()?)
+            .await?
+            .reference;
+        let head = RefSpec::from(&branch);
+        *self.head.lock().unwrap() = Some(head.clone());
+        Ok(head)
+    }
 
-        let commit_response = self
-            .commit_entry(
-                "main@2e1cfa82b035c26cbbbdae632cea070514eb8b773f616aaeaf668e2f0be8f10d",
-                operations,
-            )
-            .await?;
+    /// Entries of the branch matching `params`.
+    async fn entries(&self, params: EntriesParams) -> Result<Vec<Entry>> {
+        Ok(self
+            .client
+            .list_all_entries(&self.reference()?, &params)
+            .await?)
+    }
 
-        Ok(iceberg::Namespace::with_properties(
-            namespace.clone(),
-            properties2,
-        ))
+    /// Whether `key` exists on the branch with `content_type`.
+    async fn exists(&self, key: ContentKey, content_type: &str) -> Result<bool> {
+        let params = EntriesParams {
+            keys: vec![key],
+            ..Default::default()
+        }
+        .of_type(content_type);
+        Ok(!self.entries(params).await?.is_empty())
+    }
+
+    /// Puts of the namespaces `namespace` and its ancestors that are missing,
+    /// outermost first.
+    ///
+    /// Nessie rejects a table whose namespace does not exist, so this fails
+    /// with [`ErrorKind::NamespaceNotFound`] unless namespace creation is
+    /// enabled.
+    async fn namespace_operations(&self, namespace: &NamespaceIdent) -> Result<Vec<Operation>> {
+        let elements = namespace.clone().inner();
+        let keys: Vec<ContentKey> = (1..=elements.len())
+            .map(|depth| ContentKey::new(elements[..depth].iter().cloned()))
+            .collect();
+        let params = EntriesParams {
+            keys: keys.clone(),
+            ..Default::default()
+        }
+        .of_type(NAMESPACE);
+        let existing: Vec<ContentKey> = self
+            .entries(params)
+            .await?
+            .into_iter()
+            .map(|entry| entry.name)
+            .collect();
+
+        let missing: Vec<ContentKey> = keys
+            .into_iter()
+            .filter(|key| !existing.contains(key))
+            .collect();
+        if missing.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !self.create_namespaces {
+            return Err(Error::new(
+                ErrorKind::NamespaceNotFound,
+                format!("Namespace {} does not exist", elements.join(".")),
+            ));
+        }
+        Ok(missing
+            .into_iter()
+            .map(|key| Operation::Put {
+                content: namespace_content(&key, HashMap::new()),
+                key,
+            })
+            .collect())
+    }
+
+    /// The current content of `table`, with its content id.
+    async fn table_content(&self, table: &TableIdent) -> Result<IcebergTable> {
+        let params = EntriesParams {
+            keys: vec![table_key(table)],
+            with_content: true,
+            ..Default::default()
+        }
+        .of_type(ICEBERG_TABLE);
+        let entry = self.entries(params).await?.into_iter().next();
+        match entry.and_then(|entry| entry.content) {
+            Some(Content::IcebergTable(content)) => Ok(content),
+            _ => Err(Error::new(
+                ErrorKind::TableNotFound,
+                format!("Table {} does not exist", table_key(table)),
+            )),
+        }
+    }
+
+    async fn read_metadata(&self, metadata_location: &str) -> Result<TableMetadata> {
+        let bytes = self.file_io.new_input(metadata_location)?.read().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    async fn write_metadata(
+        &self,
+        metadata_location: &str,
+        metadata: &TableMetadata,
+    ) -> Result<()> {
+        self.file_io
+            .new_output(metadata_location)?
+            .write(serde_json::to_vec(metadata)?.into())
+            .await
     }
 
-    #[doc = " Get a namespace information from the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace, iceberg::Error> {
-        let entries = self.list_entries("main").await?;
-        let ns_key = namespace.clone().inner();
+    /// The table of `content`, stored at `table`.
+    async fn table(&self, table: &TableIdent, content: IcebergTable) -> Result<Table> {
+        let metadata = self.read_metadata(&content.metadata_location).await?;
+        if let Some(content_id) = &content.id {
+            self.remember_content_id(table, content_id);
+        }
 
-        for entry in entries {
-            let parts: Vec<&str> = entry.split('.').collect();
-            let ns_parts: Vec<String> = parts.iter().map(|s| s.to_string()).collect();
+        Table::builder()
+            .metadata(metadata)
+            .metadata_location(content.metadata_location)
+            .identifier(table.clone())
+            .file_io(self.file_io.clone())
+            .build()
+    }
 
-            if ns_parts == *ns_key {
-                return Ok(Namespace::new(namespace.clone()));
+    /// Commits `operations` on the last known head of the branch and moves
+    /// that head to the new commit.
+    async fn commit(&self, message: String, operations: Vec<Operation>) -> Result<CommitResponse> {
+        let head = self.head().await?;
+        let operations = Operations {
+            commit_meta: CommitMeta {
+                authors: self.author.iter().cloned().collect(),
+                author_time: Some(Utc::now()),
+                message,
+                ..Default::default()
+            },
+            operations,
+        };
+
+        match self.client.commit_entry(&head, operations).await {
+            Ok(response) => {
+                *self.head.lock().unwrap() = Some(RefSpec::from(&response.target_branch));
+                Ok(response)
+            }
+            Err(error) => {
+                // Someone else moved the branch; look it up again next time.
+                if matches!(error, NessieError::Conflict(_)) {
+                    *self.head.lock().unwrap() = None;
+                }
+                Err(error.into())
             }
         }
+    }
+}
 
-        Err(iceberg::Error::new(
-            iceberg::ErrorKind::Unexpected,
-            format!("Namespace {:?} not found", namespace),
-        ))
+fn namespace_content(key: &ContentKey, properties: HashMap<String, String>) -> Content {
+    Content::Namespace(NessieNamespace {
+        id: None,
+        elements: key.elements.clone(),
+        properties,
+    })
+}
+
+fn table_content(
+    id: Option<String>,
+    metadata_location: String,
+    metadata: &TableMetadata,
+) -> Content {
+    Content::IcebergTable(IcebergTable {
+        id,
+        metadata_location,
+        snapshot_id: Some(metadata.current_snapshot_id().unwrap_or(-1)),
+        schema_id: Some(metadata.current_schema_id()),
+        spec_id: Some(metadata.default_partition_spec_id()),
+        sort_order_id: Some(metadata.default_sort_order_id() as i32),
+        metadata: None,
+    })
+}
+
+/// Location of version `version` of the metadata of the table at `location`.
+fn metadata_location(location: &str, version: u32) -> String {
+    format!(
+        "{}/metadata/{:05}-{}.metadata.json",
+        location.trim_end_matches('/'),
+        version,
+        Uuid::new_v4()
+    )
+}
+
+/// Version of a metadata file named as by [`metadata_location`].
+fn metadata_version(metadata_location: &str) -> Option<u32> {
+    let name = metadata_location.rsplit('/').next()?;
+    name.split_once('-')?.0.parse().ok()
+}
+
+fn table_key(table: &TableIdent) -> ContentKey {
+    ContentKey::new(
+        table
+            .namespace()
+            .clone()
+            .inner()
+            .into_iter()
+            .chain(std::iter::once(table.name().to_string())),
+    )
+}
+
+#[async_trait]
+impl Catalog for NessieCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        let parent = parent.map(|parent| ContentKey::new(parent.clone().inner()));
+        let params = EntriesParams::children_of(parent.as_ref()).of_type(NAMESPACE);
+
+        self.entries(params)
+            .await?
+            .into_iter()
+            .map(|entry| NamespaceIdent::from_vec(entry.name.elements))
+            .collect()
     }
 
-    #[doc = " Check if namespace exists in catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool, iceberg::Error> {
-        let entries = self.list_entries("main").await?;
-
-        let ns_key = namespace.clone().inner();
-
-        Ok(entries.iter().any(|entry| {
-            let parts: Vec<&str> = entry.split('.').collect();
-            let ns_parts: Vec<String> = parts.iter().map(|s| s.to_string()).collect();
-            ns_parts == *ns_key
-        }))
-    }
-
-    #[doc = " Update a namespace inside the catalog."]
-    #[doc = ""]
-    #[doc = " # Behavior"]
-    #[doc = ""]
-    #[doc = " The properties must be the full set of namespace."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn update_namespace(
+    async fn create_namespace(
         &self,
         namespace: &NamespaceIdent,
         properties: HashMap<String, String>,
-    ) -> Result<(), iceberg::Error> {
-        Ok(())
+    ) -> Result<Namespace> {
+        let key = ContentKey::new(namespace.clone().inner());
+        let content = namespace_content(&key, properties.clone());
+
+        self.commit(
+            format!("Create namespace {}", key),
+            vec![Operation::Put { key, content }],
+        )
+        .await?;
+
+        Ok(Namespace::with_properties(namespace.clone(), properties))
     }
 
-    #[doc = " Drop a namespace from the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<(), iceberg::Error> {
-        todo!()
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        if self.namespace_exists(namespace).await? {
+            return Ok(Namespace::new(namespace.clone()));
+        }
+
+        Err(Error::new(
+            ErrorKind::NamespaceNotFound,
+            format!("Namespace {} does not exist", namespace.join(".")),
+        ))
     }
 
-    #[doc = " List tables from namespace."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn list_tables(
-        &self,
-        namespace: &NamespaceIdent,
-    ) -> Result<Vec<TableIdent>, iceberg::Error> {
-        todo!()
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        self.exists(ContentKey::new(namespace.clone().inner()), NAMESPACE)
+            .await
     }
 
-    #[doc = " Create a new table inside the namespace."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn create_table(
+    async fn update_namespace(
         &self,
-        namespace: &NamespaceIdent,
-        creation: TableCreation,
-    ) -> Result<Table, iceberg::Error> {
-        let table_name = creation.name;
-
-        let mut key_parts = namespace.clone().inner(); // Vec<String>
-        key_parts.push(table_name.clone());
-        let content_key = ContentKey {
-            elements: key_parts,
-        };
-
-        let metadata_location = format!("s3://warehouse/{}/metadata.json", table_name);
+        _namespace: &NamespaceIdent,
+        _properties: HashMap<String, String>,
+    ) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Updating namespace properties is not supported",
+        ))
+    }
 
-        let content = Content {
-            content_type: "ICEBERG_TABLE".into(),
-            metadata_location,
-            snapshot_id: Some(0),
-            schema_id: Some(creation.schema.schema_id()),
-            spec_id: Some(0),
-            sort_order_id: Some(0),
-        };
+    /// Drops `namespace`, which must be empty: Nessie would keep its tables
+    /// and namespaces, orphaned.
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        if !self.namespace_exists(namespace).await? {
+            return Err(Error::new(
+                ErrorKind::NamespaceNotFound,
+                format!("Namespace {} does not exist", namespace.join(".")),
+            ));
+        }
+        let key = ContentKey::new(namespace.clone().inner());
+        if !self
+            .entries(EntriesParams::children_of(Some(&key)))
+            .await?
+            .is_empty()
+        {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("Namespace {} is not empty", namespace.join(".")),
+            ));
+        }
 
-        let operation = crate::models::Operation::Put {
-            key: content_key,
-            content,
-        };
+        self.commit(
+            format!("Drop namespace {}", key),
+            vec![Operation::Delete { key }],
+        )
+        .await?;
 
-        let commit_meta = crate::models::CommitMeta {
-            author: "iceberg-rust <client@rust>".into(),
-            author_time: "2025-01-06T10:00:00Z".into(),
-            message: "Create table".into(),
-            signed_off_by: None,
-            properties: Default::default(),
-        };
+        Ok(())
+    }
 
-        let ops = crate::models::Operations {
-            commit_meta,
-            operations: vec![operation],
-        };
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let parent = ContentKey::new(namespace.clone().inner());
+        let params = EntriesParams::children_of(Some(&parent)).of_type(ICEBERG_TABLE);
 
-        let branch = "main";
-        let reference = self.get_reference("main".to_string()).await?;
-        let hash = reference.reference.hash.unwrap();
+        Ok(self
+            .entries(params)
+            .await?
+            .into_iter()
+            .map(|entry| TableIdent::new(namespace.clone(), entry.name.name().to_string()))
+            .collect())
+    }
 
-        self.commit_entry(format!("{}@{}", branch, hash).as_str(), ops)
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        mut creation: TableCreation,
+    ) -> Result<Table> {
+        let ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        let mut operations = self.namespace_operations(namespace).await?;
+        let location = creation.location.clone().unwrap_or_else(|| {
+            format!(
+                "{}/{}/{}",
+                self.warehouse,
+                namespace.clone().inner().join("/"),
+                creation.name
+            )
+        });
+        creation.location = Some(location.clone());
+
+        let metadata = TableMetadataBuilder::from_table_creation(creation)?
+            .build()?
+            .metadata;
+        let metadata_location = metadata_location(&location, 0);
+        self.write_metadata(&metadata_location, &metadata).await?;
+
+        let key = table_key(&ident);
+        operations.push(Operation::Put {
+            key: key.clone(),
+            content: table_content(None, metadata_location.clone(), &metadata),
+        });
+        let response = self
+            .commit(format!("Create table {}", key), operations)
             .await?;
+        if let Some(content_id) = response.added_content_id(&key) {
+            self.remember_content_id(&ident, content_id);
+        }
 
-        // let file_io: Arc<dyn FileIO> = Arc::new(LocalFileIO::new());
-
-        // Ok(Table::from_creation(creation))
-        let builder = Table::builder().file_io(self.file_io.clone());
-        let table = builder.build()?;
-        Ok(table)
-    }
-
-    #[doc = " Load table from the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn load_table(&self, table: &TableIdent) -> Result<Table, iceberg::Error> {
-        todo!()
-    }
-
-    #[doc = " Drop a table from the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn drop_table(&self, table: &TableIdent) -> Result<(), iceberg::Error> {
-        let ns = table.namespace().clone();
-        let key = ContentKey {
-            elements: ns
-                .inner()
-                .into_iter()
-                .chain(std::iter::once(table.name().to_string()))
-                .collect(),
-        };
+        Table::builder()
+            .metadata(metadata)
+            .metadata_location(metadata_location)
+            .identifier(ident)
+            .file_io(self.file_io.clone())
+            .build()
+    }
 
-        let branch = "main";
-        let reference = self.get_reference("main".to_string()).await?;
-        let hash = reference.reference.hash.ok_or(iceberg::Error::new(
-            iceberg::ErrorKind::Unexpected,
-            "Cannot get hash",
-        ))?;
-
-        let commit_meta = crate::models::CommitMeta {
-            author: "iceberg-rust <client@rust>".into(),
-            author_time: "2025-01-06T10:00:00Z".into(),
-            message: "Delete table".into(),
-            signed_off_by: None,
-            properties: Default::default(),
-        };
+    async fn load_table(&self, table: &TableIdent) -> Result<Table> {
+        let content = self.table_content(table).await?;
+        self.table(table, content).await
+    }
 
-        let op = crate::models::Operation::Delete { key: key };
+    async fn drop_table(&self, table: &TableIdent) -> Result<()> {
+        let key = table_key(table);
+        self.commit(
+            format!("Drop table {}", key),
+            vec![Operation::Delete { key }],
+        )
+        .await?;
+        self.content_ids.lock().unwrap().remove(table);
 
-        let ops = crate::models::Operations {
-            commit_meta: commit_meta,
-            operations: vec![op],
-        };
+        Ok(())
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> Result<bool> {
+        self.exists(table_key(table), ICEBERG_TABLE).await
+    }
 
-        self.commit_entry(format!("{}@{}", branch, hash).as_str(), ops)
+    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        let content = self.table_content(src).await?;
+        let mut operations = self.namespace_operations(dest.namespace()).await?;
+        let (from, to) = (table_key(src), table_key(dest));
+        // The content keeps its id, which tells Nessie it is the same table.
+        let content_id = content.id.clone();
+        operations.push(Operation::Delete { key: from.clone() });
+        operations.push(Operation::Put {
+            key: to.clone(),
+            content: Content::IcebergTable(content),
+        });
+
+        self.commit(format!("Rename table {} to {}", from, to), operations)
             .await?;
+        self.content_ids.lock().unwrap().remove(src);
+        if let Some(content_id) = content_id {
+            self.remember_content_id(dest, &content_id);
+        }
 
         Ok(())
     }
 
-    #[doc = " Check if a table exists in the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn table_exists(&self, table: &TableIdent) -> Result<bool, iceberg::Error> {
-        todo!()
+    async fn register_table(&self, table: &TableIdent, metadata_location: String) -> Result<Table> {
+        let mut operations = self.namespace_operations(table.namespace()).await?;
+        let metadata = self.read_metadata(&metadata_location).await?;
+
+        let key = table_key(table);
+        operations.push(Operation::Put {
+            key: key.clone(),
+            content: table_content(None, metadata_location.clone(), &metadata),
+        });
+        let response = self
+            .commit(format!("Register table {}", key), operations)
+            .await?;
+        if let Some(content_id) = response.added_content_id(&key) {
+            self.remember_content_id(table, content_id);
+        }
+
+        Table::builder()
+            .metadata(metadata)
+            .metadata_location(metadata_location)
+            .identifier(table.clone())
+            .file_io(self.file_io.clone())
+            .build()
     }
 
-    #[doc = " Rename a table in the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn rename_table(
-        &self,
-        src: &TableIdent,
-        dest: &TableIdent,
-    ) -> Result<(), iceberg::Error> {
-        todo!()
-    }
-
-    #[doc = " Update a table to the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn register_table(
-        &self,
-        table: &TableIdent,
-        metadata_location: String,
-    ) -> Result<Table, iceberg::Error> {
-        todo!()
-    }
-
-    #[doc = " Update a table to the catalog."]
-    #[allow(
-        elided_named_lifetimes,
-        clippy::type_complexity,
-        clippy::type_repetition_in_bounds
-    )]
-    async fn update_table(&self, commit: TableCommit) -> Result<Table, iceberg::Error> {
-        todo!()
+    /// Applies `commit` to the table on the branch, see
+    /// [`NessieCatalog::commit_table_metadata`].
+    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
+        let ident = commit.identifier().clone();
+        let table = self.load_table(&ident).await?;
+        let metadata = commit.apply(table.clone())?.metadata().clone();
+        self.commit_table_metadata(&table, metadata).await
     }
 }
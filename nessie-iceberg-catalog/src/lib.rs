@@ -0,0 +1,5 @@
+//! Iceberg catalog keeping its namespaces and tables on a Nessie branch.
+
+pub mod catalog;
+
+pub use catalog::NessieCatalog;
@@ -1,5 +0,0 @@
-mod catalog;
-
-fn main() {
-    println!("Hello, world!");
-}
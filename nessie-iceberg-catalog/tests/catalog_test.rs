@@ -0,0 +1,268 @@
+use iceberg::io::FileIOBuilder;
+use iceberg::spec::{NestedField, PrimitiveType, Schema, Type};
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::{Catalog, ErrorKind, NamespaceIdent, TableCreation, TableIdent};
+use nessie_client::client::NessieClient;
+use nessie_client::test_server::NessieTestServer;
+use nessie_iceberg_catalog::NessieCatalog;
+
+async fn catalog() -> (NessieTestServer, NessieCatalog) {
+    let server = NessieTestServer::start().await.unwrap();
+    let client = NessieClient::new(&server.base_url()).unwrap();
+    let file_io = FileIOBuilder::new("memory").build().unwrap();
+    let catalog = NessieCatalog::on_default_branch(client, "memory:/warehouse", file_io)
+        .await
+        .unwrap();
+    (server, catalog)
+}
+
+fn namespace(elements: &[&str]) -> NamespaceIdent {
+    NamespaceIdent::from_strs(elements).unwrap()
+}
+
+fn creation(name: &str) -> TableCreation {
+    let schema = Schema::builder()
+        .with_fields(vec![NestedField::required(
+            1,
+            "id",
+            Type::Primitive(PrimitiveType::Long),
+        )
+        .into()])
+        .build()
+        .unwrap();
+    TableCreation::builder()
+        .name(name.to_string())
+        .schema(schema)
+        .build()
+}
+
+#[tokio::test]
+async fn test_catalog_on_default_branch() {
+    let server = NessieTestServer::start_with_default_branch("trunk")
+        .await
+        .unwrap();
+    let client = NessieClient::new(&server.base_url()).unwrap();
+    let file_io = FileIOBuilder::new("memory").build().unwrap();
+    let catalog = NessieCatalog::on_default_branch(client, "memory:/warehouse", file_io)
+        .await
+        .unwrap();
+    assert_eq!(catalog.branch(), "trunk");
+
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    assert!(catalog.namespace_exists(&db).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_create_table_requires_namespace() {
+    let (_server, catalog) = catalog().await;
+
+    let error = catalog
+        .create_table(&namespace(&["db"]), creation("events"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::NamespaceNotFound);
+    assert!(error.message().contains("db"), "{error}");
+}
+
+#[tokio::test]
+async fn test_create_table_in_existing_namespace() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+
+    catalog.create_table(&db, creation("events")).await.unwrap();
+
+    let events = TableIdent::new(db.clone(), "events".to_string());
+    assert!(catalog.table_exists(&events).await.unwrap());
+    assert!(catalog.content_id(&events).is_some());
+    assert_eq!(catalog.list_tables(&db).await.unwrap(), vec![events]);
+}
+
+#[tokio::test]
+async fn test_namespace_creation_creates_missing_ancestors() {
+    let (_server, catalog) = catalog().await;
+    let catalog = catalog.with_namespace_creation(true);
+    let nested = namespace(&["sales", "eu"]);
+
+    catalog
+        .create_table(&nested, creation("orders"))
+        .await
+        .unwrap();
+
+    assert!(catalog
+        .namespace_exists(&namespace(&["sales"]))
+        .await
+        .unwrap());
+    assert!(catalog.namespace_exists(&nested).await.unwrap());
+    assert_eq!(
+        catalog
+            .list_namespaces(Some(&namespace(&["sales"])))
+            .await
+            .unwrap(),
+        vec![nested.clone()]
+    );
+    assert_eq!(catalog.list_tables(&nested).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_drop_namespace() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    catalog.create_table(&db, creation("events")).await.unwrap();
+
+    let error = catalog.drop_namespace(&db).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::PreconditionFailed);
+
+    let events = TableIdent::new(db.clone(), "events".to_string());
+    catalog.drop_table(&events).await.unwrap();
+    catalog.drop_namespace(&db).await.unwrap();
+    assert!(!catalog.namespace_exists(&db).await.unwrap());
+
+    let error = catalog.drop_namespace(&db).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NamespaceNotFound);
+}
+
+#[tokio::test]
+async fn test_update_namespace_is_unsupported() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+
+    let error = catalog
+        .update_namespace(&db, Default::default())
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::FeatureUnsupported);
+}
+
+#[tokio::test]
+async fn test_register_table() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    let created = catalog.create_table(&db, creation("events")).await.unwrap();
+    let metadata_location = created.metadata_location().unwrap().to_string();
+
+    let copy = TableIdent::new(db.clone(), "events_copy".to_string());
+    let registered = catalog
+        .register_table(&copy, metadata_location.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        registered.metadata_location(),
+        Some(metadata_location.as_str())
+    );
+    assert!(catalog.table_exists(&copy).await.unwrap());
+
+    let elsewhere = TableIdent::new(namespace(&["missing"]), "events".to_string());
+    let error = catalog
+        .register_table(&elsewhere, metadata_location)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NamespaceNotFound);
+}
+
+#[tokio::test]
+async fn test_rename_table() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    catalog.create_table(&db, creation("events")).await.unwrap();
+    let events = TableIdent::new(db.clone(), "events".to_string());
+    let content_id = catalog.content_id(&events);
+
+    let archived = TableIdent::new(namespace(&["archive"]), "events".to_string());
+    let error = catalog.rename_table(&events, &archived).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NamespaceNotFound);
+
+    let renamed = TableIdent::new(db.clone(), "events_v2".to_string());
+    catalog.rename_table(&events, &renamed).await.unwrap();
+    assert!(!catalog.table_exists(&events).await.unwrap());
+    assert!(catalog.table_exists(&renamed).await.unwrap());
+    assert_eq!(catalog.content_id(&renamed), content_id);
+
+    let error = catalog.rename_table(&events, &renamed).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::TableNotFound);
+}
+
+#[tokio::test]
+async fn test_load_table() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    let created = catalog.create_table(&db, creation("events")).await.unwrap();
+    let events = TableIdent::new(db.clone(), "events".to_string());
+
+    let loaded = catalog.load_table(&events).await.unwrap();
+    assert_eq!(loaded.identifier(), &events);
+    assert_eq!(loaded.metadata_location(), created.metadata_location());
+    assert_eq!(
+        loaded.metadata().current_schema(),
+        created.metadata().current_schema()
+    );
+
+    let missing = TableIdent::new(db, "missing".to_string());
+    let error = catalog.load_table(&missing).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::TableNotFound);
+}
+
+#[tokio::test]
+async fn test_update_table() {
+    let (_server, catalog) = catalog().await;
+    let db = namespace(&["db"]);
+    catalog
+        .create_namespace(&db, Default::default())
+        .await
+        .unwrap();
+    let created = catalog.create_table(&db, creation("events")).await.unwrap();
+    let events = TableIdent::new(db.clone(), "events".to_string());
+    let content_id = catalog.content_id(&events);
+
+    let tx = Transaction::new(&created);
+    let tx = tx
+        .update_table_properties()
+        .set("owner".to_string(), "analytics".to_string())
+        .apply(tx)
+        .unwrap();
+    let updated = tx.commit(&catalog).await.unwrap();
+    assert!(updated
+        .metadata_location()
+        .unwrap()
+        .contains("/metadata/00001-"));
+
+    let loaded = catalog.load_table(&events).await.unwrap();
+    assert_eq!(loaded.metadata_location(), updated.metadata_location());
+    assert_eq!(
+        loaded
+            .metadata()
+            .properties()
+            .get("owner")
+            .map(String::as_str),
+        Some("analytics")
+    );
+    assert_eq!(catalog.content_id(&events), content_id);
+}